@@ -270,7 +270,7 @@ fn validate_blocks(criterion: &mut Criterion) {
         .chain_first()
         .sign(&public_key, &private_key)
         .expect("Failed to sign a block.");
-    let mut world_state_view = WorldStateView::new(Peer::new(
+    let world_state_view = WorldStateView::new(Peer::new(
         PeerId {
             address: "127.0.0.1:8080".to_string(),
             public_key: [0; 32],
@@ -280,7 +280,7 @@ fn validate_blocks(criterion: &mut Criterion) {
     let mut success_count = 0;
     let mut failures_count = 0;
     criterion.bench_function("validate_block", |b| {
-        b.iter(|| match block.clone().validate(&mut world_state_view) {
+        b.iter(|| match block.clone().validate(&world_state_view) {
             Ok(_) => success_count += 1,
             Err(_) => failures_count += 1,
         });
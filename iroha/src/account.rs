@@ -13,6 +13,8 @@ pub struct Account {
     /// Asset's in this `Account`.
     pub assets: BTreeMap<<Asset as Identifiable>::Id, Asset>,
     signatories: Vec<PublicKey>,
+    /// Arbitrary key-value attributes of the account, like KYC status or contact data.
+    pub metadata: BTreeMap<String, String>,
 }
 
 impl Account {
@@ -26,9 +28,20 @@ impl Account {
             id: Id::new(account_name, domain_name),
             assets: BTreeMap::new(),
             signatories: vec![public_key],
+            metadata: BTreeMap::new(),
         }
     }
 
+    /// Checks if the `public_key` is one of the account's signatories.
+    pub fn has_signatory(&self, public_key: &PublicKey) -> bool {
+        self.signatories.contains(public_key)
+    }
+
+    /// Public keys of the account's signatories.
+    pub fn signatories(&self) -> &[PublicKey] {
+        &self.signatories
+    }
+
     /// Constructor of the `Transfer<Account, Asset, Account>` Iroha Special Instruction.
     pub fn transfer_asset_to(
         &self,
@@ -103,6 +116,12 @@ pub mod isi {
             <Account as Identifiable>::Id,
             Asset,
         ),
+        /// Variant of the generic `SetKeyValue` instruction for `Account` metadata.
+        SetKeyValue(<Account as Identifiable>::Id, String, String),
+        /// Variant of the generic `RemoveKeyValue` instruction for `Account` metadata.
+        RemoveKeyValue(<Account as Identifiable>::Id, String),
+        /// Variant of the `SwapAssets` instruction.
+        SwapAssets(Asset, Asset),
     }
 
     impl AccountInstruction {
@@ -124,6 +143,22 @@ pub mod isi {
                     destination_account_id.clone(),
                 )
                 .execute(authority, world_state_view),
+                AccountInstruction::SetKeyValue(account_id, key, value) => {
+                    SetKeyValue::<Account, String, String>::new(
+                        account_id.clone(),
+                        key.clone(),
+                        value.clone(),
+                    )
+                    .execute(authority, world_state_view)
+                }
+                AccountInstruction::SwapAssets(left, right) => {
+                    SwapAssets::new(left.clone(), right.clone())
+                        .execute(authority, world_state_view)
+                }
+                AccountInstruction::RemoveKeyValue(account_id, key) => {
+                    RemoveKeyValue::<Account, String>::new(account_id.clone(), key.clone())
+                        .execute(authority, world_state_view)
+                }
             }
         }
     }
@@ -151,49 +186,67 @@ pub mod isi {
     }
 
     impl Transfer<Account, Asset, Account> {
+        /// Moves the quantity, the big quantity or the fixed quantity of the `object`, depending on
+        /// the value type of its definition, from the source account's asset to the destination
+        /// account's asset with the same definition. Only the amount is transferred, the
+        /// `permissions` and the `store` of the `object` are ignored.
         fn execute(
             &self,
             authority: <Account as Identifiable>::Id,
             world_state_view: &mut WorldStateView,
         ) -> Result<(), String> {
-            PermissionInstruction::CanTransferAsset(
-                authority,
-                self.object.id.definition_id.clone(),
-                None,
-            )
-            .execute(world_state_view)?;
-            let source = world_state_view
-                .account(&self.source_id)
-                .ok_or("Failed to find accounts.")?
-                .assets
-                .get_mut(&self.object.id)
-                .ok_or("Asset's component was not found.")?;
-            let quantity_to_transfer = self.object.quantity;
-            if source.quantity < quantity_to_transfer {
-                return Err(format!(
-                    "Not enough assets: {:?}, {:?}.",
-                    source, self.object
-                ));
-            }
-            source.quantity -= quantity_to_transfer;
-            match world_state_view
-                .account(&self.destination_id)
-                .ok_or("Failed to find destination account.")?
-                .assets
-                .get_mut(&self.object.id)
-            {
-                Some(destination) => {
-                    destination.quantity += quantity_to_transfer;
+            let source_asset_id = AssetId {
+                definition_id: self.object.id.definition_id.clone(),
+                account_id: self.source_id.clone(),
+            };
+            let destination_asset_id = AssetId {
+                definition_id: self.object.id.definition_id.clone(),
+                account_id: self.destination_id.clone(),
+            };
+            let value_type = world_state_view
+                .read_asset_definition(&self.object.id.definition_id)
+                .ok_or("Failed to find asset.")?
+                .value_type;
+            match value_type {
+                AssetValueType::Quantity
+                    if self.object.big_quantity == 0 && self.object.fixed_quantity.mantissa == 0 =>
+                {
+                    Transfer::<Asset, u32, Asset>::new(
+                        source_asset_id,
+                        self.object.quantity,
+                        destination_asset_id,
+                    )
+                    .execute(authority, world_state_view)
                 }
-                None => {
-                    world_state_view
-                        .account(&self.destination_id)
-                        .ok_or("Failed to find destination account.")?
-                        .assets
-                        .insert(self.object.id.clone(), self.object.clone());
+                AssetValueType::BigQuantity
+                    if self.object.quantity == 0 && self.object.fixed_quantity.mantissa == 0 =>
+                {
+                    Transfer::<Asset, u128, Asset>::new(
+                        source_asset_id,
+                        self.object.big_quantity,
+                        destination_asset_id,
+                    )
+                    .execute(authority, world_state_view)
                 }
+                AssetValueType::Fixed(_)
+                    if self.object.quantity == 0 && self.object.big_quantity == 0 =>
+                {
+                    Transfer::<Asset, Fixed, Asset>::new(
+                        source_asset_id,
+                        self.object.fixed_quantity,
+                        destination_asset_id,
+                    )
+                    .execute(authority, world_state_view)
+                }
+                _ => Err(format!(
+                    "Asset definition {} holds values of type {}, quantity {}, big quantity {} and fixed quantity {} can not be transferred.",
+                    self.object.id.definition_id,
+                    value_type,
+                    self.object.quantity,
+                    self.object.big_quantity,
+                    self.object.fixed_quantity
+                )),
             }
-            Ok(())
         }
     }
 
@@ -206,4 +259,538 @@ pub mod isi {
             ))
         }
     }
+
+    impl SetKeyValue<Account, String, String> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanSetKeyValueInAccount(authority, self.object_id.clone())
+                .execute(world_state_view)?;
+            let store_limits = world_state_view.store_limits();
+            let account = world_state_view
+                .account(&self.object_id)
+                .ok_or("Failed to find account.")?;
+            store_limits.insert(&mut account.metadata, self.key.clone(), self.value.clone())
+        }
+    }
+
+    impl RemoveKeyValue<Account, String> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanSetKeyValueInAccount(authority, self.object_id.clone())
+                .execute(world_state_view)?;
+            world_state_view
+                .account(&self.object_id)
+                .ok_or("Failed to find account.")?
+                .metadata
+                .remove(&self.key)
+                .ok_or_else(|| format!("No value with key {} found.", self.key))?;
+            Ok(())
+        }
+    }
+
+    /// Instruction for an atomic exchange of assets between two accounts: the account of the
+    /// `left` asset transfers its amount to the account of the `right` asset and receives the
+    /// amount of the `right` asset in return. Amounts are taken from the assets and permissions
+    /// of the authority are checked the same way as in `Transfer<Account, Asset, Account>`.
+    ///
+    /// The transaction should be signed by signatories of both accounts. If any of the transfers
+    /// fails, for example because of an insufficient balance, none of them is applied.
+    #[derive(Clone, Debug)]
+    pub struct SwapAssets {
+        /// Asset and its amount which the first account gives.
+        pub left: Asset,
+        /// Asset and its amount which the second account gives.
+        pub right: Asset,
+    }
+
+    impl SwapAssets {
+        /// Default `SwapAssets` constructor.
+        pub fn new(left: Asset, right: Asset) -> Self {
+            SwapAssets { left, right }
+        }
+
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            let left_account_id = &self.left.id.account_id;
+            let right_account_id = &self.right.id.account_id;
+            if left_account_id == right_account_id {
+                return Err(format!(
+                    "Assets can not be swapped within the same account {:?}.",
+                    left_account_id
+                ));
+            }
+            check_signed_by(left_account_id, world_state_view)?;
+            check_signed_by(right_account_id, world_state_view)?;
+            world_state_view.atomically(|world_state_view| {
+                Transfer::<Account, Asset, Account>::new(
+                    left_account_id.clone(),
+                    self.left.clone(),
+                    right_account_id.clone(),
+                )
+                .execute(authority.clone(), world_state_view)?;
+                Transfer::<Account, Asset, Account>::new(
+                    right_account_id.clone(),
+                    self.right.clone(),
+                    left_account_id.clone(),
+                )
+                .execute(authority, world_state_view)
+            })
+        }
+    }
+
+    impl From<SwapAssets> for Instruction {
+        fn from(instruction: SwapAssets) -> Self {
+            Instruction::Account(AccountInstruction::SwapAssets(
+                instruction.left,
+                instruction.right,
+            ))
+        }
+    }
+
+    /// Checks that the transaction being executed is signed by one of the account's signatories.
+    fn check_signed_by(
+        account_id: &<Account as Identifiable>::Id,
+        world_state_view: &WorldStateView,
+    ) -> Result<(), String> {
+        let account = world_state_view
+            .read_account(account_id)
+            .ok_or("Failed to find account.")?;
+        if world_state_view
+            .transaction_signatories()
+            .iter()
+            .any(|public_key| account.has_signatory(public_key))
+        {
+            Ok(())
+        } else {
+            Err(format!(
+                "Transaction is not signed by a signatory of the account {:?}.",
+                account_id
+            ))
+        }
+    }
+
+    impl From<SetKeyValue<Account, String, String>> for Instruction {
+        fn from(instruction: SetKeyValue<Account, String, String>) -> Self {
+            Instruction::Account(AccountInstruction::SetKeyValue(
+                instruction.object_id,
+                instruction.key,
+                instruction.value,
+            ))
+        }
+    }
+
+    impl From<RemoveKeyValue<Account, String>> for Instruction {
+        fn from(instruction: RemoveKeyValue<Account, String>) -> Self {
+            Instruction::Account(AccountInstruction::RemoveKeyValue(
+                instruction.object_id,
+                instruction.key,
+            ))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{
+            crypto::{PrivateKey, PublicKey, Signature},
+            isi,
+            isi::fixtures::*,
+            permission,
+        };
+
+        #[test]
+        fn swap_should_require_both_signatures_and_be_atomic() {
+            let root_key_pair =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let alice_key_pair =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let bob_key_pair =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(root_key_pair.0);
+            let root_id = AccountId::new("root", "global");
+            let alice_id = add_account(&mut world_state_view, "alice", alice_key_pair.0);
+            let bob_id = add_account(&mut world_state_view, "bob", bob_key_pair.0);
+            let asset_id = |definition_name: &str, account_id: &AccountId| AssetId {
+                definition_id: AssetDefinitionId::new(definition_name, "global"),
+                account_id: account_id.clone(),
+            };
+            Instruction::from(isi::Mint::<Asset, u32>::new(10, asset_id("xor", &alice_id)))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            Instruction::from(isi::Mint::<Asset, u128>::new(5, asset_id("btc", &bob_id)))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            let swap = |btc_quantity: u128| -> Instruction {
+                SwapAssets::new(
+                    Asset::with_quantity(asset_id("xor", &alice_id), 10),
+                    Asset::with_big_quantity(asset_id("btc", &bob_id), btc_quantity),
+                )
+                .into()
+            };
+            let signed_by = |instruction: Instruction,
+                             key_pairs: &[&(PublicKey, PrivateKey)]|
+             -> SignedTransaction {
+                let (first, others) = key_pairs.split_first().expect("No key pairs.");
+                let transaction = RequestedTransaction::new(vec![instruction], root_id.clone())
+                    .accept()
+                    .expect("Failed to accept transaction.")
+                    .sign(&first.0, &first.1)
+                    .expect("Failed to sign transaction.");
+                others.iter().fold(transaction, |transaction, key_pair| {
+                    RequestedTransaction::from(transaction)
+                        .accept()
+                        .expect("Failed to accept transaction.")
+                        .sign(&key_pair.0, &key_pair.1)
+                        .expect("Failed to sign transaction.")
+                })
+            };
+            assert!(swap(5)
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            assert!(signed_by(swap(5), &[&root_key_pair, &alice_key_pair])
+                .validate(&mut world_state_view)
+                .is_err());
+            assert!(
+                signed_by(swap(6), &[&root_key_pair, &alice_key_pair, &bob_key_pair])
+                    .validate(&mut world_state_view)
+                    .is_err()
+            );
+            let balances = |world_state_view: &WorldStateView| {
+                let quantity = |definition_name: &str, account_id: &AccountId| {
+                    world_state_view
+                        .read_asset(&asset_id(definition_name, account_id))
+                        .map_or(Ok(0), Asset::balance)
+                        .expect("Failed to get the balance.")
+                };
+                (
+                    quantity("xor", &alice_id),
+                    quantity("btc", &alice_id),
+                    quantity("xor", &bob_id),
+                    quantity("btc", &bob_id),
+                )
+            };
+            assert_eq!((10, 0, 0, 5), balances(&world_state_view));
+            let forged_signature = Signature::new(bob_key_pair.0, b"forged", &alice_key_pair.1)
+                .expect("Failed to create signature.");
+            assert!(signed_by(swap(5), &[&alice_key_pair])
+                .sign(vec![forged_signature])
+                .expect("Failed to add signature.")
+                .validate(&mut world_state_view)
+                .is_err());
+            assert_eq!((10, 0, 0, 5), balances(&world_state_view));
+            signed_by(swap(5), &[&alice_key_pair, &bob_key_pair])
+                .validate(&mut world_state_view)
+                .expect("Failed to swap assets.");
+            assert_eq!((0, 5, 10, 0), balances(&world_state_view));
+        }
+
+        #[test]
+        fn transfers_should_move_big_quantities_without_copying_permissions() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            let btc_definition_id = AssetDefinitionId::new("btc", "global");
+            let root_btc_id = AssetId {
+                definition_id: btc_definition_id.clone(),
+                account_id: root_id.clone(),
+            };
+            let alice_btc_id = AssetId {
+                definition_id: btc_definition_id.clone(),
+                account_id: alice_id.clone(),
+            };
+            let big_quantity = u128::from(u32::MAX) + 1;
+            Instruction::from(isi::Mint::<Asset, u128>::new(
+                big_quantity,
+                root_btc_id.clone(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to mint big asset.");
+            Instruction::from(isi::Transfer::<Account, Asset, Account>::new(
+                root_id.clone(),
+                Asset::with_big_quantity(root_btc_id.clone(), big_quantity - 1),
+                alice_id.clone(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to transfer asset.");
+            let alice_btc = world_state_view
+                .read_asset(&alice_btc_id)
+                .expect("Failed to find transferred asset.");
+            assert_eq!(alice_btc_id, alice_btc.id);
+            assert_eq!(big_quantity - 1, alice_btc.big_quantity);
+            assert_eq!(
+                Some(1),
+                world_state_view
+                    .read_asset(&root_btc_id)
+                    .map(|asset| asset.big_quantity)
+            );
+            assert!(Instruction::from(isi::Transfer::<Asset, u128, Asset>::new(
+                root_btc_id.clone(),
+                2,
+                alice_btc_id.clone(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .is_err());
+            Instruction::from(isi::Transfer::<Asset, u128, Asset>::new(
+                root_btc_id,
+                1,
+                alice_btc_id,
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to transfer big asset.");
+            assert_eq!(
+                Ok(big_quantity),
+                world_state_view.check_asset_supply(&btc_definition_id)
+            );
+            let root_permissions_id = AssetId {
+                definition_id: permission::permission_asset_definition_id(),
+                account_id: root_id.clone(),
+            };
+            let root_permissions = world_state_view
+                .read_asset(&root_permissions_id)
+                .expect("Failed to find permissions.")
+                .clone();
+            Instruction::from(isi::Transfer::<Account, Asset, Account>::new(
+                root_id.clone(),
+                root_permissions,
+                alice_id.clone(),
+            ))
+            .execute(root_id, &mut world_state_view)
+            .expect("Failed to transfer empty asset.");
+            assert!(
+                permission::isi::PermissionInstruction::CanAnything(alice_id)
+                    .execute(&world_state_view)
+                    .is_err()
+            );
+        }
+    }
+}
+
+/// Query module provides `IrohaQuery` Account related implementations.
+pub mod query {
+    use super::*;
+    use crate::{
+        block::SignedBlockHeader,
+        permission::isi::PermissionInstruction,
+        query::{Cursor, IrohaQuery, ListOptions, QueryError, QueryItem, Value},
+        wsv::{StateEntry, StateProof},
+    };
+    use iroha_derive::{log, IntoQuery, Io};
+
+    /// To get all accounts of all domains, FindAllAccounts query can be used.
+    /// Accounts are ordered by identification unless `options` specify another sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAllAccounts {
+        options: ListOptions,
+    }
+
+    /// Result of the `FindAllAccounts` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAllAccountsResult {
+        /// Accounts of all domains selected by the query options.
+        pub accounts: Vec<Account>,
+        /// Cursor to the next page of accounts if there are more of them.
+        pub next_cursor: Option<Cursor>,
+    }
+
+    impl FindAllAccounts {
+        /// Build a `FindAllAccounts` query in the form of a `QueryRequest`.
+        pub fn build_request(options: ListOptions) -> QueryRequest {
+            let query = FindAllAccounts { options };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAllAccounts {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let mut accounts: Vec<Account> = world_state_view
+                .read_peer()
+                .domains
+                .values()
+                .flat_map(|domain| domain.accounts.values())
+                .cloned()
+                .collect();
+            accounts.sort_by(|left, right| left.id.cmp(&right.id));
+            let (accounts, next_cursor) = self
+                .options
+                .apply(accounts, world_state_view.query_limits())?;
+            Ok(QueryResult::FindAllAccounts(FindAllAccountsResult {
+                accounts,
+                next_cursor,
+            }))
+        }
+    }
+
+    /// To get an account by its identification, FindAccountById query can be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAccountById {
+        id: <Account as Identifiable>::Id,
+        with_proof: bool,
+    }
+
+    /// Result of the `FindAccountById` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAccountByIdResult {
+        /// Account with the requested identification.
+        pub account: Account,
+        /// Proof of inclusion of the account into the state the query was executed on, if
+        /// requested.
+        pub proof: Option<StateProof>,
+        /// Header which state root the `proof` should be verified against, if the proof is
+        /// requested and the block committing to the state is already committed.
+        pub state_header: Option<SignedBlockHeader>,
+    }
+
+    impl FindAccountById {
+        /// Build a `FindAccountById` query in the form of a `QueryRequest`.
+        pub fn build_request(id: <Account as Identifiable>::Id) -> QueryRequest {
+            let query = FindAccountById {
+                id,
+                with_proof: false,
+            };
+            QueryRequest::new(query.into())
+        }
+
+        /// Build a `FindAccountById` query in the form of a `QueryRequest`, which result carries a
+        /// proof of inclusion of the account into the world state.
+        ///
+        /// The state after the block at some height is committed to by the state root of the
+        /// block, so the header which the proof is verified against is the one of the latest block
+        /// or of the block the query is made `at_height` of, returned in the `state_header`.
+        pub fn build_request_with_proof(id: <Account as Identifiable>::Id) -> QueryRequest {
+            let query = FindAccountById {
+                id,
+                with_proof: true,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAccountById {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let account = world_state_view
+                .read_account(&self.id)
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!("No account with id: {:?} found.", &self.id))
+                })?
+                .clone();
+            let (proof, state_header) = if self.with_proof {
+                (
+                    world_state_view
+                        .state_proofs(|entry| match entry {
+                            StateEntry::Account(id, _, _) => *id == self.id,
+                            _ => false,
+                        })
+                        .pop(),
+                    world_state_view.state_root_header(),
+                )
+            } else {
+                (None, None)
+            };
+            Ok(QueryResult::FindAccountById(FindAccountByIdResult {
+                account,
+                proof,
+                state_header,
+            }))
+        }
+
+        fn read_permission(
+            &self,
+            authority: <Account as Identifiable>::Id,
+        ) -> PermissionInstruction {
+            PermissionInstruction::CanReadAccount(authority, self.id.clone())
+        }
+    }
+
+    /// To get all accounts of the domain, FindAccountsByDomain query can be used.
+    /// Accounts are ordered by identification unless `options` specify another sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAccountsByDomain {
+        domain_name: <Domain as Identifiable>::Id,
+        options: ListOptions,
+    }
+
+    /// Result of the `FindAccountsByDomain` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAccountsByDomainResult {
+        /// Accounts of the requested domain selected by the query options.
+        pub accounts: Vec<Account>,
+        /// Cursor to the next page of accounts if there are more of them.
+        pub next_cursor: Option<Cursor>,
+    }
+
+    impl FindAccountsByDomain {
+        /// Build a `FindAccountsByDomain` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            domain_name: <Domain as Identifiable>::Id,
+            options: ListOptions,
+        ) -> QueryRequest {
+            let query = FindAccountsByDomain {
+                domain_name,
+                options,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAccountsByDomain {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let accounts: Vec<Account> = world_state_view
+                .read_domain(&self.domain_name)
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!(
+                        "No domain with name: {} found.",
+                        &self.domain_name
+                    ))
+                })?
+                .accounts
+                .values()
+                .cloned()
+                .collect();
+            let (accounts, next_cursor) = self
+                .options
+                .apply(accounts, world_state_view.query_limits())?;
+            Ok(QueryResult::FindAccountsByDomain(
+                FindAccountsByDomainResult {
+                    accounts,
+                    next_cursor,
+                },
+            ))
+        }
+
+        fn read_permission(
+            &self,
+            authority: <Account as Identifiable>::Id,
+        ) -> PermissionInstruction {
+            PermissionInstruction::CanReadDomain(authority, self.domain_name.clone())
+        }
+    }
+
+    impl QueryItem for Account {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "name" => Some(Value::String(self.id.name.clone())),
+                "domain_name" => Some(Value::String(self.id.domain_name.clone())),
+                "assets" => Some(Value::Number(self.assets.len() as u128)),
+                "signatories" => Some(Value::Number(self.signatories.len() as u128)),
+                _ => None,
+            }
+        }
+        fn metadata(&self, key: &str) -> Option<&str> {
+            self.metadata.get(key).map(String::as_str)
+        }
+    }
 }
@@ -10,21 +10,233 @@ use std::{
     hash::Hash,
 };
 
+/// Number of digits after the decimal point of a fixed-point asset's quantity.
+pub type PrecisionDecimals = u8;
+
+/// Maximal `PrecisionDecimals` of a fixed-point asset, so that one unit of the asset still fits
+/// into `u128` with plenty of room for the integer part.
+pub const MAX_PRECISION: PrecisionDecimals = 18;
+
+/// Fixed-point decimal quantity of an asset. It is stored as an integer number of the smallest
+/// units, each of them is `10^-precision` of the asset, so all the arithmetic is exact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Encode, Decode)]
+pub struct Fixed {
+    /// Quantity in the smallest units of the asset.
+    pub mantissa: u128,
+    /// Number of digits after the decimal point.
+    pub precision: PrecisionDecimals,
+}
+
+impl Fixed {
+    /// Default `Fixed` constructor from the quantity in the smallest units.
+    pub fn new(mantissa: u128, precision: PrecisionDecimals) -> Self {
+        Fixed {
+            mantissa,
+            precision,
+        }
+    }
+
+    /// Zero quantity with the given precision.
+    pub fn zero(precision: PrecisionDecimals) -> Self {
+        Fixed::new(0, precision)
+    }
+
+    /// Parses a decimal string like `12.05` into `Fixed` with the given precision.
+    /// Fractional digits which do not fit into the precision are an error, not rounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iroha::asset::Fixed;
+    ///
+    /// assert_eq!(Ok(Fixed::new(1250, 2)), Fixed::parse("12.5", 2));
+    /// assert!(Fixed::parse("12.505", 2).is_err());
+    /// ```
+    pub fn parse(string: &str, precision: PrecisionDecimals) -> Result<Self, String> {
+        if precision > MAX_PRECISION {
+            return Err(format!(
+                "Precision {} is greater than maximum {}.",
+                precision, MAX_PRECISION
+            ));
+        }
+        let mut parts = string.splitn(2, '.');
+        let integer = parts.next().unwrap_or("");
+        let fraction = parts.next().unwrap_or("");
+        let is_number = |digits: &str| digits.bytes().all(|byte| byte.is_ascii_digit());
+        if integer.is_empty() || !is_number(integer) || !is_number(fraction) {
+            return Err(format!("Failed to parse decimal quantity: {}.", string));
+        }
+        if fraction.len() > usize::from(precision) {
+            return Err(format!(
+                "Decimal quantity {} has more than {} digits after the decimal point.",
+                string, precision
+            ));
+        }
+        let overflow = || format!("Decimal quantity {} overflows.", string);
+        let mut mantissa: u128 = integer.parse().map_err(|_| overflow())?;
+        let fraction = format!("{:0<width$}", fraction, width = usize::from(precision));
+        for digit in fraction.bytes() {
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|mantissa| mantissa.checked_add(u128::from(digit - b'0')))
+                .ok_or_else(overflow)?;
+        }
+        Ok(Fixed::new(mantissa, precision))
+    }
+
+    /// Checked addition of quantities with the same precision.
+    /// Returns `None` on overflow or if the precisions differ.
+    pub fn checked_add(self, other: Fixed) -> Option<Fixed> {
+        if self.precision != other.precision {
+            return None;
+        }
+        self.mantissa
+            .checked_add(other.mantissa)
+            .map(|mantissa| Fixed::new(mantissa, self.precision))
+    }
+
+    /// Checked subtraction of quantities with the same precision.
+    /// Returns `None` on underflow or if the precisions differ.
+    pub fn checked_sub(self, other: Fixed) -> Option<Fixed> {
+        if self.precision != other.precision {
+            return None;
+        }
+        self.mantissa
+            .checked_sub(other.mantissa)
+            .map(|mantissa| Fixed::new(mantissa, self.precision))
+    }
+}
+
+impl Display for Fixed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let width = usize::from(self.precision);
+        if width == 0 {
+            return write!(f, "{}", self.mantissa);
+        }
+        match 10u128.checked_pow(self.precision.into()) {
+            Some(scale) => write!(
+                f,
+                "{}.{:0>width$}",
+                self.mantissa / scale,
+                self.mantissa % scale,
+                width = width
+            ),
+            None => write!(f, "0.{:0>width$}", self.mantissa, width = width),
+        }
+    }
+}
+
+/// Type of the values which can be held by assets of the same `AssetDefinition`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum AssetValueType {
+    /// Integer quantity stored in the `quantity` field of the asset.
+    Quantity,
+    /// Integer quantity stored in the `big_quantity` field of the asset.
+    BigQuantity,
+    /// Fixed-point decimal quantity with the given number of digits after the decimal point.
+    Fixed(PrecisionDecimals),
+    /// Key-value structured data without any quantity.
+    Store,
+}
+
+impl Display for AssetValueType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetValueType::Quantity => write!(f, "quantity"),
+            AssetValueType::BigQuantity => write!(f, "big quantity"),
+            AssetValueType::Fixed(precision) => write!(f, "fixed with precision {}", precision),
+            AssetValueType::Store => write!(f, "store"),
+        }
+    }
+}
+
+/// Defines how many times assets of the `AssetDefinition` can be minted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum Mintable {
+    /// Asset can be minted only once, after that it becomes `Not` mintable.
+    Once,
+    /// Asset can be minted any number of times.
+    Infinitely,
+    /// Asset can not be minted anymore.
+    Not,
+}
+
 /// Asset entity represents some sort of commodity or value.
 #[derive(Clone, Debug, Encode, Decode)]
 pub struct AssetDefinition {
     /// An Identification of the `Asset`.
     pub id: <AssetDefinition as Identifiable>::Id,
+    /// Type of the values held by the assets of this definition.
+    pub value_type: AssetValueType,
+    /// How many times the assets of this definition can be minted.
+    pub mintable: Mintable,
+    /// Arbitrary key-value attributes of the asset definition, like a display name.
+    pub metadata: BTreeMap<String, String>,
+    /// Total quantity of the asset ever minted, `quantity`, `big_quantity` and the smallest units
+    /// of `fixed_quantity` are counted.
+    pub total_minted: u128,
+    /// Total quantity of the asset ever burned, `quantity`, `big_quantity` and the smallest units
+    /// of `fixed_quantity` are counted.
+    pub total_burned: u128,
 }
 
 impl AssetDefinition {
-    /// Constructor of the detached and empty `AssetDefinition` entity.
+    /// Constructor of the detached and empty `AssetDefinition` entity with `Quantity` values
+    /// which can be minted infinitely.
     ///
     /// This method can be used to create an `AssetDefinition` which should be registered in the domain.
     /// This method should not be used to create an `AssetDefinition` to work with as a part of the Iroha
     /// State.
     pub fn new(id: <AssetDefinition as Identifiable>::Id) -> Self {
-        AssetDefinition { id }
+        AssetDefinition::with_value_type(id, AssetValueType::Quantity, Mintable::Infinitely)
+    }
+
+    /// Constructor of the detached and empty `AssetDefinition` entity with the given value type
+    /// and mintability.
+    pub fn with_value_type(
+        id: <AssetDefinition as Identifiable>::Id,
+        value_type: AssetValueType,
+        mintable: Mintable,
+    ) -> Self {
+        AssetDefinition {
+            id,
+            value_type,
+            mintable,
+            metadata: BTreeMap::new(),
+            total_minted: 0,
+            total_burned: 0,
+        }
+    }
+
+    /// Quantity of the asset which should be held by all accounts together - everything minted
+    /// except everything burned. Transfers do not change it.
+    ///
+    /// Returns `Err(String)` if more is burned than minted.
+    pub fn circulating(&self) -> Result<u128, String> {
+        self.total_minted
+            .checked_sub(self.total_burned)
+            .ok_or(format!(
+                "Asset {} has {} burned, which is more than {} minted.",
+                self.id, self.total_burned, self.total_minted
+            ))
+    }
+
+    /// Checks that the definition can be registered: fixed-point values should not have more than
+    /// `MAX_PRECISION` digits after the decimal point and nothing should be minted or burned yet.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.total_minted != 0 || self.total_burned != 0 {
+            return Err(format!(
+                "Asset definition {} should be registered with nothing minted or burned.",
+                self.id
+            ));
+        }
+        match self.value_type {
+            AssetValueType::Fixed(precision) if precision > MAX_PRECISION => Err(format!(
+                "Precision of the asset definition {} is {}, while maximum is {}.",
+                self.id, precision, MAX_PRECISION
+            )),
+            _ => Ok(()),
+        }
     }
 }
 
@@ -37,6 +249,8 @@ pub struct Asset {
     pub quantity: u32,
     /// Asset's Big Quantity associated with an `Account`.
     pub big_quantity: u128,
+    /// Asset's fixed-point decimal quantity associated with an `Account`.
+    pub fixed_quantity: Fixed,
     /// Asset's key-value structured data associated with an `Account`.
     store: BTreeMap<String, String>,
     /// Asset's key-value  (action, object_id) structured permissions associated with an `Account`.
@@ -50,6 +264,7 @@ impl Asset {
             id,
             quantity,
             big_quantity: 0,
+            fixed_quantity: Fixed::zero(0),
             store: BTreeMap::new(),
             permissions: Permissions::new(),
         }
@@ -61,6 +276,19 @@ impl Asset {
             id,
             quantity: 0,
             big_quantity,
+            fixed_quantity: Fixed::zero(0),
+            store: BTreeMap::new(),
+            permissions: Permissions::new(),
+        }
+    }
+
+    /// Constructor with filled `fixed_quantity` field.
+    pub fn with_fixed_quantity(id: <Asset as Identifiable>::Id, fixed_quantity: Fixed) -> Self {
+        Self {
+            id,
+            quantity: 0,
+            big_quantity: 0,
+            fixed_quantity,
             store: BTreeMap::new(),
             permissions: Permissions::new(),
         }
@@ -73,11 +301,46 @@ impl Asset {
             id,
             quantity: 0,
             big_quantity: 0,
+            fixed_quantity: Fixed::zero(0),
             store: BTreeMap::new(),
             permissions,
         }
     }
 
+    /// Constructor with filled `store` field.
+    pub fn with_store(id: <Asset as Identifiable>::Id, store: BTreeMap<String, String>) -> Self {
+        Self {
+            id,
+            quantity: 0,
+            big_quantity: 0,
+            fixed_quantity: Fixed::zero(0),
+            store,
+            permissions: Permissions::new(),
+        }
+    }
+
+    /// Asset's key-value structured data associated with an `Account`.
+    pub fn store(&self) -> &BTreeMap<String, String> {
+        &self.store
+    }
+
+    /// Quantity of the asset held by the account, `quantity`, `big_quantity` and the smallest
+    /// units of `fixed_quantity` are counted.
+    ///
+    /// Returns `Err(String)` if the sum overflows.
+    pub fn balance(&self) -> Result<u128, String> {
+        u128::from(self.quantity)
+            .checked_add(self.big_quantity)
+            .and_then(|balance| balance.checked_add(self.fixed_quantity.mantissa))
+            .ok_or(format!("Balance of the asset {:?} overflows.", self.id))
+    }
+
+    /// Returns `true` if the asset holds nothing: neither a balance, nor a key-value in the
+    /// `store`, nor permissions.
+    pub fn is_empty(&self) -> bool {
+        self.balance() == Ok(0) && self.store.is_empty() && self.permissions.is_empty()
+    }
+
     /// Constructor of the `Mint<Asset, u32>` Iroha Special Instruction.
     pub fn mint(&self, object: u32) -> Mint<Asset, u32> {
         Mint {
@@ -93,6 +356,55 @@ impl Asset {
             destination_id: self.id.clone(),
         }
     }
+
+    /// Constructor of the `Mint<Asset, Fixed>` Iroha Special Instruction.
+    pub fn mint_fixed(&self, object: Fixed) -> Mint<Asset, Fixed> {
+        Mint {
+            object,
+            destination_id: self.id.clone(),
+        }
+    }
+
+    /// Constructor of the `SetKeyValue<Asset, String, String>` Iroha Special Instruction.
+    pub fn set_key_value(&self, key: &str, value: &str) -> SetKeyValue<Asset, String, String> {
+        SetKeyValue {
+            object_id: self.id.clone(),
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// Constructor of the `RemoveKeyValue<Asset, String>` Iroha Special Instruction.
+    pub fn remove_key_value(&self, key: &str) -> RemoveKeyValue<Asset, String> {
+        RemoveKeyValue {
+            object_id: self.id.clone(),
+            key: key.to_string(),
+        }
+    }
+
+    /// Constructor of the `Burn<Asset, u32>` Iroha Special Instruction.
+    pub fn burn(&self, object: u32) -> Burn<Asset, u32> {
+        Burn {
+            object,
+            destination_id: self.id.clone(),
+        }
+    }
+
+    /// Constructor of the `Burn<Asset, u128>` Iroha Special Instruction.
+    pub fn burn_big(&self, object: u128) -> Burn<Asset, u128> {
+        Burn {
+            object,
+            destination_id: self.id.clone(),
+        }
+    }
+
+    /// Constructor of the `Burn<Asset, Fixed>` Iroha Special Instruction.
+    pub fn burn_fixed(&self, object: Fixed) -> Burn<Asset, Fixed> {
+        Burn {
+            object,
+            destination_id: self.id.clone(),
+        }
+    }
 }
 
 /// Identification of an Asset Definition. Consists of Asset's name and Domain's name.
@@ -172,6 +484,41 @@ pub mod isi {
         MintAsset(u32, <Asset as Identifiable>::Id),
         /// Variant of the generic `Mint` instruction for `u128` --> `Asset`.
         MintBigAsset(u128, <Asset as Identifiable>::Id),
+        /// Variant of the generic `Burn` instruction for `u32` --> `Asset`.
+        BurnAsset(u32, <Asset as Identifiable>::Id),
+        /// Variant of the generic `Burn` instruction for `u128` --> `Asset`.
+        BurnBigAsset(u128, <Asset as Identifiable>::Id),
+        /// Variant of the generic `Mint` instruction for `Fixed` --> `Asset`.
+        MintFixedAsset(Fixed, <Asset as Identifiable>::Id),
+        /// Variant of the generic `Burn` instruction for `Fixed` --> `Asset`.
+        BurnFixedAsset(Fixed, <Asset as Identifiable>::Id),
+        /// Variant of the generic `SetKeyValue` instruction for `Asset` with `String` keys and
+        /// values.
+        SetKeyValue(<Asset as Identifiable>::Id, String, String),
+        /// Variant of the generic `RemoveKeyValue` instruction for `Asset` with `String` keys.
+        RemoveKeyValue(<Asset as Identifiable>::Id, String),
+        /// Variant of the generic `SetKeyValue` instruction for `AssetDefinition` metadata.
+        SetAssetDefinitionKeyValue(<AssetDefinition as Identifiable>::Id, String, String),
+        /// Variant of the generic `RemoveKeyValue` instruction for `AssetDefinition` metadata.
+        RemoveAssetDefinitionKeyValue(<AssetDefinition as Identifiable>::Id, String),
+        /// Variant of the generic `Transfer` instruction for `Asset` --`u32`--> `Asset`.
+        TransferAsset(
+            <Asset as Identifiable>::Id,
+            u32,
+            <Asset as Identifiable>::Id,
+        ),
+        /// Variant of the generic `Transfer` instruction for `Asset` --`u128`--> `Asset`.
+        TransferBigAsset(
+            <Asset as Identifiable>::Id,
+            u128,
+            <Asset as Identifiable>::Id,
+        ),
+        /// Variant of the generic `Transfer` instruction for `Asset` --`Fixed`--> `Asset`.
+        TransferFixedAsset(
+            <Asset as Identifiable>::Id,
+            Fixed,
+            <Asset as Identifiable>::Id,
+        ),
     }
 
     impl AssetInstruction {
@@ -189,6 +536,71 @@ pub mod isi {
                 AssetInstruction::MintBigAsset(big_quantity, asset_id) => {
                     Mint::new(*big_quantity, asset_id.clone()).execute(authority, world_state_view)
                 }
+                AssetInstruction::BurnAsset(quantity, asset_id) => {
+                    Burn::new(*quantity, asset_id.clone()).execute(authority, world_state_view)
+                }
+                AssetInstruction::BurnBigAsset(big_quantity, asset_id) => {
+                    Burn::new(*big_quantity, asset_id.clone()).execute(authority, world_state_view)
+                }
+                AssetInstruction::MintFixedAsset(fixed_quantity, asset_id) => {
+                    Mint::new(*fixed_quantity, asset_id.clone())
+                        .execute(authority, world_state_view)
+                }
+                AssetInstruction::BurnFixedAsset(fixed_quantity, asset_id) => {
+                    Burn::new(*fixed_quantity, asset_id.clone())
+                        .execute(authority, world_state_view)
+                }
+                AssetInstruction::SetKeyValue(asset_id, key, value) => {
+                    SetKeyValue::<Asset, String, String>::new(
+                        asset_id.clone(),
+                        key.clone(),
+                        value.clone(),
+                    )
+                    .execute(authority, world_state_view)
+                }
+                AssetInstruction::RemoveKeyValue(asset_id, key) => {
+                    RemoveKeyValue::<Asset, String>::new(asset_id.clone(), key.clone())
+                        .execute(authority, world_state_view)
+                }
+                AssetInstruction::SetAssetDefinitionKeyValue(asset_definition_id, key, value) => {
+                    SetKeyValue::<AssetDefinition, String, String>::new(
+                        asset_definition_id.clone(),
+                        key.clone(),
+                        value.clone(),
+                    )
+                    .execute(authority, world_state_view)
+                }
+                AssetInstruction::RemoveAssetDefinitionKeyValue(asset_definition_id, key) => {
+                    RemoveKeyValue::<AssetDefinition, String>::new(
+                        asset_definition_id.clone(),
+                        key.clone(),
+                    )
+                    .execute(authority, world_state_view)
+                }
+                AssetInstruction::TransferAsset(source_id, quantity, destination_id) => {
+                    Transfer::<Asset, u32, Asset>::new(
+                        source_id.clone(),
+                        *quantity,
+                        destination_id.clone(),
+                    )
+                    .execute(authority, world_state_view)
+                }
+                AssetInstruction::TransferBigAsset(source_id, big_quantity, destination_id) => {
+                    Transfer::<Asset, u128, Asset>::new(
+                        source_id.clone(),
+                        *big_quantity,
+                        destination_id.clone(),
+                    )
+                    .execute(authority, world_state_view)
+                }
+                AssetInstruction::TransferFixedAsset(source_id, fixed_quantity, destination_id) => {
+                    Transfer::<Asset, Fixed, Asset>::new(
+                        source_id.clone(),
+                        *fixed_quantity,
+                        destination_id.clone(),
+                    )
+                    .execute(authority, world_state_view)
+                }
             }
         }
     }
@@ -205,17 +617,29 @@ pub mod isi {
                 None,
             )
             .execute(world_state_view)?;
-            world_state_view
-                .asset_definition(&self.destination_id.definition_id)
-                .ok_or("Failed to find asset.")?;
+            check_value_type(
+                world_state_view,
+                &self.destination_id.definition_id,
+                AssetValueType::Quantity,
+            )?;
+            let total_minted =
+                total_minted_after(world_state_view, &self.destination_id, self.object.into())?;
+            let quantity = world_state_view
+                .read_asset(&self.destination_id)
+                .map_or(0, |asset| asset.quantity);
+            let quantity = quantity.checked_add(self.object).ok_or_else(|| {
+                format!(
+                    "Quantity of the asset {:?} overflows: {} + {}.",
+                    self.destination_id, quantity, self.object
+                )
+            })?;
+            set_total_minted(world_state_view, &self.destination_id, total_minted)?;
             match world_state_view.asset(&self.destination_id) {
                 Some(asset) => {
-                    asset.quantity += self.object;
+                    asset.quantity = quantity;
                 }
-                None => world_state_view.add_asset(Asset::with_quantity(
-                    self.destination_id.clone(),
-                    self.object,
-                )),
+                None => world_state_view
+                    .add_asset(Asset::with_quantity(self.destination_id.clone(), quantity))?,
             }
             Ok(())
         }
@@ -233,86 +657,1894 @@ pub mod isi {
                 None,
             )
             .execute(world_state_view)?;
-            world_state_view
-                .asset_definition(&self.destination_id.definition_id)
-                .ok_or("Failed to find asset.")?;
+            check_value_type(
+                world_state_view,
+                &self.destination_id.definition_id,
+                AssetValueType::BigQuantity,
+            )?;
+            let total_minted =
+                total_minted_after(world_state_view, &self.destination_id, self.object)?;
+            let big_quantity = world_state_view
+                .read_asset(&self.destination_id)
+                .map_or(0, |asset| asset.big_quantity);
+            let big_quantity = big_quantity.checked_add(self.object).ok_or_else(|| {
+                format!(
+                    "Big quantity of the asset {:?} overflows: {} + {}.",
+                    self.destination_id, big_quantity, self.object
+                )
+            })?;
+            set_total_minted(world_state_view, &self.destination_id, total_minted)?;
             match world_state_view.asset(&self.destination_id) {
                 Some(asset) => {
-                    asset.big_quantity += self.object;
+                    asset.big_quantity = big_quantity;
                 }
                 None => world_state_view.add_asset(Asset::with_big_quantity(
                     self.destination_id.clone(),
-                    self.object,
-                )),
+                    big_quantity,
+                ))?,
             }
             Ok(())
         }
     }
 
-    impl From<Mint<Asset, u32>> for Instruction {
-        fn from(instruction: Mint<Asset, u32>) -> Self {
-            Instruction::Asset(AssetInstruction::MintAsset(
-                instruction.object,
-                instruction.destination_id,
-            ))
+    impl Mint<Asset, Fixed> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanMintAsset(
+                authority,
+                self.destination_id.definition_id.clone(),
+                None,
+            )
+            .execute(world_state_view)?;
+            check_value_type(
+                world_state_view,
+                &self.destination_id.definition_id,
+                AssetValueType::Fixed(self.object.precision),
+            )?;
+            let total_minted =
+                total_minted_after(world_state_view, &self.destination_id, self.object.mantissa)?;
+            let fixed_quantity = world_state_view
+                .read_asset(&self.destination_id)
+                .map_or(Fixed::zero(self.object.precision), |asset| {
+                    asset.fixed_quantity
+                });
+            let fixed_quantity = fixed_quantity.checked_add(self.object).ok_or_else(|| {
+                format!(
+                    "Fixed quantity of the asset {:?} overflows: {} + {}.",
+                    self.destination_id, fixed_quantity, self.object
+                )
+            })?;
+            set_total_minted(world_state_view, &self.destination_id, total_minted)?;
+            match world_state_view.asset(&self.destination_id) {
+                Some(asset) => {
+                    asset.fixed_quantity = fixed_quantity;
+                }
+                None => world_state_view.add_asset(Asset::with_fixed_quantity(
+                    self.destination_id.clone(),
+                    fixed_quantity,
+                ))?,
+            }
+            Ok(())
         }
     }
-}
 
-/// Query module provides `IrohaQuery` Asset related implementations.
-pub mod query {
-    use super::*;
-    use crate::query::IrohaQuery;
-    use iroha_derive::{IntoQuery, Io};
-    use parity_scale_codec::{Decode, Encode};
-    use std::time::SystemTime;
+    impl Burn<Asset, u32> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanBurnAsset(
+                authority,
+                self.destination_id.definition_id.clone(),
+                None,
+            )
+            .execute(world_state_view)?;
+            check_value_type(
+                world_state_view,
+                &self.destination_id.definition_id,
+                AssetValueType::Quantity,
+            )?;
+            let total_burned =
+                total_burned_after(world_state_view, &self.destination_id, self.object.into())?;
+            let asset = world_state_view
+                .asset(&self.destination_id)
+                .ok_or("Failed to find asset.")?;
+            asset.quantity = asset.quantity.checked_sub(self.object).ok_or_else(|| {
+                format!(
+                    "Insufficient asset quantity to burn: {} held, {} requested.",
+                    asset.quantity, self.object
+                )
+            })?;
+            world_state_view
+                .asset_definition(&self.destination_id.definition_id)
+                .ok_or("Failed to find asset.")?
+                .total_burned = total_burned;
+            Ok(())
+        }
+    }
 
-    /// To get the state of all assets in an account (a balance),
-    /// GetAccountAssets query can be used.
-    #[derive(Debug, Io, IntoQuery, Encode, Decode)]
-    pub struct GetAccountAssets {
-        account_id: <Account as Identifiable>::Id,
+    impl Burn<Asset, u128> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanBurnAsset(
+                authority,
+                self.destination_id.definition_id.clone(),
+                None,
+            )
+            .execute(world_state_view)?;
+            check_value_type(
+                world_state_view,
+                &self.destination_id.definition_id,
+                AssetValueType::BigQuantity,
+            )?;
+            let total_burned =
+                total_burned_after(world_state_view, &self.destination_id, self.object)?;
+            let asset = world_state_view
+                .asset(&self.destination_id)
+                .ok_or("Failed to find asset.")?;
+            asset.big_quantity = asset.big_quantity.checked_sub(self.object).ok_or_else(|| {
+                format!(
+                    "Insufficient asset big quantity to burn: {} held, {} requested.",
+                    asset.big_quantity, self.object
+                )
+            })?;
+            world_state_view
+                .asset_definition(&self.destination_id.definition_id)
+                .ok_or("Failed to find asset.")?
+                .total_burned = total_burned;
+            Ok(())
+        }
     }
 
-    /// Result of the `GetAccountAssets` execution.
-    #[derive(Debug, Encode, Decode)]
-    pub struct GetAccountAssetsResult {
-        /// Assets types which are needed to be included in query result.
-        pub assets: Vec<Asset>,
+    impl Burn<Asset, Fixed> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanBurnAsset(
+                authority,
+                self.destination_id.definition_id.clone(),
+                None,
+            )
+            .execute(world_state_view)?;
+            check_value_type(
+                world_state_view,
+                &self.destination_id.definition_id,
+                AssetValueType::Fixed(self.object.precision),
+            )?;
+            let total_burned =
+                total_burned_after(world_state_view, &self.destination_id, self.object.mantissa)?;
+            let asset = world_state_view
+                .asset(&self.destination_id)
+                .ok_or("Failed to find asset.")?;
+            asset.fixed_quantity =
+                asset
+                    .fixed_quantity
+                    .checked_sub(self.object)
+                    .ok_or_else(|| {
+                        format!(
+                            "Insufficient asset fixed quantity to burn: {} held, {} requested.",
+                            asset.fixed_quantity, self.object
+                        )
+                    })?;
+            world_state_view
+                .asset_definition(&self.destination_id.definition_id)
+                .ok_or("Failed to find asset.")?
+                .total_burned = total_burned;
+            Ok(())
+        }
     }
 
-    impl GetAccountAssets {
-        /// Build a `GetAccountAssets` query in the form of a `QueryRequest`.
-        pub fn build_request(account_id: <Account as Identifiable>::Id) -> QueryRequest {
-            let query = GetAccountAssets { account_id };
-            QueryRequest {
-                timestamp: SystemTime::now()
-                    .duration_since(SystemTime::UNIX_EPOCH)
-                    .expect("Failed to get System Time.")
-                    .as_millis()
-                    .to_string(),
-                signature: Option::None,
-                query: query.into(),
+    impl Transfer<Asset, u32, Asset> {
+        pub(crate) fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            check_transfer(
+                authority,
+                &self.source_id,
+                &self.destination_id,
+                world_state_view,
+            )?;
+            check_value_type(
+                world_state_view,
+                &self.source_id.definition_id,
+                AssetValueType::Quantity,
+            )?;
+            let source_quantity = world_state_view
+                .read_asset(&self.source_id)
+                .ok_or("Asset's component was not found.")?
+                .quantity;
+            let source_quantity = source_quantity.checked_sub(self.object).ok_or_else(|| {
+                format!(
+                    "Insufficient asset quantity to transfer: {} held, {} requested.",
+                    source_quantity, self.object
+                )
+            })?;
+            if self.source_id == self.destination_id {
+                return Ok(());
+            }
+            let destination_quantity = world_state_view
+                .read_asset(&self.destination_id)
+                .map_or(0, |asset| asset.quantity);
+            let destination_quantity =
+                destination_quantity
+                    .checked_add(self.object)
+                    .ok_or_else(|| {
+                        format!(
+                            "Quantity of the asset {:?} overflows: {} + {}.",
+                            self.destination_id, destination_quantity, self.object
+                        )
+                    })?;
+            world_state_view
+                .asset(&self.source_id)
+                .ok_or("Asset's component was not found.")?
+                .quantity = source_quantity;
+            match world_state_view.asset(&self.destination_id) {
+                Some(asset) => {
+                    asset.quantity = destination_quantity;
+                }
+                None => world_state_view.add_asset(Asset::with_quantity(
+                    self.destination_id.clone(),
+                    destination_quantity,
+                ))?,
             }
+            Ok(())
         }
     }
 
-    impl Query for GetAccountAssets {
-        #[log]
-        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, String> {
-            let assets: Vec<Asset> = world_state_view
-                .read_account(&self.account_id)
-                .ok_or(format!(
-                    "No account with id: {:?} found in the current world state: {:?}.",
-                    &self.account_id, world_state_view
-                ))?
-                .assets
-                .values()
-                .cloned()
-                .collect();
-            Ok(QueryResult::GetAccountAssets(GetAccountAssetsResult {
-                assets,
-            }))
+    impl Transfer<Asset, u128, Asset> {
+        pub(crate) fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            check_transfer(
+                authority,
+                &self.source_id,
+                &self.destination_id,
+                world_state_view,
+            )?;
+            check_value_type(
+                world_state_view,
+                &self.source_id.definition_id,
+                AssetValueType::BigQuantity,
+            )?;
+            let source_big_quantity = world_state_view
+                .read_asset(&self.source_id)
+                .ok_or("Asset's component was not found.")?
+                .big_quantity;
+            let source_big_quantity =
+                source_big_quantity
+                    .checked_sub(self.object)
+                    .ok_or_else(|| {
+                        format!(
+                            "Insufficient asset big quantity to transfer: {} held, {} requested.",
+                            source_big_quantity, self.object
+                        )
+                    })?;
+            if self.source_id == self.destination_id {
+                return Ok(());
+            }
+            let destination_big_quantity = world_state_view
+                .read_asset(&self.destination_id)
+                .map_or(0, |asset| asset.big_quantity);
+            let destination_big_quantity = destination_big_quantity
+                .checked_add(self.object)
+                .ok_or_else(|| {
+                    format!(
+                        "Big quantity of the asset {:?} overflows: {} + {}.",
+                        self.destination_id, destination_big_quantity, self.object
+                    )
+                })?;
+            world_state_view
+                .asset(&self.source_id)
+                .ok_or("Asset's component was not found.")?
+                .big_quantity = source_big_quantity;
+            match world_state_view.asset(&self.destination_id) {
+                Some(asset) => {
+                    asset.big_quantity = destination_big_quantity;
+                }
+                None => world_state_view.add_asset(Asset::with_big_quantity(
+                    self.destination_id.clone(),
+                    destination_big_quantity,
+                ))?,
+            }
+            Ok(())
+        }
+    }
+
+    impl Transfer<Asset, Fixed, Asset> {
+        pub(crate) fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            check_transfer(
+                authority,
+                &self.source_id,
+                &self.destination_id,
+                world_state_view,
+            )?;
+            check_value_type(
+                world_state_view,
+                &self.source_id.definition_id,
+                AssetValueType::Fixed(self.object.precision),
+            )?;
+            let source_fixed_quantity = world_state_view
+                .read_asset(&self.source_id)
+                .ok_or("Asset's component was not found.")?
+                .fixed_quantity;
+            let source_fixed_quantity =
+                source_fixed_quantity
+                    .checked_sub(self.object)
+                    .ok_or_else(|| {
+                        format!(
+                            "Insufficient asset fixed quantity to transfer: {} held, {} requested.",
+                            source_fixed_quantity, self.object
+                        )
+                    })?;
+            if self.source_id == self.destination_id {
+                return Ok(());
+            }
+            let destination_fixed_quantity = world_state_view
+                .read_asset(&self.destination_id)
+                .map_or(Fixed::zero(self.object.precision), |asset| {
+                    asset.fixed_quantity
+                });
+            let destination_fixed_quantity = destination_fixed_quantity
+                .checked_add(self.object)
+                .ok_or_else(|| {
+                    format!(
+                        "Fixed quantity of the asset {:?} overflows: {} + {}.",
+                        self.destination_id, destination_fixed_quantity, self.object
+                    )
+                })?;
+            world_state_view
+                .asset(&self.source_id)
+                .ok_or("Asset's component was not found.")?
+                .fixed_quantity = source_fixed_quantity;
+            match world_state_view.asset(&self.destination_id) {
+                Some(asset) => {
+                    asset.fixed_quantity = destination_fixed_quantity;
+                }
+                None => world_state_view.add_asset(Asset::with_fixed_quantity(
+                    self.destination_id.clone(),
+                    destination_fixed_quantity,
+                ))?,
+            }
+            Ok(())
+        }
+    }
+
+    impl SetKeyValue<Asset, String, String> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanSetKeyValueInAsset(authority, self.object_id.clone())
+                .execute(world_state_view)?;
+            check_value_type(
+                world_state_view,
+                &self.object_id.definition_id,
+                AssetValueType::Store,
+            )?;
+            world_state_view
+                .read_account(&self.object_id.account_id)
+                .ok_or("Failed to find account.")?;
+            let store_limits = world_state_view.store_limits();
+            match world_state_view.asset(&self.object_id) {
+                Some(asset) => {
+                    store_limits.check(&asset.store, &self.key, &self.value)?;
+                    asset.store.insert(self.key.clone(), self.value.clone());
+                }
+                None => {
+                    let mut store = BTreeMap::new();
+                    store_limits.check(&store, &self.key, &self.value)?;
+                    store.insert(self.key.clone(), self.value.clone());
+                    world_state_view.add_asset(Asset::with_store(self.object_id.clone(), store))?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl RemoveKeyValue<Asset, String> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanSetKeyValueInAsset(authority, self.object_id.clone())
+                .execute(world_state_view)?;
+            check_value_type(
+                world_state_view,
+                &self.object_id.definition_id,
+                AssetValueType::Store,
+            )?;
+            world_state_view
+                .asset(&self.object_id)
+                .ok_or("Failed to find asset.")?
+                .store
+                .remove(&self.key)
+                .ok_or_else(|| format!("No value with key {} found.", self.key))?;
+            Ok(())
+        }
+    }
+
+    impl SetKeyValue<AssetDefinition, String, String> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanSetKeyValueInAssetDefinition(
+                authority,
+                self.object_id.clone(),
+            )
+            .execute(world_state_view)?;
+            let store_limits = world_state_view.store_limits();
+            let asset_definition = world_state_view
+                .asset_definition(&self.object_id)
+                .ok_or("Failed to find asset definition.")?;
+            store_limits.insert(
+                &mut asset_definition.metadata,
+                self.key.clone(),
+                self.value.clone(),
+            )
+        }
+    }
+
+    impl RemoveKeyValue<AssetDefinition, String> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanSetKeyValueInAssetDefinition(
+                authority,
+                self.object_id.clone(),
+            )
+            .execute(world_state_view)?;
+            world_state_view
+                .asset_definition(&self.object_id)
+                .ok_or("Failed to find asset definition.")?
+                .metadata
+                .remove(&self.key)
+                .ok_or_else(|| format!("No value with key {} found.", self.key))?;
+            Ok(())
+        }
+    }
+
+    /// Checks that the `authority` can transfer the asset and that the source and the destination
+    /// hold assets of the same definition.
+    fn check_transfer(
+        authority: <Account as Identifiable>::Id,
+        source_id: &<Asset as Identifiable>::Id,
+        destination_id: &<Asset as Identifiable>::Id,
+        world_state_view: &WorldStateView,
+    ) -> Result<(), String> {
+        PermissionInstruction::CanTransferAsset(authority, source_id.definition_id.clone(), None)
+            .execute(world_state_view)?;
+        if source_id.definition_id != destination_id.definition_id {
+            return Err(format!(
+                "Assets of different definitions can not be transferred: {:?} to {:?}.",
+                source_id.definition_id, destination_id.definition_id
+            ));
+        }
+        world_state_view
+            .read_account(&destination_id.account_id)
+            .ok_or("Failed to find destination account.")?;
+        Ok(())
+    }
+
+    /// Checks that the assets of the definition hold values of the `expected` type.
+    fn check_value_type(
+        world_state_view: &WorldStateView,
+        definition_id: &<AssetDefinition as Identifiable>::Id,
+        expected: AssetValueType,
+    ) -> Result<(), String> {
+        let value_type = world_state_view
+            .read_asset_definition(definition_id)
+            .ok_or("Failed to find asset.")?
+            .value_type;
+        if value_type == expected {
+            Ok(())
+        } else {
+            Err(format!(
+                "Asset definition {} holds values of type {}, not {}.",
+                definition_id, value_type, expected
+            ))
+        }
+    }
+
+    /// Total minted quantity of the asset's definition after minting `amount` more of it.
+    /// Returns `Err(String)` instead of overflowing, if the asset can not be minted anymore or if
+    /// the account to mint the asset to does not exist.
+    fn total_minted_after(
+        world_state_view: &WorldStateView,
+        asset_id: &<Asset as Identifiable>::Id,
+        amount: u128,
+    ) -> Result<u128, String> {
+        world_state_view
+            .read_account(&asset_id.account_id)
+            .ok_or("Failed to find account.")?;
+        let asset_definition = world_state_view
+            .read_asset_definition(&asset_id.definition_id)
+            .ok_or("Failed to find asset.")?;
+        if asset_definition.mintable == Mintable::Not {
+            return Err(format!(
+                "Asset definition {} is not mintable.",
+                asset_id.definition_id
+            ));
+        }
+        let total_minted = asset_definition.total_minted;
+        total_minted.checked_add(amount).ok_or_else(|| {
+            format!(
+                "Total minted quantity of the asset definition {:?} overflows: {} + {}.",
+                asset_id.definition_id, total_minted, amount
+            )
+        })
+    }
+
+    /// Records the `total_minted` quantity of the asset's definition after a successful mint.
+    /// Assets mintable `Once` become `Not` mintable.
+    fn set_total_minted(
+        world_state_view: &mut WorldStateView,
+        asset_id: &<Asset as Identifiable>::Id,
+        total_minted: u128,
+    ) -> Result<(), String> {
+        let asset_definition = world_state_view
+            .asset_definition(&asset_id.definition_id)
+            .ok_or("Failed to find asset.")?;
+        asset_definition.total_minted = total_minted;
+        if asset_definition.mintable == Mintable::Once {
+            asset_definition.mintable = Mintable::Not;
+        }
+        Ok(())
+    }
+
+    /// Total burned quantity of the asset's definition after burning `amount` more of it.
+    /// Returns `Err(String)` instead of overflowing.
+    fn total_burned_after(
+        world_state_view: &WorldStateView,
+        asset_id: &<Asset as Identifiable>::Id,
+        amount: u128,
+    ) -> Result<u128, String> {
+        let total_burned = world_state_view
+            .read_asset_definition(&asset_id.definition_id)
+            .ok_or("Failed to find asset.")?
+            .total_burned;
+        total_burned.checked_add(amount).ok_or_else(|| {
+            format!(
+                "Total burned quantity of the asset definition {:?} overflows: {} + {}.",
+                asset_id.definition_id, total_burned, amount
+            )
+        })
+    }
+
+    impl From<Mint<Asset, u32>> for Instruction {
+        fn from(instruction: Mint<Asset, u32>) -> Self {
+            Instruction::Asset(AssetInstruction::MintAsset(
+                instruction.object,
+                instruction.destination_id,
+            ))
+        }
+    }
+
+    impl From<Mint<Asset, u128>> for Instruction {
+        fn from(instruction: Mint<Asset, u128>) -> Self {
+            Instruction::Asset(AssetInstruction::MintBigAsset(
+                instruction.object,
+                instruction.destination_id,
+            ))
+        }
+    }
+
+    impl From<Burn<Asset, u32>> for Instruction {
+        fn from(instruction: Burn<Asset, u32>) -> Self {
+            Instruction::Asset(AssetInstruction::BurnAsset(
+                instruction.object,
+                instruction.destination_id,
+            ))
+        }
+    }
+
+    impl From<Burn<Asset, u128>> for Instruction {
+        fn from(instruction: Burn<Asset, u128>) -> Self {
+            Instruction::Asset(AssetInstruction::BurnBigAsset(
+                instruction.object,
+                instruction.destination_id,
+            ))
+        }
+    }
+
+    impl From<Transfer<Asset, u32, Asset>> for Instruction {
+        fn from(instruction: Transfer<Asset, u32, Asset>) -> Self {
+            Instruction::Asset(AssetInstruction::TransferAsset(
+                instruction.source_id,
+                instruction.object,
+                instruction.destination_id,
+            ))
+        }
+    }
+
+    impl From<Transfer<Asset, u128, Asset>> for Instruction {
+        fn from(instruction: Transfer<Asset, u128, Asset>) -> Self {
+            Instruction::Asset(AssetInstruction::TransferBigAsset(
+                instruction.source_id,
+                instruction.object,
+                instruction.destination_id,
+            ))
+        }
+    }
+
+    impl From<SetKeyValue<Asset, String, String>> for Instruction {
+        fn from(instruction: SetKeyValue<Asset, String, String>) -> Self {
+            Instruction::Asset(AssetInstruction::SetKeyValue(
+                instruction.object_id,
+                instruction.key,
+                instruction.value,
+            ))
+        }
+    }
+
+    impl From<RemoveKeyValue<Asset, String>> for Instruction {
+        fn from(instruction: RemoveKeyValue<Asset, String>) -> Self {
+            Instruction::Asset(AssetInstruction::RemoveKeyValue(
+                instruction.object_id,
+                instruction.key,
+            ))
+        }
+    }
+
+    impl From<SetKeyValue<AssetDefinition, String, String>> for Instruction {
+        fn from(instruction: SetKeyValue<AssetDefinition, String, String>) -> Self {
+            Instruction::Asset(AssetInstruction::SetAssetDefinitionKeyValue(
+                instruction.object_id,
+                instruction.key,
+                instruction.value,
+            ))
+        }
+    }
+
+    impl From<RemoveKeyValue<AssetDefinition, String>> for Instruction {
+        fn from(instruction: RemoveKeyValue<AssetDefinition, String>) -> Self {
+            Instruction::Asset(AssetInstruction::RemoveAssetDefinitionKeyValue(
+                instruction.object_id,
+                instruction.key,
+            ))
+        }
+    }
+
+    impl From<Mint<Asset, Fixed>> for Instruction {
+        fn from(instruction: Mint<Asset, Fixed>) -> Self {
+            Instruction::Asset(AssetInstruction::MintFixedAsset(
+                instruction.object,
+                instruction.destination_id,
+            ))
+        }
+    }
+
+    impl From<Burn<Asset, Fixed>> for Instruction {
+        fn from(instruction: Burn<Asset, Fixed>) -> Self {
+            Instruction::Asset(AssetInstruction::BurnFixedAsset(
+                instruction.object,
+                instruction.destination_id,
+            ))
+        }
+    }
+
+    impl From<Transfer<Asset, Fixed, Asset>> for Instruction {
+        fn from(instruction: Transfer<Asset, Fixed, Asset>) -> Self {
+            Instruction::Asset(AssetInstruction::TransferFixedAsset(
+                instruction.source_id,
+                instruction.object,
+                instruction.destination_id,
+            ))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{isi, isi::fixtures::*};
+
+        #[test]
+        fn quantity_changes_should_fail_on_overflow_instead_of_wrapping() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let xor_id = AssetId {
+                definition_id: AssetDefinitionId::new("xor", "global"),
+                account_id: root_id.clone(),
+            };
+            mint_xor(u32::MAX)
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            assert!(mint_xor(1)
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            assert_eq!(Some(u32::MAX), xor_quantity(&mut world_state_view));
+            let btc_definition_id = AssetDefinitionId::new("btc", "global");
+            let btc_id = AssetId {
+                definition_id: btc_definition_id.clone(),
+                account_id: root_id.clone(),
+            };
+            Instruction::from(isi::Mint::<Asset, u128>::new(u128::MAX, btc_id.clone()))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint big asset.");
+            assert!(
+                Instruction::from(isi::Mint::<Asset, u128>::new(1, btc_id.clone()))
+                    .execute(root_id.clone(), &mut world_state_view)
+                    .is_err()
+            );
+            assert_eq!(
+                Some(u128::MAX),
+                world_state_view
+                    .read_asset(&btc_id)
+                    .map(|asset| asset.big_quantity)
+            );
+            assert_eq!(
+                Ok(u128::MAX),
+                world_state_view.check_asset_supply(&btc_definition_id)
+            );
+            assert!(
+                Instruction::from(isi::Burn::<Asset, u32>::new(u32::MAX, xor_id.clone()))
+                    .execute(root_id.clone(), &mut world_state_view)
+                    .is_ok()
+            );
+            assert!(Instruction::from(isi::Burn::<Asset, u32>::new(1, xor_id))
+                .execute(root_id, &mut world_state_view)
+                .is_err());
+            assert_eq!(Some(0), xor_quantity(&mut world_state_view));
+        }
+
+        #[test]
+        fn random_quantity_changes_should_follow_checked_arithmetic() {
+            use rand::{rngs::StdRng, Rng, SeedableRng};
+
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            let xor_definition_id = AssetDefinitionId::new("xor", "global");
+            let xor_id = AssetId {
+                definition_id: xor_definition_id.clone(),
+                account_id: root_id.clone(),
+            };
+            mint_xor(0)
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            let edges = [0, 1, u32::MAX / 2, u32::MAX - 1, u32::MAX];
+            let mut rng = StdRng::seed_from_u64(42);
+            let mut root_quantity = 0u32;
+            let mut alice_quantity: Option<u32> = None;
+            for _ in 0..1000 {
+                let amount = if rng.gen() {
+                    edges[rng.gen_range(0, edges.len())]
+                } else {
+                    rng.gen()
+                };
+                let (instruction, expected) = match rng.gen_range(0, 3) {
+                    0 => (
+                        mint_xor(amount),
+                        root_quantity
+                            .checked_add(amount)
+                            .map(|quantity| (quantity, alice_quantity)),
+                    ),
+                    1 => (
+                        isi::Burn::<Asset, u32>::new(amount, xor_id.clone()).into(),
+                        root_quantity
+                            .checked_sub(amount)
+                            .map(|quantity| (quantity, alice_quantity)),
+                    ),
+                    _ => (
+                        isi::Transfer::<Account, Asset, Account>::new(
+                            root_id.clone(),
+                            Asset::with_quantity(xor_id.clone(), amount),
+                            alice_id.clone(),
+                        )
+                        .into(),
+                        root_quantity.checked_sub(amount).and_then(|quantity| {
+                            alice_quantity
+                                .map_or(Some(amount), |alice_quantity| {
+                                    alice_quantity.checked_add(amount)
+                                })
+                                .map(|alice_quantity| (quantity, Some(alice_quantity)))
+                        }),
+                    ),
+                };
+                let result = instruction.execute(root_id.clone(), &mut world_state_view);
+                assert_eq!(expected.is_some(), result.is_ok(), "{:?}", result);
+                if let Some((expected_root_quantity, expected_alice_quantity)) = expected {
+                    root_quantity = expected_root_quantity;
+                    alice_quantity = expected_alice_quantity;
+                }
+                assert_eq!(Some(root_quantity), xor_quantity(&mut world_state_view));
+                assert_eq!(
+                    Ok(u128::from(root_quantity) + u128::from(alice_quantity.unwrap_or(0))),
+                    world_state_view.check_asset_supply(&xor_definition_id)
+                );
+            }
+        }
+
+        #[test]
+        fn asset_operations_should_match_the_asset_definition() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let xor_id = AssetId {
+                definition_id: AssetDefinitionId::new("xor", "global"),
+                account_id: root_id.clone(),
+            };
+            let btc_id = AssetId {
+                definition_id: AssetDefinitionId::new("btc", "global"),
+                account_id: root_id.clone(),
+            };
+            assert!(
+                Instruction::from(isi::Mint::<Asset, u128>::new(10, xor_id.clone()))
+                    .execute(root_id.clone(), &mut world_state_view)
+                    .is_err()
+            );
+            assert!(
+                Instruction::from(isi::Mint::<Asset, u32>::new(10, btc_id.clone()))
+                    .execute(root_id.clone(), &mut world_state_view)
+                    .is_err()
+            );
+            mint_xor(10)
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            assert!(
+                Instruction::from(isi::Transfer::<Account, Asset, Account>::new(
+                    root_id.clone(),
+                    Asset::with_big_quantity(xor_id.clone(), 1),
+                    root_id.clone(),
+                ))
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err()
+            );
+            assert!(Instruction::from(isi::Transfer::<Asset, u32, Asset>::new(
+                btc_id.clone(),
+                0,
+                btc_id,
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .is_err());
+            let gold_definition_id = AssetDefinitionId::new("gold", "global");
+            Instruction::from(isi::Register::<Domain, AssetDefinition>::new(
+                AssetDefinition::with_value_type(
+                    gold_definition_id.clone(),
+                    AssetValueType::Quantity,
+                    Mintable::Once,
+                ),
+                "global".to_string(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to register asset definition.");
+            let gold_id = AssetId {
+                definition_id: gold_definition_id.clone(),
+                account_id: root_id.clone(),
+            };
+            Instruction::from(isi::Mint::<Asset, u32>::new(100, gold_id.clone()))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            assert!(Instruction::from(isi::Mint::<Asset, u32>::new(1, gold_id))
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            assert_eq!(
+                Some(Mintable::Not),
+                world_state_view
+                    .read_asset_definition(&gold_definition_id)
+                    .map(|asset_definition| asset_definition.mintable)
+            );
+            assert!(
+                Instruction::from(isi::Register::<Domain, AssetDefinition>::new(
+                    AssetDefinition::with_value_type(
+                        AssetDefinitionId::new("usd", "global"),
+                        AssetValueType::Fixed(MAX_PRECISION + 1),
+                        Mintable::Infinitely,
+                    ),
+                    "global".to_string(),
+                ))
+                .execute(root_id, &mut world_state_view)
+                .is_err()
+            );
+        }
+
+        #[test]
+        fn fixed_quantities_should_follow_the_definition_precision() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            let usd_definition_id = AssetDefinitionId::new("usd", "global");
+            Instruction::from(isi::Register::<Domain, AssetDefinition>::new(
+                AssetDefinition::with_value_type(
+                    usd_definition_id.clone(),
+                    AssetValueType::Fixed(2),
+                    Mintable::Infinitely,
+                ),
+                "global".to_string(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to register asset definition.");
+            let root_usd_id = AssetId {
+                definition_id: usd_definition_id.clone(),
+                account_id: root_id.clone(),
+            };
+            let alice_usd_id = AssetId {
+                definition_id: usd_definition_id.clone(),
+                account_id: alice_id,
+            };
+            let amount = |string: &str| Fixed::parse(string, 2).expect("Failed to parse amount.");
+            assert!(Instruction::from(isi::Mint::<Asset, Fixed>::new(
+                Fixed::parse("1.5", 1).expect("Failed to parse amount."),
+                root_usd_id.clone(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .is_err());
+            Instruction::from(isi::Mint::<Asset, Fixed>::new(
+                amount("100.10"),
+                root_usd_id.clone(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to mint fixed asset.");
+            Instruction::from(isi::Transfer::<Asset, Fixed, Asset>::new(
+                root_usd_id.clone(),
+                amount("0.35"),
+                alice_usd_id.clone(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to transfer fixed asset.");
+            assert!(Instruction::from(isi::Burn::<Asset, Fixed>::new(
+                amount("99.76"),
+                root_usd_id.clone(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .is_err());
+            Instruction::from(isi::Burn::<Asset, Fixed>::new(
+                amount("0.05"),
+                root_usd_id.clone(),
+            ))
+            .execute(root_id, &mut world_state_view)
+            .expect("Failed to burn fixed asset.");
+            let root_usd = world_state_view
+                .read_asset(&root_usd_id)
+                .expect("Failed to find asset.");
+            assert_eq!("99.70", root_usd.fixed_quantity.to_string());
+            let alice_usd = world_state_view
+                .read_asset(&alice_usd_id)
+                .expect("Failed to find asset.");
+            assert_eq!("0.35", alice_usd.fixed_quantity.to_string());
+            assert_eq!(
+                alice_usd.fixed_quantity,
+                Fixed::decode(&mut alice_usd.fixed_quantity.encode().as_slice())
+                    .expect("Failed to decode fixed quantity.")
+            );
+            assert_eq!(
+                Ok(10005),
+                world_state_view.check_asset_supply(&usd_definition_id)
+            );
+        }
+
+        #[test]
+        fn minting_to_unknown_account_should_fail_for_every_value_type() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let usd_definition_id = AssetDefinitionId::new("usd", "global");
+            Instruction::from(isi::Register::<Domain, AssetDefinition>::new(
+                AssetDefinition::with_value_type(
+                    usd_definition_id.clone(),
+                    AssetValueType::Fixed(2),
+                    Mintable::Infinitely,
+                ),
+                "global".to_string(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to register asset definition.");
+            let nobody_id = AccountId::new("nobody", "global");
+            let asset_id = |definition_name: &str| AssetId {
+                definition_id: AssetDefinitionId::new(definition_name, "global"),
+                account_id: nobody_id.clone(),
+            };
+            let instructions: Vec<Instruction> = vec![
+                isi::Mint::<Asset, u32>::new(1, asset_id("xor")).into(),
+                isi::Mint::<Asset, u128>::new(1, asset_id("btc")).into(),
+                isi::Mint::<Asset, Fixed>::new(
+                    Fixed::parse("1.00", 2).expect("Failed to parse amount."),
+                    asset_id("usd"),
+                )
+                .into(),
+            ];
+            for instruction in instructions {
+                assert!(instruction
+                    .execute(root_id.clone(), &mut world_state_view)
+                    .is_err());
+            }
+            for definition_name in &["xor", "btc", "usd"] {
+                assert_eq!(
+                    Ok(0),
+                    world_state_view
+                        .check_asset_supply(&AssetDefinitionId::new(definition_name, "global"))
+                );
+            }
+            assert!(world_state_view
+                .add_asset(Asset::with_quantity(asset_id("xor"), 1))
+                .is_err());
+        }
+
+        #[test]
+        fn minting_should_keep_asset_supply_consistent() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let xor_definition_id = AssetDefinitionId::new("xor", "global");
+            mint_xor(100)
+                .execute(AccountId::new("root", "global"), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            mint_xor(50)
+                .execute(AccountId::new("root", "global"), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            assert!(
+                Instruction::Compose(Box::new(mint_xor(10)), Box::new(mint_unknown_asset()))
+                    .execute(AccountId::new("root", "global"), &mut world_state_view)
+                    .is_err()
+            );
+            assert_eq!(
+                Ok(150),
+                world_state_view.check_asset_supply(&xor_definition_id)
+            );
+            let asset_definition = world_state_view
+                .read_asset_definition(&xor_definition_id)
+                .expect("Failed to find asset definition.");
+            assert_eq!(150, asset_definition.total_minted);
+            assert_eq!(0, asset_definition.total_burned);
+        }
+
+        #[test]
+        fn supply_counters_should_not_be_set_by_clients_or_overflow() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let mut eth_definition = AssetDefinition::new(AssetDefinitionId::new("eth", "global"));
+            eth_definition.total_minted = 1_000;
+            assert!(
+                Instruction::from(isi::Register::<Domain, AssetDefinition>::new(
+                    eth_definition.clone(),
+                    "global".to_string(),
+                ))
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err()
+            );
+            eth_definition.total_minted = 0;
+            eth_definition.total_burned = 1;
+            assert!(eth_definition.circulating().is_err());
+            assert!(
+                Instruction::from(isi::Register::<Domain, AssetDefinition>::new(
+                    eth_definition,
+                    "global".to_string(),
+                ))
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err()
+            );
+            let mut asset = Asset::with_quantity(
+                AssetId {
+                    definition_id: AssetDefinitionId::new("xor", "global"),
+                    account_id: root_id,
+                },
+                u32::MAX,
+            );
+            asset.big_quantity = u128::MAX;
+            assert!(asset.balance().is_err());
+        }
+
+        #[test]
+        fn burning_should_decrease_asset_supply_and_check_balance() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let xor_definition_id = AssetDefinitionId::new("xor", "global");
+            let xor_id = AssetId {
+                definition_id: xor_definition_id.clone(),
+                account_id: root_id.clone(),
+            };
+            mint_xor(100)
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            mint_xor(50)
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            Instruction::from(isi::Burn::<Asset, u32>::new(30, xor_id.clone()))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to burn asset.");
+            assert!(
+                Instruction::from(isi::Burn::<Asset, u32>::new(121, xor_id.clone()))
+                    .execute(root_id.clone(), &mut world_state_view)
+                    .is_err()
+            );
+            Instruction::from(isi::Burn::<Asset, u32>::new(50, xor_id))
+                .execute(root_id, &mut world_state_view)
+                .expect("Failed to burn asset.");
+            assert_eq!(Some(70), xor_quantity(&mut world_state_view));
+            assert_eq!(
+                Ok(70),
+                world_state_view.check_asset_supply(&xor_definition_id)
+            );
+            let asset_definition = world_state_view
+                .read_asset_definition(&xor_definition_id)
+                .expect("Failed to find asset definition.");
+            assert_eq!(150, asset_definition.total_minted);
+            assert_eq!(80, asset_definition.total_burned);
+        }
+
+        #[test]
+        fn key_values_should_be_set_in_store_assets_within_limits() {
+            use crate::{asset::query::FindAssetKeyValue, query::QueryResult, wsv::StoreLimits};
+
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view =
+                world_state_view_with_root(public_key).with_store_limits(StoreLimits {
+                    max_entries: 2,
+                    max_key_length: 8,
+                    max_value_length: 16,
+                });
+            let root_id = AccountId::new("root", "global");
+            let alice = Account::new("alice", "global", public_key);
+            let alice_id = alice.id.clone();
+            let bob = Account::new("bob", "global", public_key);
+            let bob_id = bob.id.clone();
+            let accounts = &mut world_state_view
+                .domain("global")
+                .expect("Failed to find domain.")
+                .accounts;
+            accounts.insert(alice_id.clone(), alice);
+            accounts.insert(bob_id.clone(), bob);
+            let documents_definition_id = AssetDefinitionId::new("documents", "global");
+            Instruction::from(isi::Register::<Domain, AssetDefinition>::new(
+                AssetDefinition::with_value_type(
+                    documents_definition_id.clone(),
+                    AssetValueType::Store,
+                    Mintable::Not,
+                ),
+                "global".to_string(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to register asset definition.");
+            let documents_id = AssetId {
+                definition_id: documents_definition_id,
+                account_id: alice_id.clone(),
+            };
+            let set = |key: &str, value: &str| -> Instruction {
+                isi::SetKeyValue::<Asset, String, String>::new(
+                    documents_id.clone(),
+                    key.to_string(),
+                    value.to_string(),
+                )
+                .into()
+            };
+            set("contract", "hash-1")
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to set value.");
+            assert!(set("invoice", "hash-2")
+                .execute(alice_id.clone(), &mut world_state_view)
+                .is_err());
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "set_key_value_in_asset",
+                "documents#global",
+            );
+            set("invoice", "hash-2")
+                .execute(alice_id.clone(), &mut world_state_view)
+                .expect("Failed to set value.");
+            assert!(set("receipt", "hash-3")
+                .execute(bob_id, &mut world_state_view)
+                .is_err());
+            assert!(set("receipt", "hash-3")
+                .execute(alice_id.clone(), &mut world_state_view)
+                .is_err());
+            assert!(set("contract", "a value which is too long")
+                .execute(alice_id.clone(), &mut world_state_view)
+                .is_err());
+            set("contract", "hash-4")
+                .execute(alice_id.clone(), &mut world_state_view)
+                .expect("Failed to overwrite value.");
+            match FindAssetKeyValue::build_request(documents_id.clone(), "contract")
+                .query
+                .execute(&world_state_view)
+            {
+                Ok(QueryResult::FindAssetKeyValue(result)) => assert_eq!("hash-4", result.value),
+                result => panic!("Unexpected query result: {:?}", result),
+            }
+            Instruction::from(isi::RemoveKeyValue::<Asset, String>::new(
+                documents_id.clone(),
+                "invoice".to_string(),
+            ))
+            .execute(alice_id.clone(), &mut world_state_view)
+            .expect("Failed to remove value.");
+            assert!(Instruction::from(isi::RemoveKeyValue::<Asset, String>::new(
+                documents_id.clone(),
+                "invoice".to_string(),
+            ))
+            .execute(alice_id.clone(), &mut world_state_view)
+            .is_err());
+            set("receipt", "hash-3")
+                .execute(alice_id, &mut world_state_view)
+                .expect("Failed to set value.");
+            assert_eq!(
+                2,
+                world_state_view
+                    .read_asset(&documents_id)
+                    .expect("Failed to find asset.")
+                    .store()
+                    .len()
+            );
+            assert!(
+                Instruction::from(isi::SetKeyValue::<Asset, String, String>::new(
+                    AssetId {
+                        definition_id: AssetDefinitionId::new("xor", "global"),
+                        account_id: root_id.clone(),
+                    },
+                    "key".to_string(),
+                    "value".to_string(),
+                ))
+                .execute(root_id, &mut world_state_view)
+                .is_err()
+            );
+        }
+    }
+}
+
+/// Query module provides `IrohaQuery` Asset related implementations.
+pub mod query {
+    use super::*;
+    use crate::{
+        block::SignedBlockHeader,
+        permission::isi::PermissionInstruction,
+        query::{Cursor, IrohaQuery, ListOptions, QueryError, QueryItem, QueryLimits, Value},
+        wsv::{StateEntry, StateProof},
+    };
+    use iroha_derive::{IntoQuery, Io};
+    use parity_scale_codec::{Decode, Encode};
+
+    /// To get the state of all assets in an account (a balance),
+    /// GetAccountAssets query can be used.
+    /// Assets are ordered by identification unless `options` specify another sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct GetAccountAssets {
+        account_id: <Account as Identifiable>::Id,
+        options: ListOptions,
+        with_proofs: bool,
+    }
+
+    /// Result of the `GetAccountAssets` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct GetAccountAssetsResult {
+        /// Assets types which are needed to be included in query result.
+        pub assets: Vec<Asset>,
+        /// Cursor to the next page of assets if there are more of them.
+        pub next_cursor: Option<Cursor>,
+        /// Proofs of inclusion of the returned assets into the state the query was executed on,
+        /// if requested. Proofs are ordered by asset identification.
+        pub proofs: Vec<StateProof>,
+        /// Header which state root the `proofs` should be verified against, if proofs are
+        /// requested and the block committing to the state is already committed.
+        pub state_header: Option<SignedBlockHeader>,
+    }
+
+    impl GetAccountAssets {
+        /// Build a `GetAccountAssets` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            account_id: <Account as Identifiable>::Id,
+            options: ListOptions,
+        ) -> QueryRequest {
+            let query = GetAccountAssets {
+                account_id,
+                options,
+                with_proofs: false,
+            };
+            QueryRequest::new(query.into())
+        }
+
+        /// Build a `GetAccountAssets` query in the form of a `QueryRequest`, which result carries
+        /// proofs of inclusion of the returned assets into the world state.
+        ///
+        /// The state after the block at some height is committed to by the state root of the
+        /// block, so the header which the proofs are verified against is the one of the latest block
+        /// or of the block the query is made `at_height` of, returned in the `state_header`.
+        pub fn build_request_with_proofs(
+            account_id: <Account as Identifiable>::Id,
+            options: ListOptions,
+        ) -> QueryRequest {
+            let query = GetAccountAssets {
+                account_id,
+                options,
+                with_proofs: true,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for GetAccountAssets {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let assets: Vec<Asset> = world_state_view
+                .read_account(&self.account_id)
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!(
+                        "No account with id: {:?} found.",
+                        &self.account_id
+                    ))
+                })?
+                .assets
+                .values()
+                .cloned()
+                .collect();
+            let (assets, next_cursor) = self
+                .options
+                .apply(assets, world_state_view.query_limits())?;
+            let (proofs, state_header) = if self.with_proofs {
+                (
+                    world_state_view.state_proofs(|entry| match entry {
+                        StateEntry::Asset(proven) => {
+                            assets.iter().any(|asset| asset.id == proven.id)
+                        }
+                        _ => false,
+                    }),
+                    world_state_view.state_root_header(),
+                )
+            } else {
+                (Vec::new(), None)
+            };
+            Ok(QueryResult::GetAccountAssets(GetAccountAssetsResult {
+                assets,
+                next_cursor,
+                proofs,
+                state_header,
+            }))
+        }
+
+        fn read_permission(
+            &self,
+            authority: <Account as Identifiable>::Id,
+        ) -> PermissionInstruction {
+            PermissionInstruction::CanReadAccount(authority, self.account_id.clone())
+        }
+    }
+
+    /// To get a value by its key from the key-value store of an asset, FindAssetKeyValue query
+    /// can be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAssetKeyValue {
+        asset_id: <Asset as Identifiable>::Id,
+        key: String,
+    }
+
+    /// Result of the `FindAssetKeyValue` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAssetKeyValueResult {
+        /// Value stored by the requested key.
+        pub value: String,
+    }
+
+    impl FindAssetKeyValue {
+        /// Build a `FindAssetKeyValue` query in the form of a `QueryRequest`.
+        pub fn build_request(asset_id: <Asset as Identifiable>::Id, key: &str) -> QueryRequest {
+            let query = FindAssetKeyValue {
+                asset_id,
+                key: key.to_string(),
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAssetKeyValue {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let value = world_state_view
+                .read_asset(&self.asset_id)
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!("No asset with id: {:?} found.", &self.asset_id))
+                })?
+                .store
+                .get(&self.key)
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!("No value with key: {} found.", &self.key))
+                })?
+                .clone();
+            Ok(QueryResult::FindAssetKeyValue(FindAssetKeyValueResult {
+                value,
+            }))
+        }
+
+        fn read_permission(
+            &self,
+            authority: <Account as Identifiable>::Id,
+        ) -> PermissionInstruction {
+            PermissionInstruction::CanReadAccount(authority, self.asset_id.account_id.clone())
+        }
+    }
+
+    /// To get all asset definitions registered in all domains,
+    /// FindAllAssetDefinitions query can be used.
+    /// Asset definitions are ordered by identification unless `options` specify another sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAllAssetDefinitions {
+        options: ListOptions,
+    }
+
+    /// Result of the `FindAllAssetDefinitions` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAllAssetDefinitionsResult {
+        /// Asset definitions of all domains selected by the query options.
+        pub asset_definitions: Vec<AssetDefinition>,
+        /// Cursor to the next page of asset definitions if there are more of them.
+        pub next_cursor: Option<Cursor>,
+    }
+
+    impl FindAllAssetDefinitions {
+        /// Build a `FindAllAssetDefinitions` query in the form of a `QueryRequest`.
+        pub fn build_request(options: ListOptions) -> QueryRequest {
+            let query = FindAllAssetDefinitions { options };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAllAssetDefinitions {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let mut asset_definitions: Vec<AssetDefinition> = world_state_view
+                .read_peer()
+                .domains
+                .values()
+                .flat_map(|domain| domain.asset_definitions.values())
+                .cloned()
+                .collect();
+            asset_definitions.sort_by(|left, right| left.id.cmp(&right.id));
+            let (asset_definitions, next_cursor) = self
+                .options
+                .apply(asset_definitions, world_state_view.query_limits())?;
+            Ok(QueryResult::FindAllAssetDefinitions(
+                FindAllAssetDefinitionsResult {
+                    asset_definitions,
+                    next_cursor,
+                },
+            ))
+        }
+    }
+
+    /// To get the assets of the given definition held by all accounts,
+    /// FindAssetsByDefinition query can be used.
+    /// Assets are ordered by identification unless `options` specify another sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAssetsByDefinition {
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+        options: ListOptions,
+    }
+
+    /// Result of the `FindAssetsByDefinition` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAssetsByDefinitionResult {
+        /// Assets of the requested definition selected by the query options.
+        pub assets: Vec<Asset>,
+        /// Cursor to the next page of assets if there are more of them.
+        pub next_cursor: Option<Cursor>,
+    }
+
+    impl FindAssetsByDefinition {
+        /// Build a `FindAssetsByDefinition` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            asset_definition_id: <AssetDefinition as Identifiable>::Id,
+            options: ListOptions,
+        ) -> QueryRequest {
+            let query = FindAssetsByDefinition {
+                asset_definition_id,
+                options,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAssetsByDefinition {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let mut assets: Vec<Asset> = world_state_view
+                .read_peer()
+                .domains
+                .values()
+                .flat_map(|domain| domain.accounts.values())
+                .flat_map(|account| account.assets.values())
+                .filter(|asset| asset.id.definition_id == self.asset_definition_id)
+                .cloned()
+                .collect();
+            assets.sort_by(|left, right| left.id.cmp(&right.id));
+            let (assets, next_cursor) = self
+                .options
+                .apply(assets, world_state_view.query_limits())?;
+            Ok(QueryResult::FindAssetsByDefinition(
+                FindAssetsByDefinitionResult {
+                    assets,
+                    next_cursor,
+                },
+            ))
+        }
+    }
+
+    /// To get the supply of the asset - the whitepaper's `getTotalQtyEverCreated`,
+    /// `getTotalQtyEverDeleted` and `getAssetQty`, GetAssetSupply query can be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct GetAssetSupply {
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+    }
+
+    /// Result of the `GetAssetSupply` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct GetAssetSupplyResult {
+        /// Total quantity of the asset ever minted.
+        pub total_minted: u128,
+        /// Total quantity of the asset ever burned.
+        pub total_burned: u128,
+        /// Quantity of the asset held by all accounts.
+        pub circulating: u128,
+    }
+
+    impl GetAssetSupply {
+        /// Build a `GetAssetSupply` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            asset_definition_id: <AssetDefinition as Identifiable>::Id,
+        ) -> QueryRequest {
+            let query = GetAssetSupply {
+                asset_definition_id,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for GetAssetSupply {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let asset_definition = world_state_view
+                .read_asset_definition(&self.asset_definition_id)
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!(
+                        "No asset definition with id: {:?} found.",
+                        &self.asset_definition_id
+                    ))
+                })?;
+            let circulating = world_state_view
+                .check_asset_supply(&self.asset_definition_id)
+                .map_err(QueryError::InconsistentState)?;
+            Ok(QueryResult::GetAssetSupply(GetAssetSupplyResult {
+                total_minted: asset_definition.total_minted,
+                total_burned: asset_definition.total_burned,
+                circulating,
+            }))
+        }
+    }
+
+    /// Balance of the asset held by an account.
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+    pub struct AssetHolder {
+        /// Identification of the holding account.
+        pub account_id: <Account as Identifiable>::Id,
+        /// Quantity of the asset held by the account.
+        pub balance: u128,
+    }
+
+    /// To get accounts holding the asset sorted by balance from the largest one,
+    /// FindAssetHolders query can be used. Only the first `limit` holders are returned, so it also
+    /// serves top-N requests. The `limit` should not be larger than `max_aggregate_results` of the
+    /// peer `QueryLimits`.
+    /// Accounts with zero balance are not counted as holders.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAssetHolders {
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+        limit: u32,
+    }
+
+    /// Result of the `FindAssetHolders` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAssetHoldersResult {
+        /// Holders of the asset, ordered by balance from the largest one and then by account
+        /// identification.
+        pub holders: Vec<AssetHolder>,
+    }
+
+    impl FindAssetHolders {
+        /// Build a `FindAssetHolders` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            asset_definition_id: <AssetDefinition as Identifiable>::Id,
+            limit: u32,
+        ) -> QueryRequest {
+            let query = FindAssetHolders {
+                asset_definition_id,
+                limit,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAssetHolders {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let limit = check_aggregate_limit(self.limit, world_state_view.query_limits())?;
+            let mut holders: Vec<AssetHolder> =
+                held_assets(world_state_view, &self.asset_definition_id)?
+                    .map(|asset| {
+                        Ok(AssetHolder {
+                            account_id: asset.id.account_id.clone(),
+                            balance: asset.balance().map_err(QueryError::InconsistentState)?,
+                        })
+                    })
+                    .collect::<Result<_, QueryError>>()?;
+            holders.sort_by(|left, right| {
+                right
+                    .balance
+                    .cmp(&left.balance)
+                    .then_with(|| left.account_id.cmp(&right.account_id))
+            });
+            holders.truncate(limit);
+            Ok(QueryResult::FindAssetHolders(FindAssetHoldersResult {
+                holders,
+            }))
+        }
+    }
+
+    /// To get the number of accounts holding the asset, GetAssetHolderCount query can be used.
+    /// Accounts with zero balance are not counted as holders.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct GetAssetHolderCount {
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+    }
+
+    /// Result of the `GetAssetHolderCount` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct GetAssetHolderCountResult {
+        /// Number of accounts holding the asset.
+        pub count: u64,
+    }
+
+    impl GetAssetHolderCount {
+        /// Build a `GetAssetHolderCount` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            asset_definition_id: <AssetDefinition as Identifiable>::Id,
+        ) -> QueryRequest {
+            let query = GetAssetHolderCount {
+                asset_definition_id,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for GetAssetHolderCount {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let count = held_assets(world_state_view, &self.asset_definition_id)?.count() as u64;
+            Ok(QueryResult::GetAssetHolderCount(
+                GetAssetHolderCountResult { count },
+            ))
+        }
+    }
+
+    /// Total balance of the asset held by accounts of a domain.
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+    pub struct DomainBalance {
+        /// Name of the domain of the holding accounts.
+        pub domain_name: <Domain as Identifiable>::Id,
+        /// Sum of balances of the holding accounts.
+        pub balance: u128,
+    }
+
+    /// To get sums of balances of the asset per domain of the holding accounts,
+    /// GetAssetBalancesByDomain query can be used. Only the first `limit` domains are returned,
+    /// the `limit` should not be larger than `max_aggregate_results` of the peer `QueryLimits`.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct GetAssetBalancesByDomain {
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+        limit: u32,
+    }
+
+    /// Result of the `GetAssetBalancesByDomain` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct GetAssetBalancesByDomainResult {
+        /// Balances of domains with at least one holder, ordered by domain name.
+        pub balances: Vec<DomainBalance>,
+    }
+
+    impl GetAssetBalancesByDomain {
+        /// Build a `GetAssetBalancesByDomain` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            asset_definition_id: <AssetDefinition as Identifiable>::Id,
+            limit: u32,
+        ) -> QueryRequest {
+            let query = GetAssetBalancesByDomain {
+                asset_definition_id,
+                limit,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for GetAssetBalancesByDomain {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let limit = check_aggregate_limit(self.limit, world_state_view.query_limits())?;
+            let mut balances: BTreeMap<&str, u128> = BTreeMap::new();
+            for asset in held_assets(world_state_view, &self.asset_definition_id)? {
+                let balance = balances
+                    .entry(asset.id.account_id.domain_name.as_str())
+                    .or_insert(0);
+                *balance = asset
+                    .balance()
+                    .ok()
+                    .and_then(|asset_balance| balance.checked_add(asset_balance))
+                    .ok_or_else(|| {
+                        QueryError::InconsistentState(format!(
+                            "Balance of the asset {} in the domain {} overflows.",
+                            self.asset_definition_id, asset.id.account_id.domain_name
+                        ))
+                    })?;
+            }
+            let balances = balances
+                .into_iter()
+                .take(limit)
+                .map(|(domain_name, balance)| DomainBalance {
+                    domain_name: domain_name.to_string(),
+                    balance,
+                })
+                .collect();
+            Ok(QueryResult::GetAssetBalancesByDomain(
+                GetAssetBalancesByDomainResult { balances },
+            ))
+        }
+    }
+
+    /// Number of assets held by an account.
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+    pub struct AccountAssetCount {
+        /// Identification of the account.
+        pub account_id: <Account as Identifiable>::Id,
+        /// Number of assets in the account.
+        pub count: u64,
+    }
+
+    /// To get numbers of assets held by accounts sorted from the largest one,
+    /// GetAccountAssetCounts query can be used. Only the first `limit` accounts are returned,
+    /// the `limit` should not be larger than `max_aggregate_results` of the peer `QueryLimits`.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct GetAccountAssetCounts {
+        limit: u32,
+    }
+
+    /// Result of the `GetAccountAssetCounts` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct GetAccountAssetCountsResult {
+        /// Numbers of assets, ordered from the largest one and then by account identification.
+        pub counts: Vec<AccountAssetCount>,
+    }
+
+    impl GetAccountAssetCounts {
+        /// Build a `GetAccountAssetCounts` query in the form of a `QueryRequest`.
+        pub fn build_request(limit: u32) -> QueryRequest {
+            let query = GetAccountAssetCounts { limit };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for GetAccountAssetCounts {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let limit = check_aggregate_limit(self.limit, world_state_view.query_limits())?;
+            let mut counts: Vec<AccountAssetCount> = world_state_view
+                .read_peer()
+                .domains
+                .values()
+                .flat_map(|domain| domain.accounts.values())
+                .map(|account| AccountAssetCount {
+                    account_id: account.id.clone(),
+                    count: account.assets.len() as u64,
+                })
+                .collect();
+            counts.sort_by(|left, right| {
+                right
+                    .count
+                    .cmp(&left.count)
+                    .then_with(|| left.account_id.cmp(&right.account_id))
+            });
+            counts.truncate(limit);
+            Ok(QueryResult::GetAccountAssetCounts(
+                GetAccountAssetCountsResult { counts },
+            ))
+        }
+    }
+
+    fn check_aggregate_limit(limit: u32, query_limits: QueryLimits) -> Result<usize, QueryError> {
+        if limit > query_limits.max_aggregate_results {
+            Err(QueryError::LimitExceeded(format!(
+                "Invalid limit: {}, at most {} entries can be requested.",
+                limit, query_limits.max_aggregate_results
+            )))
+        } else {
+            Ok(limit as usize)
+        }
+    }
+
+    /// Assets of the definition with non-zero balance across all accounts.
+    fn held_assets<'a>(
+        world_state_view: &'a WorldStateView,
+        asset_definition_id: &'a <AssetDefinition as Identifiable>::Id,
+    ) -> Result<impl Iterator<Item = &'a Asset>, QueryError> {
+        if world_state_view
+            .read_asset_definition(asset_definition_id)
+            .is_none()
+        {
+            return Err(QueryError::NotFound(format!(
+                "No asset definition with id: {:?} found.",
+                asset_definition_id
+            )));
+        }
+        Ok(world_state_view
+            .read_peer()
+            .domains
+            .values()
+            .flat_map(|domain| domain.accounts.values())
+            .flat_map(|account| account.assets.values())
+            .filter(move |asset| {
+                &asset.id.definition_id == asset_definition_id && asset.balance() != Ok(0)
+            }))
+    }
+
+    impl QueryItem for Asset {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "name" => Some(Value::String(self.id.definition_id.name.clone())),
+                "domain_name" => Some(Value::String(self.id.definition_id.domain_name.clone())),
+                "account_name" => Some(Value::String(self.id.account_id.name.clone())),
+                "account_domain_name" => {
+                    Some(Value::String(self.id.account_id.domain_name.clone()))
+                }
+                "quantity" => Some(Value::Number(self.quantity.into())),
+                "big_quantity" => Some(Value::Number(self.big_quantity)),
+                "fixed_quantity" => Some(Value::Number(self.fixed_quantity.mantissa)),
+                _ => None,
+            }
+        }
+    }
+
+    impl QueryItem for AssetDefinition {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "name" => Some(Value::String(self.id.name.clone())),
+                "domain_name" => Some(Value::String(self.id.domain_name.clone())),
+                "total_minted" => Some(Value::Number(self.total_minted)),
+                "total_burned" => Some(Value::Number(self.total_burned)),
+                "circulating" => self.circulating().ok().map(Value::Number),
+                "value_type" => Some(Value::String(self.value_type.to_string())),
+                _ => None,
+            }
+        }
+        fn metadata(&self, key: &str) -> Option<&str> {
+            self.metadata.get(key).map(String::as_str)
         }
     }
 }
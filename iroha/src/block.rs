@@ -1,7 +1,7 @@
 //! This module contains `Block` structures for each state, it's transitions, implementations and related traits
 //! implementations.
 
-use crate::{crypto, prelude::*};
+use crate::{crypto, prelude::*, tx::RejectedTransaction};
 use iroha_derive::Io;
 use parity_scale_codec::{Decode, Encode};
 use std::time::SystemTime;
@@ -39,6 +39,7 @@ impl PendingBlock {
                 previous_block_hash,
                 // TODO: get actual merkle tree hash
                 merkle_root_hash: [0u8; 32],
+                state_root_hash: [0u8; 32],
             },
         }
     }
@@ -52,6 +53,7 @@ impl PendingBlock {
                 height: 0,
                 previous_block_hash: [0u8; 32],
                 merkle_root_hash: [0u8; 32],
+                state_root_hash: [0u8; 32],
             },
         }
     }
@@ -78,6 +80,10 @@ pub struct BlockHeader {
     pub previous_block_hash: Hash,
     /// Hash of merkle tree root of the tree of transactions hashes.
     pub merkle_root_hash: Hash,
+    /// Root hash of the Merkle tree of the world state entries after the block's transactions
+    /// are applied, computed when the block is built. Peers refuse to sign a block with a state
+    /// root they can not reproduce by applying the block to their own state.
+    pub state_root_hash: Hash,
 }
 
 impl BlockHeader {
@@ -88,20 +94,34 @@ impl BlockHeader {
 }
 
 impl ChainedBlock {
+    /// Record the root hash of the world state after the block's transactions are applied to
+    /// the given one in the block header.
+    pub fn with_state_root(mut self, world_state_view: &WorldStateView) -> ChainedBlock {
+        self.header.state_root_hash = state_root_after(
+            self.transactions
+                .iter()
+                .cloned()
+                .map(SignedTransaction::from),
+            world_state_view,
+        );
+        self
+    }
+
     /// Sign block by the given key pair.
+    /// Transactions keep only the signatures of their creators' signatories.
     pub fn sign(
         self,
         public_key: &PublicKey,
         private_key: &PrivateKey,
     ) -> Result<SignedBlock, String> {
         let signature_payload: Vec<u8> = self.hash().to_vec();
-        let mut transactions = Vec::new();
-        for transaction in self.transactions {
-            transactions.push(transaction.sign(public_key, private_key)?);
-        }
         Ok(SignedBlock {
             header: self.header,
-            transactions,
+            transactions: self
+                .transactions
+                .into_iter()
+                .map(SignedTransaction::from)
+                .collect(),
             signatures: vec![Signature::new(
                 *public_key,
                 &signature_payload,
@@ -150,20 +170,33 @@ impl SignedBlock {
         })
     }
 
+    /// Root hash of the world state after the block's transactions are applied to the given one.
+    pub fn state_root_after(&self, world_state_view: &WorldStateView) -> Hash {
+        state_root_after(self.transactions.iter().cloned(), world_state_view)
+    }
+
     /// Validate block transactions against current state of the world.
     pub fn validate(self, world_state_view: &WorldStateView) -> Result<ValidBlock, String> {
         let mut world_state_view = world_state_view.clone();
         let mut transactions = Vec::new();
+        let mut rejected_transactions = Vec::new();
         for transaction in self.transactions {
             match transaction.validate(&mut world_state_view) {
                 Ok(transaction) => transactions.push(transaction),
-                Err(e) => eprintln!("Transaction validation failed: {}", e),
+                Err(transaction) => {
+                    eprintln!(
+                        "Transaction validation failed: {}",
+                        transaction.rejection_reason
+                    );
+                    rejected_transactions.push(transaction)
+                }
             }
         }
         Ok(ValidBlock {
             header: self.header,
             signatures: self.signatures,
             transactions,
+            rejected_transactions,
         })
     }
 
@@ -173,6 +206,21 @@ impl SignedBlock {
     }
 }
 
+/// Applies the transactions and the listeners to a copy of the world state the same way the
+/// committed block is applied and returns the resulting state root. Rejected transactions leave
+/// the state untouched.
+fn state_root_after<I>(transactions: I, world_state_view: &WorldStateView) -> Hash
+where
+    I: IntoIterator<Item = SignedTransaction>,
+{
+    let mut world_state_view = world_state_view.clone();
+    for transaction in transactions {
+        let _ = transaction.validate(&mut world_state_view);
+    }
+    world_state_view.execute_listeners();
+    world_state_view.state_root_hash()
+}
+
 /// After full validation `SignedBlock` can transform into `ValidBlock`.
 #[derive(Clone, Debug, Io, Encode, Decode)]
 pub struct ValidBlock {
@@ -180,6 +228,8 @@ pub struct ValidBlock {
     pub header: BlockHeader,
     /// array of transactions, which successfully passed validation and consensus step.
     pub transactions: Vec<ValidTransaction>,
+    /// array of transactions, which failed validation, with the reasons of rejection.
+    pub rejected_transactions: Vec<RejectedTransaction>,
     /// Signatures of peers which approved this block
     pub signatures: Vec<Signature>,
 }
@@ -191,6 +241,7 @@ impl ValidBlock {
         CommittedBlock {
             header: self.header,
             transactions: self.transactions,
+            rejected_transactions: self.rejected_transactions,
             signatures: self.signatures,
         }
     }
@@ -209,6 +260,8 @@ pub struct CommittedBlock {
     pub header: BlockHeader,
     /// array of transactions, which successfully passed validation and consensus step.
     pub transactions: Vec<ValidTransaction>,
+    /// array of transactions, which failed validation, with the reasons of rejection.
+    pub rejected_transactions: Vec<RejectedTransaction>,
     /// Signatures of peers which approved this block
     pub signatures: Vec<Signature>,
 }
@@ -219,11 +272,298 @@ impl CommittedBlock {
     pub fn hash(&self) -> Hash {
         self.header.hash()
     }
+
+    /// Header of the block with the signatures of the peers which approved it.
+    pub fn signed_header(&self) -> SignedBlockHeader {
+        SignedBlockHeader {
+            header: self.header.clone(),
+            signatures: self.signatures.clone(),
+        }
+    }
+}
+
+/// Header of a committed block with the signatures of the peers which approved it, so a client
+/// can check the state root of the block without downloading its transactions.
+#[derive(Clone, Debug, Io, Encode, Decode)]
+pub struct SignedBlockHeader {
+    /// Header
+    pub header: BlockHeader,
+    /// Signatures of peers which approved the block.
+    pub signatures: Vec<Signature>,
+}
+
+impl SignedBlockHeader {
+    /// Checks that all signatures are valid signatures of the header. A client should also check
+    /// that enough of them belong to the trusted peers.
+    pub fn verify_signatures(&self) -> Result<(), String> {
+        let signature_payload = self.header.hash();
+        self.signatures
+            .iter()
+            .try_for_each(|signature| signature.verify(&signature_payload))
+    }
+}
+
+/// Query module provides `IrohaQuery` Block related implementations. Blocks and transactions are
+/// looked up in the committed blocks stored by `Kura`.
+pub mod query {
+    use super::*;
+    use crate::{
+        query::{Cursor, IrohaQuery, ListOptions, QueryError, QueryItem, Value},
+        wsv::TransactionLocation,
+    };
+    use iroha_derive::{log, IntoQuery};
+
+    /// The maximum number of headers returned by a single `FindBlockHeaders` query.
+    pub const MAX_BLOCK_HEADERS_RANGE: u64 = 100;
+
+    /// To get a committed block by its height, `FindBlockByHeight` query can be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindBlockByHeight {
+        height: u64,
+    }
+
+    /// To get a committed block by its hash, `FindBlockByHash` query can be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindBlockByHash {
+        hash: Hash,
+    }
+
+    /// To get the latest committed block, `FindLatestBlock` query can be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindLatestBlock;
+
+    /// Result of the `FindBlockByHeight`, `FindBlockByHash` and `FindLatestBlock` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindBlockResult {
+        /// Requested block with its transactions, rejected transactions and signatures.
+        pub block: CommittedBlock,
+    }
+
+    /// To get headers of committed blocks with heights in `[from_height, to_height)`,
+    /// `FindBlockHeaders` query can be used. The range should not be larger than
+    /// `MAX_BLOCK_HEADERS_RANGE`. Headers are ordered by height unless `options` specify another
+    /// sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindBlockHeaders {
+        from_height: u64,
+        to_height: u64,
+        options: ListOptions,
+    }
+
+    /// Result of the `FindBlockHeaders` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindBlockHeadersResult {
+        /// Headers of the blocks in the requested range selected by the query options.
+        pub headers: Vec<BlockHeader>,
+        /// Cursor to the next page of headers if there are more of them.
+        pub next_cursor: Option<Cursor>,
+    }
+
+    /// To get a committed or rejected transaction by its hash, `FindTransactionByHash` query can
+    /// be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindTransactionByHash {
+        hash: Hash,
+    }
+
+    /// Transaction stored in a block - either committed or rejected with the reason of rejection.
+    #[derive(Debug, Encode, Decode)]
+    pub enum BlockTransaction {
+        /// Transaction which passed validation and was applied.
+        Valid(ValidTransaction),
+        /// Transaction which failed validation, it contains the reason of rejection.
+        Rejected(RejectedTransaction),
+    }
+
+    /// Result of the `FindTransactionByHash` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindTransactionByHashResult {
+        /// Height of the block containing the transaction.
+        pub block_height: u64,
+        /// Hash of the block containing the transaction.
+        pub block_hash: Hash,
+        /// Requested transaction with its signatures.
+        pub transaction: BlockTransaction,
+    }
+
+    impl FindBlockByHeight {
+        /// Build a `FindBlockByHeight` query in the form of a `QueryRequest`.
+        pub fn build_request(height: u64) -> QueryRequest {
+            let query = FindBlockByHeight { height };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindBlockByHeight {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let block = world_state_view
+                .with_blocks(|blocks| {
+                    blocks
+                        .iter()
+                        .find(|block| block.header.height == self.height)
+                        .cloned()
+                })
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!("No block with height: {} found.", self.height))
+                })?;
+            Ok(QueryResult::FindBlockByHeight(FindBlockResult { block }))
+        }
+    }
+
+    impl FindBlockByHash {
+        /// Build a `FindBlockByHash` query in the form of a `QueryRequest`.
+        pub fn build_request(hash: Hash) -> QueryRequest {
+            let query = FindBlockByHash { hash };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindBlockByHash {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let block = world_state_view
+                .with_blocks(|blocks| {
+                    blocks
+                        .iter()
+                        .find(|block| block.hash() == self.hash)
+                        .cloned()
+                })
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!("No block with hash: {:?} found.", self.hash))
+                })?;
+            Ok(QueryResult::FindBlockByHash(FindBlockResult { block }))
+        }
+    }
+
+    impl FindLatestBlock {
+        /// Build a `FindLatestBlock` query in the form of a `QueryRequest`.
+        pub fn build_request() -> QueryRequest {
+            QueryRequest::new(FindLatestBlock.into())
+        }
+    }
+
+    impl Query for FindLatestBlock {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let block = world_state_view
+                .with_blocks(|blocks| blocks.last().cloned())
+                .ok_or_else(|| QueryError::NotFound("No blocks committed yet.".to_string()))?;
+            Ok(QueryResult::FindLatestBlock(FindBlockResult { block }))
+        }
+    }
+
+    impl FindBlockHeaders {
+        /// Build a `FindBlockHeaders` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            from_height: u64,
+            to_height: u64,
+            options: ListOptions,
+        ) -> QueryRequest {
+            let query = FindBlockHeaders {
+                from_height,
+                to_height,
+                options,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindBlockHeaders {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            if self.from_height > self.to_height
+                || self.to_height - self.from_height > MAX_BLOCK_HEADERS_RANGE
+            {
+                return Err(QueryError::LimitExceeded(format!(
+                    "Invalid block headers range: [{}, {}), at most {} headers can be requested.",
+                    self.from_height, self.to_height, MAX_BLOCK_HEADERS_RANGE
+                )));
+            }
+            let headers: Vec<BlockHeader> = world_state_view.with_blocks(|blocks| {
+                blocks
+                    .iter()
+                    .filter(|block| {
+                        block.header.height >= self.from_height
+                            && block.header.height < self.to_height
+                    })
+                    .map(|block| block.header.clone())
+                    .collect()
+            });
+            let (headers, next_cursor) = self
+                .options
+                .apply(headers, world_state_view.query_limits())?;
+            Ok(QueryResult::FindBlockHeaders(FindBlockHeadersResult {
+                headers,
+                next_cursor,
+            }))
+        }
+    }
+
+    impl QueryItem for BlockHeader {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "height" => Some(Value::Number(self.height.into())),
+                "timestamp" => Some(Value::Number(self.timestamp)),
+                _ => None,
+            }
+        }
+    }
+
+    impl FindTransactionByHash {
+        /// Build a `FindTransactionByHash` query in the form of a `QueryRequest`.
+        pub fn build_request(hash: Hash) -> QueryRequest {
+            let query = FindTransactionByHash { hash };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindTransactionByHash {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let location = world_state_view
+                .transaction_location(&self.hash)
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!(
+                        "No transaction with hash: {:?} found.",
+                        self.hash
+                    ))
+                })?;
+            let result = world_state_view.with_blocks(|blocks| match location {
+                TransactionLocation::Committed(block_index, transaction_index) => {
+                    let block = &blocks[block_index];
+                    FindTransactionByHashResult {
+                        block_height: block.header.height,
+                        block_hash: block.hash(),
+                        transaction: BlockTransaction::Valid(
+                            block.transactions[transaction_index].clone(),
+                        ),
+                    }
+                }
+                TransactionLocation::Rejected(block_index, transaction_index) => {
+                    let block = &blocks[block_index];
+                    FindTransactionByHashResult {
+                        block_height: block.header.height,
+                        block_hash: block.hash(),
+                        transaction: BlockTransaction::Rejected(
+                            block.rejected_transactions[transaction_index].clone(),
+                        ),
+                    }
+                }
+            });
+            Ok(QueryResult::FindTransactionByHash(result))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::block::{BlockHeader, ValidBlock};
+    use crate::{
+        block::{query::*, BlockHeader, ValidBlock},
+        isi::{self, fixtures::commit_block},
+        peer::PeerId,
+        prelude::*,
+    };
 
     #[test]
     pub fn committed_and_valid_block_hashes_are_equal() {
@@ -233,11 +573,102 @@ mod tests {
                 height: 0,
                 previous_block_hash: [0u8; 32],
                 merkle_root_hash: [0u8; 32],
+                state_root_hash: [0u8; 32],
             },
             transactions: vec![],
+            rejected_transactions: vec![],
             signatures: vec![],
         };
         let commited_block = valid_block.clone().commit();
         assert_eq!(valid_block.hash(), commited_block.hash())
     }
+
+    #[async_std::test]
+    async fn committed_and_rejected_transactions_should_be_found_by_hash() {
+        let mut world_state_view = WorldStateView::new(Peer::new(
+            PeerId {
+                address: "127.0.0.1:8080".to_string(),
+                public_key: [0; 32],
+            },
+            &Vec::new(),
+        ));
+        let (public_key, private_key) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let account_id = AccountId::new("root", "global");
+        let sign = |instruction: Instruction| {
+            RequestedTransaction::new(vec![instruction], account_id.clone())
+                .accept()
+                .expect("Failed to accept transaction.")
+                .sign(&public_key, &private_key)
+                .expect("Failed to sign transaction.")
+        };
+        let valid_transaction = sign(Instruction::Notify("Test".to_string()))
+            .validate(&mut world_state_view)
+            .expect("Failed to validate transaction.");
+        let rejected_transaction = sign(
+            isi::Mint::<Asset, u32>::new(
+                10,
+                AssetId {
+                    definition_id: AssetDefinitionId::new("xor", "global"),
+                    account_id: account_id.clone(),
+                },
+            )
+            .into(),
+        )
+        .validate(&mut world_state_view)
+        .expect_err("Transaction should be rejected.");
+        let block = ValidBlock {
+            header: BlockHeader {
+                timestamp: 0,
+                height: 0,
+                previous_block_hash: [0; 32],
+                merkle_root_hash: [0; 32],
+                state_root_hash: [0; 32],
+            },
+            transactions: vec![valid_transaction.clone()],
+            rejected_transactions: vec![rejected_transaction.clone()],
+            signatures: vec![],
+        }
+        .commit();
+        commit_block(&mut world_state_view, block).await;
+        match FindTransactionByHash::build_request(rejected_transaction.hash())
+            .query
+            .execute(&world_state_view)
+            .expect("Failed to find transaction.")
+        {
+            QueryResult::FindTransactionByHash(FindTransactionByHashResult {
+                block_height: 0,
+                transaction: BlockTransaction::Rejected(_),
+                ..
+            }) => (),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        match FindTransactionByHash::build_request(valid_transaction.hash())
+            .query
+            .execute(&world_state_view)
+            .expect("Failed to find transaction.")
+        {
+            QueryResult::FindTransactionByHash(FindTransactionByHashResult {
+                transaction: BlockTransaction::Valid(_),
+                ..
+            }) => (),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        match FindBlockHeaders::build_request(0, 10, ListOptions::default())
+            .query
+            .execute(&world_state_view)
+            .expect("Failed to find block headers.")
+        {
+            QueryResult::FindBlockHeaders(result) => assert_eq!(1, result.headers.len()),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        assert!(FindBlockHeaders::build_request(
+            0,
+            MAX_BLOCK_HEADERS_RANGE + 1,
+            ListOptions::default()
+        )
+        .query
+        .execute(&world_state_view)
+        .is_err());
+    }
 }
@@ -1,7 +1,7 @@
 //! This module contains functionality related to `Bridge`.
 
 use crate::prelude::*;
-use iroha_derive::Io;
+use iroha_derive::{log, IntoQuery, Io};
 use parity_scale_codec::{Decode, Encode};
 
 /// Enumeration of all supported bridge kinds (types). Each variant represents some communication
@@ -60,7 +60,7 @@ impl BridgeId {
 }
 
 /// An entity used for performing operations between Iroha and third-party blockchain.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct Bridge {
     /// Component Identification.
     id: <Bridge as Identifiable>::Id,
@@ -168,3 +168,100 @@ pub mod wsv {
         }
     }
 }
+
+/// Query module provides `IrohaQuery` Bridge related implementations.
+pub mod query {
+    use super::*;
+    use crate::query::{Cursor, IrohaQuery, ListOptions, QueryError, QueryItem, Value};
+
+    /// To get all registered bridges, FindAllBridges query can be used.
+    /// Bridges are ordered by name unless `options` specify another sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAllBridges {
+        options: ListOptions,
+    }
+
+    /// Result of the `FindAllBridges` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAllBridgesResult {
+        /// Registered bridges selected by the query options.
+        pub bridges: Vec<Bridge>,
+        /// Cursor to the next page of bridges if there are more of them.
+        pub next_cursor: Option<Cursor>,
+    }
+
+    impl FindAllBridges {
+        /// Build a `FindAllBridges` query in the form of a `QueryRequest`.
+        pub fn build_request(options: ListOptions) -> QueryRequest {
+            let query = FindAllBridges { options };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAllBridges {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let mut bridges: Vec<Bridge> = world_state_view
+                .read_peer()
+                .bridges
+                .values()
+                .cloned()
+                .collect();
+            bridges.sort_by(|left, right| left.name().cmp(right.name()));
+            let (bridges, next_cursor) = self
+                .options
+                .apply(bridges, world_state_view.query_limits())?;
+            Ok(QueryResult::FindAllBridges(FindAllBridgesResult {
+                bridges,
+                next_cursor,
+            }))
+        }
+    }
+
+    impl QueryItem for Bridge {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "name" => Some(Value::String(self.name().to_string())),
+                _ => None,
+            }
+        }
+    }
+
+    /// To get a bridge by its name, FindBridgeByName query can be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindBridgeByName {
+        name: String,
+    }
+
+    /// Result of the `FindBridgeByName` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindBridgeByNameResult {
+        /// Bridge with the requested name.
+        pub bridge: Bridge,
+    }
+
+    impl FindBridgeByName {
+        /// Build a `FindBridgeByName` query in the form of a `QueryRequest`.
+        pub fn build_request(name: &str) -> QueryRequest {
+            let query = FindBridgeByName {
+                name: name.to_owned(),
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindBridgeByName {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let bridge = world_state_view
+                .read_bridge(&self.name)
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!("No bridge with name: {} found.", &self.name))
+                })?
+                .clone();
+            Ok(QueryResult::FindBridgeByName(FindBridgeByNameResult {
+                bridge,
+            }))
+        }
+    }
+}
@@ -1,8 +1,12 @@
 //! This module contains `Configuration` structure and related implementation.
 use crate::{
+    account::Id as AccountId,
     crypto::{PrivateKey, PublicKey},
     kura::Mode,
     peer::PeerId,
+    query::QueryLimits,
+    tx::TransactionLimits,
+    wsv::{SnapshotRetention, StoreLimits},
 };
 use iroha_derive::*;
 use std::{
@@ -22,17 +26,61 @@ const TRUSTED_PEERS: &str = "IROHA_TRUSTED_PEERS";
 const MAX_FAULTY_PEERS: &str = "MAX_FAULTY_PEERS";
 const IROHA_PUBLIC_KEY: &str = "IROHA_PUBLIC_KEY";
 const IROHA_PRIVATE_KEY: &str = "IROHA_PRIVATE_KEY";
+const ROOT_PUBLIC_KEY: &str = "ROOT_PUBLIC_KEY";
+const CLIENT_PUBLIC_KEY: &str = "CLIENT_PUBLIC_KEY";
+const CLIENT_PRIVATE_KEY: &str = "CLIENT_PRIVATE_KEY";
 const COMMIT_TIME_MS: &str = "COMMIT_TIME_MS";
 const TX_RECEIPT_TIME_MS: &str = "TX_RECEIPT_TIME_MS";
+const MAX_TRANSACTION_SIZE: &str = "MAX_TRANSACTION_SIZE";
+const MAX_INSTRUCTION_NUMBER: &str = "MAX_INSTRUCTION_NUMBER";
+const MAX_INSTRUCTION_DEPTH: &str = "MAX_INSTRUCTION_DEPTH";
+const ACCOUNT_ID: &str = "ACCOUNT_ID";
+const ALLOW_UNSIGNED_QUERIES: &str = "ALLOW_UNSIGNED_QUERIES";
+const WSV_SNAPSHOT_INTERVAL: &str = "WSV_SNAPSHOT_INTERVAL";
+const WSV_MAX_SNAPSHOTS: &str = "WSV_MAX_SNAPSHOTS";
+const STORE_MAX_ENTRIES: &str = "STORE_MAX_ENTRIES";
+const STORE_MAX_KEY_LENGTH: &str = "STORE_MAX_KEY_LENGTH";
+const STORE_MAX_VALUE_LENGTH: &str = "STORE_MAX_VALUE_LENGTH";
+const QUERY_DEFAULT_PAGE_SIZE: &str = "QUERY_DEFAULT_PAGE_SIZE";
+const QUERY_MAX_PAGE_SIZE: &str = "QUERY_MAX_PAGE_SIZE";
+const QUERY_MAX_AGGREGATE_RESULTS: &str = "QUERY_MAX_AGGREGATE_RESULTS";
+const QUERY_TIMESTAMP_WINDOW_MS: &str = "QUERY_TIMESTAMP_WINDOW_MS";
 const DEFAULT_TORII_URL: &str = "127.0.0.1:1337";
 const DEFAULT_BLOCK_TIME_MS: u64 = 1000;
 const DEFAULT_KURA_INIT_MODE: Mode = Mode::Strict;
 const DEFAULT_KURA_BLOCK_STORE_PATH: &str = "./blocks";
 const DEFAULT_MAX_FAULTY_PEERS: usize = 0;
+const DEFAULT_ACCOUNT_ID: &str = "root@global";
+const DEFAULT_ALLOW_UNSIGNED_QUERIES: bool = false;
 /// Amount of time Peer waits for `BlockCommitted` message from the proxy tail.
 pub const DEFAULT_COMMIT_TIME_MS: u64 = 1000;
 /// Amount of time Peer waits for `TransactionReceipt` from the leader.
 pub const DEFAULT_TX_RECEIPT_TIME_MS: u64 = 100;
+/// Maximum size of the encoded transaction in bytes accepted by Torii.
+pub const DEFAULT_MAX_TRANSACTION_SIZE: u64 = 32_768;
+/// Maximum number of instructions in a transaction accepted by Torii.
+pub const DEFAULT_MAX_INSTRUCTION_NUMBER: u64 = 4096;
+/// Maximum depth of nested instructions in a transaction accepted by Torii.
+pub const DEFAULT_MAX_INSTRUCTION_DEPTH: u64 = 16;
+/// Number of committed blocks between two snapshots of the world state kept for historical queries.
+pub const DEFAULT_WSV_SNAPSHOT_INTERVAL: u64 = 100;
+/// Maximum number of world state snapshots kept for historical queries.
+pub const DEFAULT_WSV_MAX_SNAPSHOTS: u64 = 10;
+/// Maximum number of entries in a key-value store of a world state entity.
+pub const DEFAULT_STORE_MAX_ENTRIES: u64 = 256;
+/// Maximum length in bytes of a key in a key-value store of a world state entity.
+pub const DEFAULT_STORE_MAX_KEY_LENGTH: u64 = 128;
+/// Maximum length in bytes of a value in a key-value store of a world state entity.
+pub const DEFAULT_STORE_MAX_VALUE_LENGTH: u64 = 4096;
+/// Number of results of a list query returned if the query does not specify a limit.
+pub const DEFAULT_QUERY_DEFAULT_PAGE_SIZE: u32 = 100;
+/// Maximum number of results of a list query returned in one page.
+pub const DEFAULT_QUERY_MAX_PAGE_SIZE: u32 = 1000;
+/// Maximum number of entries returned by an aggregate query.
+pub const DEFAULT_QUERY_MAX_AGGREGATE_RESULTS: u32 = 1000;
+/// Maximum difference in milliseconds between the timestamp of a query and the time of the peer,
+/// older and future queries are rejected.
+pub const DEFAULT_QUERY_TIMESTAMP_WINDOW_MS: u64 = 60_000;
 
 /// Configuration parameters container.
 pub struct Configuration {
@@ -51,12 +99,35 @@ pub struct Configuration {
     pub max_faulty_peers: usize,
     /// Public key of this peer. Should be the same as in `peer_id`
     pub public_key: PublicKey,
-    /// Private key of this peer.
-    pub private_key: PrivateKey,
+    /// Private key of this peer, required to run a peer, but not to run a client.
+    pub private_key: Option<PrivateKey>,
+    /// Public key of the `root@global` account created at the start of the blockchain. Should be
+    /// the same for all peers. Defaults to `public_key`.
+    pub root_public_key: PublicKey,
     /// Amount of time Peer waits for CommitMessage from the proxy tail.
     pub commit_time_ms: u64,
     /// Amount of time Peer waits for TxReceipt from the leader.
     pub tx_receipt_time_ms: u64,
+    /// Limits checked by Torii during stateless validation of incoming transactions.
+    pub transaction_limits: TransactionLimits,
+    /// Account on behalf of which clients submit transactions and queries, signing them with
+    /// `client_public_key` and `client_private_key`.
+    pub account_id: AccountId,
+    /// Public key of the client, should be a signatory of `account_id`.
+    pub client_public_key: Option<PublicKey>,
+    /// Private key of the client. Better to be provided through the environment, than stored in
+    /// the configuration file.
+    pub client_private_key: Option<PrivateKey>,
+    /// If set, Torii executes queries which are not signed by any account.
+    pub allow_unsigned_queries: bool,
+    /// Rules of retaining world state snapshots which historical queries are answered from.
+    pub snapshot_retention: SnapshotRetention,
+    /// Limits of the key-value stores of the world state entities. Should be the same for all
+    /// peers, otherwise they will disagree on the results of the instructions.
+    pub store_limits: StoreLimits,
+    /// Limits of the pages of list query results and of aggregate query results returned by
+    /// Torii.
+    pub query_limits: QueryLimits,
 }
 
 impl Configuration {
@@ -115,18 +186,74 @@ impl Configuration {
                     .or_else(|| config_map.remove(IROHA_PUBLIC_KEY))
                     .ok_or("IROHA_PUBLIC_KEY should be set.")?,
             )?,
-            private_key: parse_private_key(
-                &env::var(IROHA_PRIVATE_KEY)
-                    .ok()
-                    .or_else(|| config_map.remove(IROHA_PRIVATE_KEY))
-                    .ok_or("IROHA_PRIVATE_KEY should be set.")?,
-            )?,
+            private_key: env::var(IROHA_PRIVATE_KEY)
+                .ok()
+                .or_else(|| config_map.remove(IROHA_PRIVATE_KEY))
+                .map(|private_key| parse_private_key(&private_key))
+                .transpose()?,
+            root_public_key: env::var(ROOT_PUBLIC_KEY)
+                .ok()
+                .or_else(|| config_map.remove(ROOT_PUBLIC_KEY))
+                .map(|public_key| parse_public_key(&public_key))
+                .transpose()?,
+            client_public_key: env::var(CLIENT_PUBLIC_KEY)
+                .ok()
+                .or_else(|| config_map.remove(CLIENT_PUBLIC_KEY))
+                .map(|public_key| parse_public_key(&public_key))
+                .transpose()?,
+            client_private_key: env::var(CLIENT_PRIVATE_KEY)
+                .ok()
+                .or_else(|| config_map.remove(CLIENT_PRIVATE_KEY))
+                .map(|private_key| parse_private_key(&private_key))
+                .transpose()?,
             commit_time_ms: env::var(COMMIT_TIME_MS)
                 .ok()
                 .or_else(|| config_map.remove(COMMIT_TIME_MS)),
             tx_receipt_time_ms: env::var(TX_RECEIPT_TIME_MS)
                 .ok()
                 .or_else(|| config_map.remove(TX_RECEIPT_TIME_MS)),
+            max_transaction_size: env::var(MAX_TRANSACTION_SIZE)
+                .ok()
+                .or_else(|| config_map.remove(MAX_TRANSACTION_SIZE)),
+            max_instruction_number: env::var(MAX_INSTRUCTION_NUMBER)
+                .ok()
+                .or_else(|| config_map.remove(MAX_INSTRUCTION_NUMBER)),
+            max_instruction_depth: env::var(MAX_INSTRUCTION_DEPTH)
+                .ok()
+                .or_else(|| config_map.remove(MAX_INSTRUCTION_DEPTH)),
+            account_id: env::var(ACCOUNT_ID)
+                .ok()
+                .or_else(|| config_map.remove(ACCOUNT_ID)),
+            allow_unsigned_queries: env::var(ALLOW_UNSIGNED_QUERIES)
+                .ok()
+                .or_else(|| config_map.remove(ALLOW_UNSIGNED_QUERIES)),
+            wsv_snapshot_interval: env::var(WSV_SNAPSHOT_INTERVAL)
+                .ok()
+                .or_else(|| config_map.remove(WSV_SNAPSHOT_INTERVAL)),
+            wsv_max_snapshots: env::var(WSV_MAX_SNAPSHOTS)
+                .ok()
+                .or_else(|| config_map.remove(WSV_MAX_SNAPSHOTS)),
+            store_max_entries: env::var(STORE_MAX_ENTRIES)
+                .ok()
+                .or_else(|| config_map.remove(STORE_MAX_ENTRIES)),
+            store_max_key_length: env::var(STORE_MAX_KEY_LENGTH)
+                .ok()
+                .or_else(|| config_map.remove(STORE_MAX_KEY_LENGTH)),
+            store_max_value_length: env::var(STORE_MAX_VALUE_LENGTH)
+                .ok()
+                .or_else(|| config_map.remove(STORE_MAX_VALUE_LENGTH)),
+            query_default_page_size: env::var(QUERY_DEFAULT_PAGE_SIZE)
+                .ok()
+                .or_else(|| config_map.remove(QUERY_DEFAULT_PAGE_SIZE)),
+            query_max_page_size: env::var(QUERY_MAX_PAGE_SIZE)
+                .ok()
+                .or_else(|| config_map.remove(QUERY_MAX_PAGE_SIZE)),
+            query_max_aggregate_results: env::var(QUERY_MAX_AGGREGATE_RESULTS)
+                .ok()
+                .or_else(|| config_map.remove(QUERY_MAX_AGGREGATE_RESULTS)),
+            query_timestamp_window_ms: env::var(QUERY_TIMESTAMP_WINDOW_MS)
+                .ok()
+                .or_else(|| config_map.remove(QUERY_TIMESTAMP_WINDOW_MS)),
         }
         .build()?)
     }
@@ -157,9 +284,27 @@ impl Configuration {
         self.max_faulty_peers = max_faulty_peers;
     }
 
-    /// Set `public_key` and `private_key` configuration parameters - will overwrite the existing one.
-    pub fn key_pair(&self) -> (PublicKey, PrivateKey) {
-        (self.public_key, self.private_key)
+    /// Set `account_id` configuration parameter - will overwrite the existing one.
+    pub fn account_id(&mut self, account_id: AccountId) {
+        self.account_id = account_id;
+    }
+
+    /// Get `public_key` and `private_key` of this peer.
+    ///
+    /// # Errors
+    /// Returns error if the private key of the peer is not configured.
+    pub fn key_pair(&self) -> Result<(PublicKey, PrivateKey), String> {
+        let private_key = self
+            .private_key
+            .ok_or("IROHA_PRIVATE_KEY should be set to run a peer.")?;
+        Ok((self.public_key, private_key))
+    }
+
+    /// Set `client_public_key` and `client_private_key` configuration parameters - will overwrite
+    /// the existing ones.
+    pub fn client_key_pair(&mut self, public_key: PublicKey, private_key: PrivateKey) {
+        self.client_public_key = Some(public_key);
+        self.client_private_key = Some(private_key);
     }
 }
 
@@ -175,12 +320,6 @@ impl Display for Configuration {
 
 impl Debug for Configuration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let first_half_of_private_key: [u8; 32] = self.private_key[0..32]
-            .try_into()
-            .expect("Wrong format of private key.");
-        let second_half_of_private_key: [u8; 32] = self.private_key[32..64]
-            .try_into()
-            .expect("Wrong format of private key.");
         f.debug_struct("Configuration")
             .field("peer_id", &self.peer_id)
             .field("block_build_step_ms", &self.block_build_step_ms)
@@ -189,9 +328,15 @@ impl Debug for Configuration {
             .field("trusted_peers", &self.trusted_peers)
             .field("max_faulty_peers", &self.max_faulty_peers)
             .field("public_key", &self.public_key)
-            .field("private_key[0..32]", &first_half_of_private_key)
-            .field("private_key[32..64]", &second_half_of_private_key)
+            .field("root_public_key", &self.root_public_key)
             .field("commit_time_ms", &self.commit_time_ms)
+            .field("transaction_limits", &self.transaction_limits)
+            .field("account_id", &self.account_id)
+            .field("client_public_key", &self.client_public_key)
+            .field("allow_unsigned_queries", &self.allow_unsigned_queries)
+            .field("snapshot_retention", &self.snapshot_retention)
+            .field("store_limits", &self.store_limits)
+            .field("query_limits", &self.query_limits)
             .finish()
     }
 }
@@ -204,9 +349,26 @@ struct ConfigurationBuilder {
     trusted_peers: Vec<PeerId>,
     max_faulty_peers: Option<String>,
     public_key: PublicKey,
-    private_key: PrivateKey,
+    private_key: Option<PrivateKey>,
+    root_public_key: Option<PublicKey>,
+    client_public_key: Option<PublicKey>,
+    client_private_key: Option<PrivateKey>,
     commit_time_ms: Option<String>,
     tx_receipt_time_ms: Option<String>,
+    max_transaction_size: Option<String>,
+    max_instruction_number: Option<String>,
+    max_instruction_depth: Option<String>,
+    account_id: Option<String>,
+    allow_unsigned_queries: Option<String>,
+    wsv_snapshot_interval: Option<String>,
+    wsv_max_snapshots: Option<String>,
+    store_max_entries: Option<String>,
+    store_max_key_length: Option<String>,
+    store_max_value_length: Option<String>,
+    query_default_page_size: Option<String>,
+    query_max_page_size: Option<String>,
+    query_max_aggregate_results: Option<String>,
+    query_timestamp_window_ms: Option<String>,
 }
 
 impl ConfigurationBuilder {
@@ -217,6 +379,34 @@ impl ConfigurationBuilder {
                 .unwrap_or_else(|| DEFAULT_TORII_URL.to_string()),
             public_key: self.public_key,
         };
+        let query_limits = QueryLimits {
+            default_page_size: self
+                .query_default_page_size
+                .unwrap_or_else(|| DEFAULT_QUERY_DEFAULT_PAGE_SIZE.to_string())
+                .parse()
+                .expect("Query default page size should be a number."),
+            max_page_size: self
+                .query_max_page_size
+                .unwrap_or_else(|| DEFAULT_QUERY_MAX_PAGE_SIZE.to_string())
+                .parse()
+                .expect("Query max page size should be a number."),
+            max_aggregate_results: self
+                .query_max_aggregate_results
+                .unwrap_or_else(|| DEFAULT_QUERY_MAX_AGGREGATE_RESULTS.to_string())
+                .parse()
+                .expect("Query max aggregate results should be a number."),
+            timestamp_window_ms: self
+                .query_timestamp_window_ms
+                .unwrap_or_else(|| DEFAULT_QUERY_TIMESTAMP_WINDOW_MS.to_string())
+                .parse()
+                .expect("Query timestamp window should be a number."),
+        };
+        if query_limits.default_page_size > query_limits.max_page_size {
+            return Err(format!(
+                "Query default page size {} should not exceed max page size {}.",
+                query_limits.default_page_size, query_limits.max_page_size
+            ));
+        }
         Ok(Configuration {
             peer_id,
             block_build_step_ms: self
@@ -224,7 +414,7 @@ impl ConfigurationBuilder {
                 .unwrap_or_else(|| DEFAULT_BLOCK_TIME_MS.to_string())
                 .parse()
                 .expect("Block build step should be a number."),
-            mode: self.mode.unwrap_or_else(|| DEFAULT_KURA_INIT_MODE),
+            mode: self.mode.unwrap_or(DEFAULT_KURA_INIT_MODE),
             kura_block_store_path: self
                 .kura_block_store_path
                 .unwrap_or_else(|| DEFAULT_KURA_BLOCK_STORE_PATH.to_string()),
@@ -236,6 +426,7 @@ impl ConfigurationBuilder {
                 .map_err(|e| format!("Max faulty peers parse failed: {}", e))?,
             public_key: self.public_key,
             private_key: self.private_key,
+            root_public_key: self.root_public_key.unwrap_or(self.public_key),
             commit_time_ms: self
                 .commit_time_ms
                 .unwrap_or_else(|| DEFAULT_COMMIT_TIME_MS.to_string())
@@ -246,6 +437,65 @@ impl ConfigurationBuilder {
                 .unwrap_or_else(|| DEFAULT_TX_RECEIPT_TIME_MS.to_string())
                 .parse()
                 .expect("Tx receipt time should be a number."),
+            transaction_limits: TransactionLimits {
+                max_instruction_number: self
+                    .max_instruction_number
+                    .unwrap_or_else(|| DEFAULT_MAX_INSTRUCTION_NUMBER.to_string())
+                    .parse()
+                    .expect("Max instruction number should be a number."),
+                max_instruction_depth: self
+                    .max_instruction_depth
+                    .unwrap_or_else(|| DEFAULT_MAX_INSTRUCTION_DEPTH.to_string())
+                    .parse()
+                    .expect("Max instruction depth should be a number."),
+                max_transaction_size: self
+                    .max_transaction_size
+                    .unwrap_or_else(|| DEFAULT_MAX_TRANSACTION_SIZE.to_string())
+                    .parse()
+                    .expect("Max transaction size should be a number."),
+            },
+            account_id: parse_account_id(
+                &self
+                    .account_id
+                    .unwrap_or_else(|| DEFAULT_ACCOUNT_ID.to_string()),
+            )?,
+            client_public_key: self.client_public_key,
+            client_private_key: self.client_private_key,
+            allow_unsigned_queries: self
+                .allow_unsigned_queries
+                .unwrap_or_else(|| DEFAULT_ALLOW_UNSIGNED_QUERIES.to_string())
+                .parse()
+                .expect("Allow unsigned queries should be a boolean."),
+            snapshot_retention: SnapshotRetention {
+                snapshot_interval: self
+                    .wsv_snapshot_interval
+                    .unwrap_or_else(|| DEFAULT_WSV_SNAPSHOT_INTERVAL.to_string())
+                    .parse()
+                    .expect("WSV snapshot interval should be a number."),
+                max_snapshots: self
+                    .wsv_max_snapshots
+                    .unwrap_or_else(|| DEFAULT_WSV_MAX_SNAPSHOTS.to_string())
+                    .parse()
+                    .expect("WSV max snapshots should be a number."),
+            },
+            store_limits: StoreLimits {
+                max_entries: self
+                    .store_max_entries
+                    .unwrap_or_else(|| DEFAULT_STORE_MAX_ENTRIES.to_string())
+                    .parse()
+                    .expect("Store max entries should be a number."),
+                max_key_length: self
+                    .store_max_key_length
+                    .unwrap_or_else(|| DEFAULT_STORE_MAX_KEY_LENGTH.to_string())
+                    .parse()
+                    .expect("Store max key length should be a number."),
+                max_value_length: self
+                    .store_max_value_length
+                    .unwrap_or_else(|| DEFAULT_STORE_MAX_VALUE_LENGTH.to_string())
+                    .parse()
+                    .expect("Store max value length should be a number."),
+            },
+            query_limits,
         })
     }
 }
@@ -296,6 +546,20 @@ fn parse_trusted_peers(trusted_peers_string: Option<String>) -> Result<Vec<PeerI
     }
 }
 
+/// Parses string formatted as "account_name@domain_name" into `AccountId`.
+fn parse_account_id(account_id_string: &str) -> Result<AccountId, String> {
+    let mut parts = account_id_string.split('@');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(name), Some(domain_name), None) if !name.is_empty() && !domain_name.is_empty() => {
+            Ok(AccountId::new(name, domain_name))
+        }
+        _ => Err(format!(
+            "Account id should be in the `account_name@domain_name` format: {}",
+            account_id_string
+        )),
+    }
+}
+
 /// Parses string formatted as "[ byte1, byte2, ... ]" into `crypto::PublicKey`.
 fn parse_public_key(public_key_string: &str) -> Result<PublicKey, String> {
     let vector: Vec<u8> = public_key_string
@@ -379,6 +643,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn client_keys_should_not_default_to_peer_keys() -> Result<(), String> {
+        let mut configuration = Configuration::from_path(CONFIGURATION_PATH)?;
+        assert_eq!(None, configuration.client_public_key);
+        assert!(configuration.client_private_key.is_none());
+        assert_eq!(configuration.public_key, configuration.root_public_key);
+        assert!(configuration.key_pair().is_ok());
+        configuration.private_key = None;
+        assert!(configuration.key_pair().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_account_id_success() {
+        assert_eq!(
+            Ok(AccountId::new("alice", "wonderland")),
+            parse_account_id("alice@wonderland")
+        );
+    }
+
+    #[test]
+    fn parse_malformed_account_id_should_fail() {
+        assert!(parse_account_id("alice").is_err());
+        assert!(parse_account_id("alice@").is_err());
+        assert!(parse_account_id("alice@wonderland@looking_glass").is_err());
+    }
+
     #[test]
     fn parse_public_key_success() {
         let public_key_string = "[101, 170, 80, 164, 103, 38, 73, 61, 223, 133, 83, 139, 247, 77, 176, 84, 117, 15, 22, 28, 155, 125, 80, 226, 40, 26, 61, 248, 40, 159, 58, 53]";
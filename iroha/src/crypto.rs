@@ -7,7 +7,7 @@ use std::{
 };
 use ursa::{
     blake2::{
-        digest::{Input, VariableOutput},
+        digest::{Update, VariableOutput},
         VarBlake2b,
     },
     keys::{KeyGenOption, PrivateKey as UrsaPrivateKey, PublicKey as UrsaPublicKey},
@@ -55,7 +55,7 @@ pub fn hash(bytes: Vec<u8>) -> Hash {
     let vec_hash = VarBlake2b::new(32)
         .expect("Failed to initialize variable size hash")
         .chain(bytes)
-        .vec_result();
+        .finalize_boxed();
     let mut hash = [0; 32];
     hash.copy_from_slice(&vec_hash);
     hash
@@ -126,7 +126,7 @@ mod tests {
 
     use hex_literal::hex;
     use ursa::blake2::{
-        digest::{Input, VariableOutput},
+        digest::{Update, VariableOutput},
         VarBlake2b,
     };
 
@@ -142,8 +142,8 @@ mod tests {
     #[test]
     fn blake2_32b() {
         let mut hasher = VarBlake2b::new(32).unwrap();
-        hasher.input(hex!("6920616d2064617461"));
-        hasher.variable_result(|res| {
+        hasher.update(hex!("6920616d2064617461"));
+        hasher.finalize_variable(|res| {
             assert_eq!(
                 res[..],
                 hex!("ba67336efd6a3df3a70eeb757860763036785c182ff4cf587541a0068d09f5b2")[..]
@@ -1,18 +1,21 @@
 //! This module contains `Domain` structure and related implementations and trait implementations.
 use crate::{isi::prelude::*, prelude::*};
-use std::collections::HashMap;
+use parity_scale_codec::{Decode, Encode};
+use std::collections::BTreeMap;
 
 type Name = String;
 
 /// Named group of `Account` and `Asset` entities.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct Domain {
     /// Domain name, for example company name.
     pub name: Name,
     /// Accounts of the domain.
-    pub accounts: HashMap<<Account as Identifiable>::Id, Account>,
+    pub accounts: BTreeMap<<Account as Identifiable>::Id, Account>,
     /// Assets of the domain.
-    pub asset_definitions: HashMap<<AssetDefinition as Identifiable>::Id, AssetDefinition>,
+    pub asset_definitions: BTreeMap<<AssetDefinition as Identifiable>::Id, AssetDefinition>,
+    /// Arbitrary key-value attributes of the domain, like a display name.
+    pub metadata: BTreeMap<String, String>,
 }
 
 impl Domain {
@@ -22,8 +25,9 @@ impl Domain {
     pub fn new(name: Name) -> Self {
         Domain {
             name,
-            accounts: HashMap::new(),
-            asset_definitions: HashMap::new(),
+            accounts: BTreeMap::new(),
+            asset_definitions: BTreeMap::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -42,6 +46,28 @@ impl Domain {
             destination_id: self.name.clone(),
         }
     }
+
+    /// Constructor of `Unregister<Domain, Account>` Iroha Special Instruction.
+    pub fn unregister_account(
+        &self,
+        object_id: <Account as Identifiable>::Id,
+    ) -> Unregister<Domain, Account> {
+        Unregister {
+            object_id,
+            destination_id: self.name.clone(),
+        }
+    }
+
+    /// Constructor of `Unregister<Domain, AssetDefinition>` Iroha Special Instruction.
+    pub fn unregister_asset(
+        &self,
+        object_id: <AssetDefinition as Identifiable>::Id,
+    ) -> Unregister<Domain, AssetDefinition> {
+        Unregister {
+            object_id,
+            destination_id: self.name.clone(),
+        }
+    }
 }
 
 impl Identifiable for Domain {
@@ -53,7 +79,12 @@ impl Identifiable for Domain {
 /// and the `From/Into` implementations to convert `DomainInstruction` variants into generic ISI.
 pub mod isi {
     use super::*;
-    use crate::{isi::Register, permission::isi::PermissionInstruction};
+    use crate::{
+        isi::{
+            validate_name, Register, RegistrationError, RemoveKeyValue, SetKeyValue, Unregister,
+        },
+        permission::{isi::PermissionInstruction, permission_asset_definition_id},
+    };
     use iroha_derive::*;
     use parity_scale_codec::{Decode, Encode};
 
@@ -64,6 +95,14 @@ pub mod isi {
         RegisterAccount(Name, Account),
         /// Variant of the generic `Register` instruction for `AssetDefinition` --> `Domain`.
         RegisterAsset(Name, AssetDefinition),
+        /// Variant of the generic `Unregister` instruction for `Account` --> `Domain`.
+        UnregisterAccount(Name, <Account as Identifiable>::Id),
+        /// Variant of the generic `Unregister` instruction for `AssetDefinition` --> `Domain`.
+        UnregisterAsset(Name, <AssetDefinition as Identifiable>::Id),
+        /// Variant of the generic `SetKeyValue` instruction for `Domain` metadata.
+        SetKeyValue(Name, String, String),
+        /// Variant of the generic `RemoveKeyValue` instruction for `Domain` metadata.
+        RemoveKeyValue(Name, String),
     }
 
     impl DomainInstruction {
@@ -78,10 +117,35 @@ pub mod isi {
                 DomainInstruction::RegisterAccount(domain_name, account) => {
                     Register::new(account.clone(), domain_name.clone())
                         .execute(authority, world_state_view)
+                        .map_err(String::from)
                 }
                 DomainInstruction::RegisterAsset(domain_name, asset) => {
                     Register::new(asset.clone(), domain_name.clone())
                         .execute(authority, world_state_view)
+                        .map_err(String::from)
+                }
+                DomainInstruction::UnregisterAccount(domain_name, account_id) => {
+                    Unregister::<Domain, Account>::new(account_id.clone(), domain_name.clone())
+                        .execute(authority, world_state_view)
+                }
+                DomainInstruction::UnregisterAsset(domain_name, asset_definition_id) => {
+                    Unregister::<Domain, AssetDefinition>::new(
+                        asset_definition_id.clone(),
+                        domain_name.clone(),
+                    )
+                    .execute(authority, world_state_view)
+                }
+                DomainInstruction::SetKeyValue(domain_name, key, value) => {
+                    SetKeyValue::<Domain, String, String>::new(
+                        domain_name.clone(),
+                        key.clone(),
+                        value.clone(),
+                    )
+                    .execute(authority, world_state_view)
+                }
+                DomainInstruction::RemoveKeyValue(domain_name, key) => {
+                    RemoveKeyValue::<Domain, String>::new(domain_name.clone(), key.clone())
+                        .execute(authority, world_state_view)
                 }
             }
         }
@@ -101,17 +165,35 @@ pub mod isi {
             &self,
             authority: <Account as Identifiable>::Id,
             world_state_view: &mut WorldStateView,
-        ) -> Result<(), String> {
+        ) -> Result<(), RegistrationError> {
             PermissionInstruction::CanRegisterAccount(authority, None).execute(world_state_view)?;
             let account = self.object.clone();
+            validate_name(&account.id.name)?;
+            if account.id.domain_name != self.destination_id {
+                return Err(RegistrationError::InvalidId(format!(
+                    "account {}@{} does not belong to the domain {}",
+                    account.id.name, account.id.domain_name, self.destination_id
+                )));
+            }
+            if !account.assets.is_empty() {
+                return Err(RegistrationError::Rejected(format!(
+                    "Account {}@{} should be registered without assets.",
+                    account.id.name, account.id.domain_name
+                )));
+            }
+            world_state_view
+                .store_limits()
+                .check_store(&account.metadata)?;
             let domain = world_state_view
                 .domain(&self.destination_id)
-                .ok_or("Failed to find domain.")?;
+                .ok_or_else(|| {
+                    RegistrationError::NotFound(format!("Domain {}", self.destination_id))
+                })?;
             if domain.accounts.contains_key(&account.id) {
-                Err(format!(
-                    "Domain already contains an account with an Id: {:?}",
-                    &account.id
-                ))
+                Err(RegistrationError::AlreadyExists(format!(
+                    "Account {}@{}",
+                    account.id.name, account.id.domain_name
+                )))
             } else {
                 domain.accounts.insert(account.id.clone(), account);
                 Ok(())
@@ -133,16 +215,783 @@ pub mod isi {
             &self,
             authority: <Account as Identifiable>::Id,
             world_state_view: &mut WorldStateView,
-        ) -> Result<(), String> {
+        ) -> Result<(), RegistrationError> {
             PermissionInstruction::CanRegisterAssetDefinition(authority, None)
                 .execute(world_state_view)?;
-            let asset = self.object.clone();
+            let asset_definition = self.object.clone();
+            validate_name(&asset_definition.id.name)?;
+            if asset_definition.id.domain_name != self.destination_id {
+                return Err(RegistrationError::InvalidId(format!(
+                    "asset definition {} does not belong to the domain {}",
+                    asset_definition.id, self.destination_id
+                )));
+            }
+            asset_definition.validate()?;
+            world_state_view
+                .store_limits()
+                .check_store(&asset_definition.metadata)?;
+            let domain = world_state_view
+                .domain(&self.destination_id)
+                .ok_or_else(|| {
+                    RegistrationError::NotFound(format!("Domain {}", self.destination_id))
+                })?;
+            if domain.asset_definitions.contains_key(&asset_definition.id) {
+                Err(RegistrationError::AlreadyExists(format!(
+                    "Asset definition {}",
+                    asset_definition.id
+                )))
+            } else {
+                domain
+                    .asset_definitions
+                    .insert(asset_definition.id.clone(), asset_definition);
+                Ok(())
+            }
+        }
+    }
+
+    /// Unregisters the account from the domain.
+    /// The account should not hold non-empty assets - balances should be burned or transferred
+    /// and store values removed first, empty assets are removed together with the account.
+    /// Accounts holding permissions, `root` among them, can not be unregistered.
+    /// Transactions of the account stay in the history and can still be queried.
+    impl Unregister<Domain, Account> {
+        pub(crate) fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanUnregisterAccount(authority, self.destination_id.clone())
+                .execute(world_state_view)?;
+            if self.object_id.domain_name != self.destination_id {
+                return Err(format!(
+                    "Account {:?} does not belong to the domain {}.",
+                    self.object_id, self.destination_id
+                ));
+            }
+            if world_state_view.read_account(&self.object_id).is_none() {
+                return Err(format!("Failed to find account {:?}.", self.object_id));
+            }
+            if world_state_view
+                .read_asset(&AssetId {
+                    definition_id: permission_asset_definition_id(),
+                    account_id: self.object_id.clone(),
+                })
+                .is_some()
+            {
+                return Err(format!(
+                    "Account {:?} holds permissions and can not be unregistered.",
+                    self.object_id
+                ));
+            }
+            detach_assets(world_state_view, |asset| {
+                asset.id.account_id == self.object_id
+            })?;
+            world_state_view
+                .domain(&self.destination_id)
+                .ok_or("Failed to find domain.")?
+                .accounts
+                .remove(&self.object_id);
+            Ok(())
+        }
+    }
+
+    impl From<Unregister<Domain, Account>> for Instruction {
+        fn from(instruction: Unregister<Domain, Account>) -> Self {
+            Instruction::Domain(DomainInstruction::UnregisterAccount(
+                instruction.destination_id,
+                instruction.object_id,
+            ))
+        }
+    }
+
+    /// Unregisters the asset definition from the domain.
+    /// No account should hold a non-empty asset of the definition - balances should be burned
+    /// and store values removed first, empty assets are removed from all accounts together with
+    /// the definition.
+    /// Transactions with the asset stay in the history and can still be queried.
+    impl Unregister<Domain, AssetDefinition> {
+        pub(crate) fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanUnregisterAssetDefinition(
+                authority,
+                self.destination_id.clone(),
+            )
+            .execute(world_state_view)?;
+            if self.object_id.domain_name != self.destination_id {
+                return Err(format!(
+                    "Asset definition {} does not belong to the domain {}.",
+                    self.object_id, self.destination_id
+                ));
+            }
+            if self.object_id == permission_asset_definition_id() {
+                return Err("Permission asset definition can not be unregistered.".to_string());
+            }
+            if world_state_view
+                .read_asset_definition(&self.object_id)
+                .is_none()
+            {
+                return Err(format!(
+                    "Failed to find asset definition {}.",
+                    self.object_id
+                ));
+            }
+            detach_assets(world_state_view, |asset| {
+                asset.id.definition_id == self.object_id
+            })?;
             world_state_view
                 .domain(&self.destination_id)
                 .ok_or("Failed to find domain.")?
                 .asset_definitions
-                .insert(asset.id.clone(), asset);
+                .remove(&self.object_id);
+            Ok(())
+        }
+    }
+
+    impl From<Unregister<Domain, AssetDefinition>> for Instruction {
+        fn from(instruction: Unregister<Domain, AssetDefinition>) -> Self {
+            Instruction::Domain(DomainInstruction::UnregisterAsset(
+                instruction.destination_id,
+                instruction.object_id,
+            ))
+        }
+    }
+
+    /// Removes assets selected by `is_detached` from all accounts of the world state.
+    /// Returns `Err(String)` without changing anything if any of these assets is not empty.
+    pub(crate) fn detach_assets<F>(
+        world_state_view: &mut WorldStateView,
+        is_detached: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(&Asset) -> bool,
+    {
+        if let Some(asset) = world_state_view
+            .read_peer()
+            .domains
+            .values()
+            .flat_map(|domain| domain.accounts.values())
+            .flat_map(|account| account.assets.values())
+            .find(|asset| is_detached(asset) && !asset.is_empty())
+        {
+            return Err(format!(
+                "Asset {} of {:?} is not empty, burn, transfer or remove its contents first.",
+                asset.id.definition_id, asset.id.account_id
+            ));
+        }
+        let detached_ids: Vec<<Asset as Identifiable>::Id> = world_state_view
+            .read_peer()
+            .domains
+            .values()
+            .flat_map(|domain| domain.accounts.values())
+            .flat_map(|account| account.assets.values())
+            .filter(|asset| is_detached(asset))
+            .map(|asset| asset.id.clone())
+            .collect();
+        for asset_id in detached_ids {
+            world_state_view
+                .account(&asset_id.account_id)
+                .ok_or("Failed to find account.")?
+                .assets
+                .remove(&asset_id);
+        }
+        Ok(())
+    }
+
+    impl SetKeyValue<Domain, String, String> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanSetKeyValueInDomain(authority, self.object_id.clone())
+                .execute(world_state_view)?;
+            let store_limits = world_state_view.store_limits();
+            let domain = world_state_view
+                .domain(&self.object_id)
+                .ok_or("Failed to find domain.")?;
+            store_limits.insert(&mut domain.metadata, self.key.clone(), self.value.clone())
+        }
+    }
+
+    impl RemoveKeyValue<Domain, String> {
+        fn execute(
+            &self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanSetKeyValueInDomain(authority, self.object_id.clone())
+                .execute(world_state_view)?;
+            world_state_view
+                .domain(&self.object_id)
+                .ok_or("Failed to find domain.")?
+                .metadata
+                .remove(&self.key)
+                .ok_or_else(|| format!("No value with key {} found.", self.key))?;
             Ok(())
         }
     }
+
+    impl From<SetKeyValue<Domain, String, String>> for Instruction {
+        fn from(instruction: SetKeyValue<Domain, String, String>) -> Self {
+            Instruction::Domain(DomainInstruction::SetKeyValue(
+                instruction.object_id,
+                instruction.key,
+                instruction.value,
+            ))
+        }
+    }
+
+    impl From<RemoveKeyValue<Domain, String>> for Instruction {
+        fn from(instruction: RemoveKeyValue<Domain, String>) -> Self {
+            Instruction::Domain(DomainInstruction::RemoveKeyValue(
+                instruction.object_id,
+                instruction.key,
+            ))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{
+            isi,
+            isi::{fixtures::*, MAX_NAME_LENGTH},
+        };
+
+        #[test]
+        fn unregister_should_refuse_non_empty_balances() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            let xor_definition_id = AssetDefinitionId::new("xor", "global");
+            let alice_xor_id = AssetId {
+                definition_id: xor_definition_id.clone(),
+                account_id: alice_id.clone(),
+            };
+            Instruction::from(isi::Mint::<Asset, u32>::new(10, alice_xor_id.clone()))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            let unregister_alice: Instruction =
+                isi::Unregister::<Domain, Account>::new(alice_id.clone(), "global".to_string())
+                    .into();
+            let unregister_xor: Instruction = isi::Unregister::<Domain, AssetDefinition>::new(
+                xor_definition_id.clone(),
+                "global".to_string(),
+            )
+            .into();
+            assert!(unregister_alice
+                .execute(alice_id.clone(), &mut world_state_view)
+                .is_err());
+            assert!(unregister_alice
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            assert!(unregister_xor
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            Instruction::from(isi::Burn::<Asset, u32>::new(10, alice_xor_id.clone()))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to burn asset.");
+            unregister_xor
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to unregister asset definition.");
+            assert!(world_state_view.read_asset(&alice_xor_id).is_none());
+            assert!(world_state_view
+                .read_asset_definition(&xor_definition_id)
+                .is_none());
+            unregister_alice
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to unregister account.");
+            assert!(world_state_view.read_account(&alice_id).is_none());
+            let peer_id = world_state_view.read_peer().id.clone();
+            Instruction::from(isi::Add::<Peer, Domain>::new(
+                Domain::new("wonderland".to_string()),
+                peer_id.clone(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to add domain.");
+            let rose_definition_id = AssetDefinitionId::new("rose", "wonderland");
+            Instruction::from(isi::Register::<Domain, AssetDefinition>::new(
+                AssetDefinition::new(rose_definition_id.clone()),
+                "wonderland".to_string(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to register asset definition.");
+            let root_rose_id = AssetId {
+                definition_id: rose_definition_id,
+                account_id: root_id.clone(),
+            };
+            Instruction::from(isi::Mint::<Asset, u32>::new(1, root_rose_id.clone()))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to mint asset.");
+            let remove_wonderland: Instruction =
+                isi::Remove::<Peer, Domain>::new("wonderland".to_string(), peer_id.clone()).into();
+            assert!(remove_wonderland
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            Instruction::from(isi::Burn::<Asset, u32>::new(1, root_rose_id.clone()))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to burn asset.");
+            remove_wonderland
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to remove domain.");
+            assert!(world_state_view.read_domain("wonderland").is_none());
+            assert!(world_state_view.read_asset(&root_rose_id).is_none());
+            assert!(Instruction::from(isi::Remove::<Peer, Domain>::new(
+                "global".to_string(),
+                peer_id
+            ))
+            .execute(root_id, &mut world_state_view)
+            .is_err());
+        }
+
+        #[test]
+        fn unregister_should_refuse_accounts_with_permissions_or_store_values() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            world_state_view
+                .add_asset(Asset::with_permission(
+                    AssetId {
+                        definition_id: permission_asset_definition_id(),
+                        account_id: alice_id.clone(),
+                    },
+                    ("unregister_account".to_string(), "global".to_string()),
+                ))
+                .expect("Failed to grant permission.");
+            let unregister_root: Instruction =
+                isi::Unregister::<Domain, Account>::new(root_id.clone(), "global".to_string())
+                    .into();
+            assert!(unregister_root
+                .execute(alice_id.clone(), &mut world_state_view)
+                .is_err());
+            assert!(world_state_view.read_account(&root_id).is_some());
+            let bob_id = add_account(&mut world_state_view, "bob", public_key);
+            let bob_note_id = AssetId {
+                definition_id: AssetDefinitionId::new("note", "global"),
+                account_id: bob_id.clone(),
+            };
+            Instruction::from(isi::Register::<Domain, AssetDefinition>::new(
+                AssetDefinition::with_value_type(
+                    bob_note_id.definition_id.clone(),
+                    AssetValueType::Store,
+                    Mintable::Not,
+                ),
+                "global".to_string(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to register asset definition.");
+            Instruction::from(isi::SetKeyValue::<Asset, String, String>::new(
+                bob_note_id.clone(),
+                "text".to_string(),
+                "hello".to_string(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to set key value.");
+            let unregister_bob: Instruction =
+                isi::Unregister::<Domain, Account>::new(bob_id.clone(), "global".to_string())
+                    .into();
+            assert!(unregister_bob
+                .execute(alice_id.clone(), &mut world_state_view)
+                .is_err());
+            assert!(world_state_view.read_asset(&bob_note_id).is_some());
+            Instruction::from(isi::RemoveKeyValue::<Asset, String>::new(
+                bob_note_id.clone(),
+                "text".to_string(),
+            ))
+            .execute(root_id, &mut world_state_view)
+            .expect("Failed to remove key value.");
+            unregister_bob
+                .execute(alice_id, &mut world_state_view)
+                .expect("Failed to unregister account.");
+            assert!(world_state_view.read_account(&bob_id).is_none());
+        }
+
+        #[test]
+        fn registration_should_not_overwrite_existing_entities() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let root_id = AccountId::new("root", "global");
+            let register_asset_definition = |name: &str, domain_name: &str| {
+                Register::<Domain, AssetDefinition>::new(
+                    AssetDefinition::new(AssetDefinitionId::new(name, domain_name)),
+                    domain_name.to_string(),
+                )
+            };
+            assert_eq!(
+                Err(RegistrationError::AlreadyExists(
+                    "Asset definition btc#global".to_string()
+                )),
+                register_asset_definition("btc", "global")
+                    .execute(root_id.clone(), &mut world_state_view)
+            );
+            assert_eq!(
+                Some(AssetValueType::BigQuantity),
+                world_state_view
+                    .read_asset_definition(&AssetDefinitionId::new("btc", "global"))
+                    .map(|asset_definition| asset_definition.value_type)
+            );
+            assert_eq!(
+                Err(RegistrationError::NotFound("Domain unknown".to_string())),
+                register_asset_definition("gold", "unknown")
+                    .execute(root_id.clone(), &mut world_state_view)
+            );
+            assert!(matches!(
+                register_asset_definition("go#ld", "global")
+                    .execute(root_id.clone(), &mut world_state_view),
+                Err(RegistrationError::InvalidId(_))
+            ));
+            let register_account = |name: &str| {
+                Register::<Domain, Account>::new(
+                    Account::new(name, "global", public_key),
+                    "global".to_string(),
+                )
+            };
+            assert_eq!(
+                Err(RegistrationError::AlreadyExists(
+                    "Account root@global".to_string()
+                )),
+                register_account("root").execute(root_id.clone(), &mut world_state_view)
+            );
+            assert!(world_state_view
+                .read_asset(&AssetId {
+                    definition_id: permission_asset_definition_id(),
+                    account_id: root_id.clone(),
+                })
+                .is_some());
+            for name in &[
+                "al@ice",
+                "",
+                "\u{430}lice",
+                &"a".repeat(MAX_NAME_LENGTH + 1),
+            ] {
+                assert!(matches!(
+                    register_account(name).execute(root_id.clone(), &mut world_state_view),
+                    Err(RegistrationError::InvalidId(_))
+                ));
+            }
+            register_account(&"a".repeat(MAX_NAME_LENGTH))
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to register account.");
+            let peer_id = world_state_view.read_peer().id.clone();
+            assert_eq!(
+                Err(RegistrationError::AlreadyExists(
+                    "Domain global".to_string()
+                )),
+                Add::<Peer, Domain>::new(Domain::new("global".to_string()), peer_id.clone())
+                    .execute(root_id.clone(), &mut world_state_view)
+            );
+            assert!(world_state_view.read_account(&root_id).is_some());
+            assert!(matches!(
+                Add::<Peer, Domain>::new(Domain::new("wonder land".to_string()), peer_id)
+                    .execute(root_id, &mut world_state_view),
+                Err(RegistrationError::InvalidId(_))
+            ));
+        }
+
+        #[test]
+        fn metadata_should_be_set_by_permitted_accounts_within_limits() {
+            use crate::wsv::StoreLimits;
+
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view =
+                world_state_view_with_root(public_key).with_store_limits(StoreLimits {
+                    max_entries: 1,
+                    max_key_length: 8,
+                    max_value_length: 24,
+                });
+            let root_id = AccountId::new("root", "global");
+            let alice = Account::new("alice", "global", public_key);
+            let alice_id = alice.id.clone();
+            let bob = Account::new("bob", "global", public_key);
+            let bob_id = bob.id.clone();
+            let accounts = &mut world_state_view
+                .domain("global")
+                .expect("Failed to find domain.")
+                .accounts;
+            accounts.insert(alice_id.clone(), alice);
+            accounts.insert(bob_id.clone(), bob);
+            let set_in_account = |key: &str, value: &str| -> Instruction {
+                isi::SetKeyValue::<Account, String, String>::new(
+                    alice_id.clone(),
+                    key.to_string(),
+                    value.to_string(),
+                )
+                .into()
+            };
+            assert!(set_in_account("kyc", "passed")
+                .execute(alice_id.clone(), &mut world_state_view)
+                .is_err());
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "set_key_value_in_account",
+                "alice@global",
+            );
+            set_in_account("email", "alice@example.com")
+                .execute(alice_id.clone(), &mut world_state_view)
+                .expect("Failed to set account metadata.");
+            assert!(set_in_account("email", "alice@example.org")
+                .execute(bob_id.clone(), &mut world_state_view)
+                .is_err());
+            assert!(set_in_account("country", "jp")
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            assert!(set_in_account("email", "a value which is too long")
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            assert_eq!(
+                Some(&"alice@example.com".to_string()),
+                world_state_view
+                    .read_account(&alice_id)
+                    .expect("Failed to find account.")
+                    .metadata
+                    .get("email")
+            );
+            Instruction::from(isi::RemoveKeyValue::<Account, String>::new(
+                alice_id.clone(),
+                "email".to_string(),
+            ))
+            .execute(root_id.clone(), &mut world_state_view)
+            .expect("Failed to remove account metadata.");
+            assert!(
+                Instruction::from(isi::RemoveKeyValue::<Account, String>::new(
+                    alice_id.clone(),
+                    "email".to_string(),
+                ))
+                .execute(alice_id.clone(), &mut world_state_view)
+                .is_err()
+            );
+            let set_in_domain: Instruction = isi::SetKeyValue::<Domain, String, String>::new(
+                "global".to_string(),
+                "title".to_string(),
+                "Global".to_string(),
+            )
+            .into();
+            assert!(set_in_domain
+                .clone()
+                .execute(alice_id.clone(), &mut world_state_view)
+                .is_err());
+            set_in_domain
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to set domain metadata.");
+            assert_eq!(
+                Some(&"Global".to_string()),
+                world_state_view
+                    .read_domain("global")
+                    .expect("Failed to find domain.")
+                    .metadata
+                    .get("title")
+            );
+            let xor_definition_id = AssetDefinitionId::new("xor", "global");
+            let set_in_asset_definition: Instruction =
+                isi::SetKeyValue::<AssetDefinition, String, String>::new(
+                    xor_definition_id.clone(),
+                    "ticker".to_string(),
+                    "XOR".to_string(),
+                )
+                .into();
+            assert!(set_in_asset_definition
+                .clone()
+                .execute(bob_id, &mut world_state_view)
+                .is_err());
+            set_in_asset_definition
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to set asset definition metadata.");
+            Instruction::from(isi::RemoveKeyValue::<AssetDefinition, String>::new(
+                xor_definition_id.clone(),
+                "ticker".to_string(),
+            ))
+            .execute(root_id, &mut world_state_view)
+            .expect("Failed to remove asset definition metadata.");
+            assert!(world_state_view
+                .read_asset_definition(&xor_definition_id)
+                .expect("Failed to find asset definition.")
+                .metadata
+                .is_empty());
+        }
+
+        #[test]
+        fn registration_should_check_supplied_metadata_and_assets() {
+            use crate::wsv::StoreLimits;
+
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view =
+                world_state_view_with_root(public_key).with_store_limits(StoreLimits {
+                    max_entries: 1,
+                    max_key_length: 8,
+                    max_value_length: 24,
+                });
+            let root_id = AccountId::new("root", "global");
+            let register_account = |account: Account| -> Instruction {
+                isi::Register::<Domain, Account>::new(account, "global".to_string()).into()
+            };
+            let mut alice = Account::new("alice", "global", public_key);
+            alice
+                .metadata
+                .insert("email".to_string(), "alice@example.com".to_string());
+            alice
+                .metadata
+                .insert("phone".to_string(), "+000000000".to_string());
+            assert!(register_account(alice.clone())
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            alice.metadata.remove("phone");
+            let xor_id = AssetId {
+                definition_id: AssetDefinitionId::new("xor", "global"),
+                account_id: alice.id.clone(),
+            };
+            alice
+                .assets
+                .insert(xor_id.clone(), Asset::with_quantity(xor_id, 100));
+            assert!(register_account(alice.clone())
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            alice.assets.clear();
+            register_account(alice)
+                .execute(root_id.clone(), &mut world_state_view)
+                .expect("Failed to register account.");
+            let register_asset_definition = |asset_definition: AssetDefinition| -> Instruction {
+                isi::Register::<Domain, AssetDefinition>::new(
+                    asset_definition,
+                    "global".to_string(),
+                )
+                .into()
+            };
+            let mut eth_definition = AssetDefinition::new(AssetDefinitionId::new("eth", "global"));
+            eth_definition
+                .metadata
+                .insert("description".to_string(), "Ether".to_string());
+            assert!(register_asset_definition(eth_definition.clone())
+                .execute(root_id.clone(), &mut world_state_view)
+                .is_err());
+            eth_definition.metadata.clear();
+            eth_definition
+                .metadata
+                .insert("name".to_string(), "Ether".to_string());
+            register_asset_definition(eth_definition)
+                .execute(root_id, &mut world_state_view)
+                .expect("Failed to register asset definition.");
+        }
+    }
+}
+
+/// Query module provides `IrohaQuery` Domain related implementations.
+pub mod query {
+    use super::*;
+    use crate::{
+        permission::isi::PermissionInstruction,
+        query::{Cursor, IrohaQuery, ListOptions, QueryError, QueryItem, Value},
+    };
+    use iroha_derive::{log, IntoQuery, Io};
+
+    /// To get all domains registered in the peer, FindAllDomains query can be used.
+    /// Domains are ordered by name unless `options` specify another sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAllDomains {
+        options: ListOptions,
+    }
+
+    /// Result of the `FindAllDomains` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAllDomainsResult {
+        /// Registered domains selected by the query options.
+        pub domains: Vec<Domain>,
+        /// Cursor to the next page of domains if there are more of them.
+        pub next_cursor: Option<Cursor>,
+    }
+
+    impl FindAllDomains {
+        /// Build a `FindAllDomains` query in the form of a `QueryRequest`.
+        pub fn build_request(options: ListOptions) -> QueryRequest {
+            let query = FindAllDomains { options };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAllDomains {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let mut domains: Vec<Domain> = world_state_view
+                .read_peer()
+                .domains
+                .values()
+                .cloned()
+                .collect();
+            domains.sort_by(|left, right| left.name.cmp(&right.name));
+            let (domains, next_cursor) = self
+                .options
+                .apply(domains, world_state_view.query_limits())?;
+            Ok(QueryResult::FindAllDomains(FindAllDomainsResult {
+                domains,
+                next_cursor,
+            }))
+        }
+    }
+
+    impl QueryItem for Domain {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "name" => Some(Value::String(self.name.clone())),
+                "accounts" => Some(Value::Number(self.accounts.len() as u128)),
+                "asset_definitions" => Some(Value::Number(self.asset_definitions.len() as u128)),
+                _ => None,
+            }
+        }
+        fn metadata(&self, key: &str) -> Option<&str> {
+            self.metadata.get(key).map(String::as_str)
+        }
+    }
+
+    /// To get a domain by its name, FindDomainByName query can be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindDomainByName {
+        name: Name,
+    }
+
+    /// Result of the `FindDomainByName` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindDomainByNameResult {
+        /// Domain with the requested name.
+        pub domain: Domain,
+    }
+
+    impl FindDomainByName {
+        /// Build a `FindDomainByName` query in the form of a `QueryRequest`.
+        pub fn build_request(name: Name) -> QueryRequest {
+            let query = FindDomainByName { name };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindDomainByName {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let domain = world_state_view
+                .read_domain(&self.name)
+                .ok_or_else(|| {
+                    QueryError::NotFound(format!("No domain with name: {} found.", &self.name))
+                })?
+                .clone();
+            Ok(QueryResult::FindDomainByName(FindDomainByNameResult {
+                domain,
+            }))
+        }
+
+        fn read_permission(
+            &self,
+            authority: <Account as Identifiable>::Id,
+        ) -> PermissionInstruction {
+            PermissionInstruction::CanReadDomain(authority, self.name.clone())
+        }
+    }
 }
@@ -3,6 +3,10 @@
 use crate::prelude::*;
 use iroha_derive::Io;
 use parity_scale_codec::{Decode, Encode};
+use std::fmt::{self, Display, Formatter};
+
+/// Maximum length of the names of domains, accounts and asset definitions.
+pub const MAX_NAME_LENGTH: usize = 64;
 
 pub mod prelude {
     //! Re-exports important traits and types. Meant to be glob imported when using `Iroha`.
@@ -46,13 +50,15 @@ impl Instruction {
             Instruction::Asset(origin) => Ok(origin.execute(authority, world_state_view)?),
             Instruction::Account(origin) => Ok(origin.execute(authority, world_state_view)?),
             Instruction::Permission(origin) => Ok(origin.execute(world_state_view)?),
-            Instruction::Compose(left, right) => {
+            Instruction::Compose(left, right) => world_state_view.atomically(|world_state_view| {
                 left.execute(authority.clone(), world_state_view)?;
                 right.execute(authority, world_state_view)?;
                 Ok(())
-            }
+            }),
             Instruction::If(condition, then, otherwise) => {
-                match condition.execute(authority.clone(), world_state_view) {
+                match world_state_view.atomically(|world_state_view| {
+                    condition.execute(authority.clone(), world_state_view)
+                }) {
                     Ok(_) => then.execute(authority, world_state_view),
                     Err(_) => {
                         if let Some(otherwise) = otherwise {
@@ -69,6 +75,94 @@ impl Instruction {
             }
         }
     }
+
+    /// Calculates the depth of the instructions tree - plain instructions have depth `1`, each
+    /// level of `Compose`, `If` or listener nesting adds one more.
+    pub fn depth(&self) -> u64 {
+        match self {
+            Instruction::Peer(crate::peer::isi::PeerInstruction::AddListener(listener, _)) => {
+                1 + listener.depth()
+            }
+            Instruction::Compose(left, right) => 1 + left.depth().max(right.depth()),
+            Instruction::If(condition, then, otherwise) => {
+                1 + condition
+                    .depth()
+                    .max(then.depth())
+                    .max(otherwise.as_ref().map_or(0, |otherwise| otherwise.depth()))
+            }
+            _ => 1,
+        }
+    }
+
+    /// Collects identifications of all assets which are touched by the instruction, including
+    /// the ones touched by nested instructions.
+    pub fn asset_ids(&self) -> Vec<<Asset as Identifiable>::Id> {
+        use crate::{account::isi::AccountInstruction, asset::isi::AssetInstruction};
+
+        match self {
+            Instruction::Asset(AssetInstruction::MintAsset(_, asset_id))
+            | Instruction::Asset(AssetInstruction::MintBigAsset(_, asset_id))
+            | Instruction::Asset(AssetInstruction::BurnAsset(_, asset_id))
+            | Instruction::Asset(AssetInstruction::BurnBigAsset(_, asset_id))
+            | Instruction::Asset(AssetInstruction::MintFixedAsset(_, asset_id))
+            | Instruction::Asset(AssetInstruction::BurnFixedAsset(_, asset_id))
+            | Instruction::Asset(AssetInstruction::SetKeyValue(asset_id, _, _))
+            | Instruction::Asset(AssetInstruction::RemoveKeyValue(asset_id, _)) => {
+                vec![asset_id.clone()]
+            }
+            Instruction::Asset(AssetInstruction::TransferAsset(source_id, _, destination_id))
+            | Instruction::Asset(AssetInstruction::TransferBigAsset(
+                source_id,
+                _,
+                destination_id,
+            ))
+            | Instruction::Asset(AssetInstruction::TransferFixedAsset(
+                source_id,
+                _,
+                destination_id,
+            )) => vec![source_id.clone(), destination_id.clone()],
+            Instruction::Account(AccountInstruction::TransferAsset(
+                source_id,
+                destination_id,
+                asset,
+            )) => vec![
+                AssetId {
+                    definition_id: asset.id.definition_id.clone(),
+                    account_id: source_id.clone(),
+                },
+                AssetId {
+                    definition_id: asset.id.definition_id.clone(),
+                    account_id: destination_id.clone(),
+                },
+            ],
+            Instruction::Account(AccountInstruction::SwapAssets(left, right)) => vec![
+                left.id.clone(),
+                AssetId {
+                    definition_id: left.id.definition_id.clone(),
+                    account_id: right.id.account_id.clone(),
+                },
+                right.id.clone(),
+                AssetId {
+                    definition_id: right.id.definition_id.clone(),
+                    account_id: left.id.account_id.clone(),
+                },
+            ],
+            Instruction::Compose(left, right) => {
+                let mut asset_ids = left.asset_ids();
+                asset_ids.extend(right.asset_ids());
+                asset_ids
+            }
+            Instruction::If(condition, then, otherwise) => {
+                let mut asset_ids = condition.asset_ids();
+                asset_ids.extend(then.asset_ids());
+                if let Some(otherwise) = otherwise {
+                    asset_ids.extend(otherwise.asset_ids());
+                }
+                asset_ids
+            }
+            _ => Vec::new(),
+        }
+    }
 }
 
 /// Generic instruction for an addition of an object to the identifiable destination.
@@ -119,6 +213,58 @@ where
     }
 }
 
+/// Generic instruction for an unregistration of an object from the identifiable destination.
+pub struct Unregister<D, O>
+where
+    D: Identifiable,
+    O: Identifiable,
+{
+    /// Identification of the object which should be unregistered.
+    pub object_id: O::Id,
+    /// Destination object `Id`.
+    pub destination_id: D::Id,
+}
+
+impl<D, O> Unregister<D, O>
+where
+    D: Identifiable,
+    O: Identifiable,
+{
+    /// Default `Unregister` constructor.
+    pub fn new(object_id: O::Id, destination_id: D::Id) -> Self {
+        Unregister {
+            object_id,
+            destination_id,
+        }
+    }
+}
+
+/// Generic instruction for a removal of an object from the identifiable destination.
+pub struct Remove<D, O>
+where
+    D: Identifiable,
+    O: Identifiable,
+{
+    /// Identification of the object which should be removed.
+    pub object_id: O::Id,
+    /// Destination object `Id`.
+    pub destination_id: D::Id,
+}
+
+impl<D, O> Remove<D, O>
+where
+    D: Identifiable,
+    O: Identifiable,
+{
+    /// Default `Remove` constructor.
+    pub fn new(object_id: O::Id, destination_id: D::Id) -> Self {
+        Remove {
+            object_id,
+            destination_id,
+        }
+    }
+}
+
 /// Generic instruction for a mint of an object to the identifiable destination.
 pub struct Mint<D, O>
 where
@@ -143,6 +289,30 @@ where
     }
 }
 
+/// Generic instruction for a burn of an object from the identifiable destination.
+pub struct Burn<D, O>
+where
+    D: Identifiable,
+{
+    /// Object which should be burned.
+    pub object: O,
+    /// Destination object `Id`.
+    pub destination_id: D::Id,
+}
+
+impl<D, O> Burn<D, O>
+where
+    D: Identifiable,
+{
+    /// Default `Burn` constructor.
+    pub fn new(object: O, destination_id: D::Id) -> Self {
+        Burn {
+            object,
+            destination_id,
+        }
+    }
+}
+
 /// Generic instruction for a transfer of an object from the identifiable source to the identifiable destination.
 pub struct Transfer<Src: Identifiable, Obj, Dst: Identifiable> {
     /// Source object `Id`.
@@ -163,3 +333,278 @@ impl<Src: Identifiable, Obj, Dst: Identifiable> Transfer<Src, Obj, Dst> {
         }
     }
 }
+
+/// Generic instruction for setting a value by the key in the key-value store of the identifiable
+/// object.
+pub struct SetKeyValue<O, K, V>
+where
+    O: Identifiable,
+{
+    /// Identification of the object which store should be changed.
+    pub object_id: O::Id,
+    /// Key of the value.
+    pub key: K,
+    /// Value which should be set.
+    pub value: V,
+}
+
+impl<O, K, V> SetKeyValue<O, K, V>
+where
+    O: Identifiable,
+{
+    /// Default `SetKeyValue` constructor.
+    pub fn new(object_id: O::Id, key: K, value: V) -> Self {
+        SetKeyValue {
+            object_id,
+            key,
+            value,
+        }
+    }
+}
+
+/// Generic instruction for removing a value by the key from the key-value store of the
+/// identifiable object.
+pub struct RemoveKeyValue<O, K>
+where
+    O: Identifiable,
+{
+    /// Identification of the object which store should be changed.
+    pub object_id: O::Id,
+    /// Key of the value which should be removed.
+    pub key: K,
+}
+
+impl<O, K> RemoveKeyValue<O, K>
+where
+    O: Identifiable,
+{
+    /// Default `RemoveKeyValue` constructor.
+    pub fn new(object_id: O::Id, key: K) -> Self {
+        RemoveKeyValue { object_id, key }
+    }
+}
+
+/// Reason of a failure of registration instructions like `Register` and `Add`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistrationError {
+    /// Entity with the same identification is already registered.
+    AlreadyExists(String),
+    /// Destination of the registration is not found.
+    NotFound(String),
+    /// Identification of the registered entity is malformed.
+    InvalidId(String),
+    /// Registration is rejected for another reason, like missing permissions or invalid
+    /// metadata.
+    Rejected(String),
+}
+
+impl Display for RegistrationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistrationError::AlreadyExists(entity) => write!(f, "{} already exists.", entity),
+            RegistrationError::NotFound(entity) => write!(f, "{} not found.", entity),
+            RegistrationError::InvalidId(reason) => {
+                write!(f, "Invalid identification: {}.", reason)
+            }
+            RegistrationError::Rejected(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl From<RegistrationError> for String {
+    fn from(error: RegistrationError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<String> for RegistrationError {
+    fn from(reason: String) -> Self {
+        RegistrationError::Rejected(reason)
+    }
+}
+
+/// Checks that the `name` of a domain, an account or an asset definition is not empty, is not
+/// longer than `MAX_NAME_LENGTH` characters and consists of ASCII alphanumeric characters, `_`,
+/// `-` and `.` only, so it does not break `name@domain` and `name#domain` identifications and
+/// can not be mistaken for a name with lookalike characters.
+pub fn validate_name(name: &str) -> Result<(), RegistrationError> {
+    if name.is_empty() {
+        Err(RegistrationError::InvalidId("name is empty".to_string()))
+    } else if name.chars().count() > MAX_NAME_LENGTH {
+        Err(RegistrationError::InvalidId(format!(
+            "name {} is longer than {} characters",
+            name, MAX_NAME_LENGTH
+        )))
+    } else if let Some(character) = name
+        .chars()
+        .find(|character| !character.is_ascii_alphanumeric() && !"_-.".contains(*character))
+    {
+        Err(RegistrationError::InvalidId(format!(
+            "name {} contains forbidden character {:?}",
+            name, character
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// World state and instructions shared by the tests of the instructions of different entities.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use crate::{isi::Mint, peer::PeerId, permission, prelude::*};
+    use std::collections::{BTreeMap, HashMap};
+
+    /// World state with the `global` domain holding the `root` account with a permission to do
+    /// anything, the `xor` asset definition of quantities and the `btc` one of big quantities.
+    pub(crate) fn world_state_view_with_root(public_key: PublicKey) -> WorldStateView {
+        WorldStateView::new(peer_with_root(public_key))
+    }
+
+    /// Peer of the `world_state_view_with_root`, for the world states which are constructed
+    /// differently.
+    pub(crate) fn peer_with_root(public_key: PublicKey) -> Peer {
+        let domain_name = "global".to_string();
+        let mut asset_definitions = BTreeMap::new();
+        let permission_asset_definition_id = permission::permission_asset_definition_id();
+        asset_definitions.insert(
+            permission_asset_definition_id.clone(),
+            AssetDefinition::new(permission_asset_definition_id.clone()),
+        );
+        let xor_definition_id = AssetDefinitionId::new("xor", &domain_name);
+        asset_definitions.insert(
+            xor_definition_id.clone(),
+            AssetDefinition::new(xor_definition_id),
+        );
+        let btc_definition_id = AssetDefinitionId::new("btc", &domain_name);
+        asset_definitions.insert(
+            btc_definition_id.clone(),
+            AssetDefinition::with_value_type(
+                btc_definition_id,
+                AssetValueType::BigQuantity,
+                Mintable::Infinitely,
+            ),
+        );
+        let account_id = AccountId::new("root", &domain_name);
+        let asset_id = AssetId {
+            definition_id: permission_asset_definition_id,
+            account_id: account_id.clone(),
+        };
+        let asset =
+            Asset::with_permission(asset_id.clone(), ("anything".to_string(), "".to_string()));
+        let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
+        account.assets.insert(asset_id, asset);
+        let mut accounts = BTreeMap::new();
+        accounts.insert(account_id, account);
+        let domain = Domain {
+            name: domain_name.clone(),
+            accounts,
+            asset_definitions,
+            metadata: BTreeMap::new(),
+        };
+        let mut domains = HashMap::new();
+        domains.insert(domain_name, domain);
+        Peer::with_domains(
+            PeerId {
+                address: "127.0.0.1:8080".to_string(),
+                public_key,
+            },
+            &Vec::new(),
+            domains,
+        )
+    }
+
+    /// Registers an account with the given `name` and signatory in the `global` domain.
+    pub(crate) fn add_account(
+        world_state_view: &mut WorldStateView,
+        name: &str,
+        public_key: PublicKey,
+    ) -> AccountId {
+        let account = Account::new(name, "global", public_key);
+        let account_id = account.id.clone();
+        world_state_view
+            .domain("global")
+            .expect("Failed to find domain.")
+            .accounts
+            .insert(account_id.clone(), account);
+        account_id
+    }
+
+    /// `world_state_view_with_root` with the `alice` and `bob` accounts in the `global` domain,
+    /// each of them signed by the given key and holding 10 `xor`.
+    pub(crate) fn world_state_view_with_holders(public_key: PublicKey) -> WorldStateView {
+        let mut world_state_view = world_state_view_with_root(public_key);
+        for name in &["alice", "bob"] {
+            let account_id = add_account(&mut world_state_view, name, public_key);
+            world_state_view
+                .add_asset(Asset::with_quantity(
+                    AssetId {
+                        definition_id: AssetDefinitionId::new("xor", "global"),
+                        account_id,
+                    },
+                    10,
+                ))
+                .expect("Failed to add asset.");
+        }
+        world_state_view
+    }
+
+    /// Gives the account the permission to perform the `action` on the `object`, replacing the
+    /// permissions it held before.
+    pub(crate) fn grant_permission(
+        world_state_view: &mut WorldStateView,
+        account_id: &AccountId,
+        action: &str,
+        object: &str,
+    ) {
+        world_state_view
+            .add_asset(Asset::with_permission(
+                AssetId {
+                    definition_id: permission::permission_asset_definition_id(),
+                    account_id: account_id.clone(),
+                },
+                (action.to_string(), object.to_string()),
+            ))
+            .expect("Failed to grant permission.");
+    }
+
+    /// Stores the block in the committed blocks the way `Kura` does and puts it into the world
+    /// state.
+    pub(crate) async fn commit_block(world_state_view: &mut WorldStateView, block: CommittedBlock) {
+        world_state_view.committed_blocks().push(block.clone());
+        world_state_view.put(&block).await;
+    }
+
+    /// Instruction minting `quantity` of `xor` to the `root` account.
+    pub(crate) fn mint_xor(quantity: u32) -> Instruction {
+        Mint::<Asset, u32>::new(
+            quantity,
+            AssetId {
+                definition_id: AssetDefinitionId::new("xor", "global"),
+                account_id: AccountId::new("root", "global"),
+            },
+        )
+        .into()
+    }
+
+    /// Instruction minting an asset which definition is not registered to the `root` account.
+    pub(crate) fn mint_unknown_asset() -> Instruction {
+        Mint::<Asset, u32>::new(
+            10,
+            AssetId {
+                definition_id: AssetDefinitionId::new("unknown", "global"),
+                account_id: AccountId::new("root", "global"),
+            },
+        )
+        .into()
+    }
+
+    /// Quantity of `xor` held by the `root` account.
+    pub(crate) fn xor_quantity(world_state_view: &mut WorldStateView) -> Option<u32> {
+        world_state_view
+            .asset(&AssetId {
+                definition_id: AssetDefinitionId::new("xor", "global"),
+                account_id: AccountId::new("root", "global"),
+            })
+            .map(|asset| asset.quantity)
+    }
+}
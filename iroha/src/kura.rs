@@ -11,14 +11,16 @@ use std::{
     convert::TryFrom,
     fs,
     path::{Path, PathBuf},
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 /// High level data storage representation.
 /// Provides all necessary methods to read and write data, hides implementation details.
 #[derive(Debug)]
 pub struct Kura {
+    #[allow(dead_code)]
     mode: Mode,
-    blocks: Vec<ValidBlock>,
+    blocks: CommittedBlocks,
     block_store: BlockStore,
     block_sender: CommittedBlockSender,
     merkle_tree: MerkleTree,
@@ -33,32 +35,37 @@ impl Kura {
             block_store: BlockStore::new(block_store_path),
             block_sender,
             merkle_tree: MerkleTree::new(),
-            blocks: Vec::new(),
+            blocks: CommittedBlocks::default(),
         }
     }
 
+    /// Committed blocks cached by `Kura`, shared with the `WorldStateView`.
+    pub fn committed_blocks(&self) -> CommittedBlocks {
+        self.blocks.clone()
+    }
+
     /// After constructing `Kura` it should be initialized to be ready to work with it.
     pub async fn init(&mut self) -> Result<(), String> {
         let blocks = self.block_store.read_all().await;
         let blocks_refs = blocks.iter().collect::<Vec<&ValidBlock>>();
         self.merkle_tree.build(&blocks_refs);
-        self.blocks = blocks;
+        *self.blocks.write() = blocks.into_iter().map(ValidBlock::commit).collect();
         Ok(())
     }
 
     /// Methods consumes new validated block and atomically stores and caches it.
     #[log]
     pub async fn store(&mut self, mut block: ValidBlock) -> Result<Hash, String> {
-        if !self.blocks.is_empty() {
-            let last_block_index = self.blocks.len() - 1;
-            block.header.height = last_block_index as u64 + 1;
-            block.header.previous_block_hash = self.blocks.as_mut_slice()[last_block_index].hash();
+        if let Some(last_block) = self.blocks.read().last() {
+            block.header.height = last_block.header.height + 1;
+            block.header.previous_block_hash = last_block.hash();
         }
         let block_store_result = self.block_store.write(&block).await;
         match block_store_result {
             Ok(hash) => {
-                self.block_sender.send(block.clone().commit()).await;
-                self.blocks.push(block);
+                let block = block.commit();
+                self.blocks.write().push(block.clone());
+                self.block_sender.send(block).await;
                 Ok(hash)
             }
             Err(error) => {
@@ -71,6 +78,57 @@ impl Kura {
     }
 }
 
+/// Committed blocks cached by `Kura` in memory, ordered by height. Clones share the same blocks,
+/// so the `WorldStateView` serves block and transaction lookups from them instead of keeping a
+/// copy of its own.
+#[derive(Clone, Debug, Default)]
+pub struct CommittedBlocks {
+    blocks: Arc<RwLock<Vec<CommittedBlock>>>,
+}
+
+impl CommittedBlocks {
+    /// Appends the block with the next height.
+    pub fn push(&self, block: CommittedBlock) {
+        self.write().push(block);
+    }
+
+    /// Get a copy of the block with the given `height`.
+    pub fn get(&self, height: u64) -> Option<CommittedBlock> {
+        self.read().get(height as usize).cloned()
+    }
+
+    /// Number of the cached blocks.
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Returns `true` if no blocks are cached yet.
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Executes `f` on the first `number` blocks, or on all of them if there are less blocks.
+    pub fn with_first<R, F>(&self, number: usize, f: F) -> R
+    where
+        F: FnOnce(&[CommittedBlock]) -> R,
+    {
+        let blocks = self.read();
+        f(&blocks[..number.min(blocks.len())])
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Vec<CommittedBlock>> {
+        self.blocks
+            .read()
+            .expect("Failed to read committed blocks.")
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Vec<CommittedBlock>> {
+        self.blocks
+            .write()
+            .expect("Failed to write committed blocks.")
+    }
+}
+
 /// Kura work mode.
 #[derive(Debug)]
 pub enum Mode {
@@ -270,4 +328,49 @@ mod tests {
             .await
             .expect("Failed to store block into Kura.");
     }
+
+    #[async_std::test]
+    async fn stored_and_restored_blocks_should_be_shared_with_the_world_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let world_state_view = WorldStateView::new(Peer::new(
+            PeerId {
+                address: "127.0.0.1:8080".to_string(),
+                public_key: [0; 32],
+            },
+            &Vec::new(),
+        ));
+        let (tx, _rx) = sync::channel(100);
+        let mut kura = Kura::new(Mode::Strict, dir.path(), tx);
+        kura.init().await.expect("Failed to init Kura.");
+        let committed_blocks = kura.committed_blocks();
+        for _ in 0..2 {
+            let block = PendingBlock::new(Vec::new())
+                .chain_first()
+                .sign(&[0; 32], &[0; 64])
+                .expect("Failed to sign blocks.")
+                .validate(&world_state_view)
+                .expect("Failed to validate block.");
+            kura.store(block)
+                .await
+                .expect("Failed to store block into Kura.");
+        }
+        assert_eq!(committed_blocks.len(), 2);
+        let latest_block = committed_blocks.get(1).expect("Failed to get block.");
+        assert_eq!(
+            latest_block.header.previous_block_hash,
+            committed_blocks
+                .get(0)
+                .expect("Failed to get block.")
+                .hash()
+        );
+        let (tx, _rx) = sync::channel(100);
+        let mut kura = Kura::new(Mode::Strict, dir.path(), tx);
+        kura.init().await.expect("Failed to init Kura.");
+        let restored_blocks = kura.committed_blocks();
+        assert_eq!(restored_blocks.len(), 2);
+        assert_eq!(
+            restored_blocks.get(1).expect("Failed to get block.").hash(),
+            latest_block.hash()
+        );
+    }
 }
@@ -14,7 +14,7 @@ pub mod dex;
 pub mod domain;
 pub mod isi;
 mod kura;
-mod merkle;
+pub mod merkle;
 pub mod peer;
 mod permission;
 pub mod query;
@@ -38,7 +38,12 @@ use async_std::{
     sync::{self, Receiver, RwLock, Sender},
     task,
 };
-use std::{collections::HashMap, path::Path, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    sync::Arc,
+    time::Duration,
+};
 
 /// The interval at which sumeragi checks if there are tx in the `queue`.
 pub const TX_RETRIEVAL_INTERVAL: Duration = Duration::from_millis(100);
@@ -84,7 +89,7 @@ impl Iroha {
         let (kura_blocks_sender, kura_blocks_receiver) = sync::channel(100);
         let (message_sender, message_receiver) = sync::channel(100);
         let domain_name = "global".to_string();
-        let mut asset_definitions = HashMap::new();
+        let mut asset_definitions = BTreeMap::new();
         let asset_definition_id = permission::permission_asset_definition_id();
         asset_definitions.insert(
             asset_definition_id.clone(),
@@ -97,35 +102,48 @@ impl Iroha {
         };
         let asset =
             Asset::with_permission(asset_id.clone(), ("anything".to_string(), "".to_string()));
-        let mut account =
-            Account::new(&account_id.name, &account_id.domain_name, config.public_key);
+        let mut account = Account::new(
+            &account_id.name,
+            &account_id.domain_name,
+            config.root_public_key,
+        );
         account.assets.insert(asset_id, asset);
-        let mut accounts = HashMap::new();
+        let mut accounts = BTreeMap::new();
         accounts.insert(account_id, account);
         let domain = Domain {
             name: domain_name.clone(),
             accounts,
             asset_definitions,
+            metadata: BTreeMap::new(),
         };
         let mut domains = HashMap::new();
         domains.insert(domain_name, domain);
-        let world_state_view = Arc::new(RwLock::new(WorldStateView::new(Peer::with_domains(
-            config.peer_id.clone(),
-            &config.trusted_peers,
-            domains,
-        ))));
+        let (_public_key, private_key) = config
+            .key_pair()
+            .expect("Failed to get the key pair of the peer.");
+        let kura = Kura::new(
+            config.mode,
+            Path::new(&config.kura_block_store_path),
+            wsv_blocks_sender,
+        );
+        let world_state_view = Arc::new(RwLock::new(
+            WorldStateView::with_snapshot_retention(
+                Peer::with_domains(config.peer_id.clone(), &config.trusted_peers, domains),
+                config.snapshot_retention,
+            )
+            .with_committed_blocks(kura.committed_blocks())
+            .with_store_limits(config.store_limits)
+            .with_query_limits(config.query_limits),
+        ));
         let torii = Torii::new(
             &config.peer_id.address.clone(),
             Arc::clone(&world_state_view),
             transactions_sender.clone(),
             message_sender,
+            config.transaction_limits,
+            config.allow_unsigned_queries,
         );
-        let (_public_key, private_key) = config.key_pair();
-        let kura = Arc::new(RwLock::new(Kura::new(
-            config.mode,
-            Path::new(&config.kura_block_store_path),
-            wsv_blocks_sender,
-        )));
+        let kura = Arc::new(RwLock::new(kura));
         let sumeragi = Arc::new(RwLock::new(
             Sumeragi::new(
                 private_key,
@@ -160,6 +178,17 @@ impl Iroha {
     pub async fn start(&self) -> Result<(), String> {
         let kura = Arc::clone(&self.kura);
         kura.write().await.init().await?;
+        {
+            // Blocks restored by Kura are applied to the world state, so that its indexes of
+            // transactions match the heights of the blocks in the shared committed blocks.
+            let mut world_state_view = self.world_state_view.write().await;
+            let committed_blocks = world_state_view.committed_blocks().clone();
+            for height in 0..committed_blocks.len() as u64 {
+                if let Some(block) = committed_blocks.get(height) {
+                    world_state_view.put(&block).await;
+                }
+            }
+        }
         let torii = Arc::clone(&self.torii);
         let torii_handle = task::spawn(async move {
             if let Err(e) = torii.write().await.start().await {
@@ -240,14 +269,17 @@ pub mod prelude {
     #[doc(inline)]
     pub use crate::{
         account::{Account, Id as AccountId},
-        asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
+        asset::{
+            Asset, AssetDefinition, AssetDefinitionId, AssetId, AssetValueType, Fixed, Mintable,
+        },
         block::{CommittedBlock, PendingBlock, ValidBlock},
         config::Configuration,
         crypto::{Hash, PrivateKey, PublicKey, Signature},
         domain::Domain,
         isi::Instruction,
+        kura::CommittedBlocks,
         peer::Peer,
-        query::{Query, QueryRequest, QueryResult},
+        query::{ListOptions, Query, QueryRequest, QueryResult},
         tx::{AcceptedTransaction, RequestedTransaction, SignedTransaction, ValidTransaction},
         wsv::WorldStateView,
         CommittedBlockReceiver, CommittedBlockSender, Identifiable, Iroha, TransactionReceiver,
@@ -1,4 +1,14 @@
-use crate::prelude::*;
+//! This module contains the binary Merkle tree used to commit to blocks and world state entries
+//! and proofs of inclusion into it.
+
+use crate::{crypto, prelude::*};
+use parity_scale_codec::{Decode, Encode};
+
+/// Prefix of the hashed data of a leaf, so that a leaf hash differs from any subtree hash.
+const LEAF_HASH_PREFIX: u8 = 0;
+/// Prefix of the hashed children hashes of a subtree, so that a subtree hash differs from any leaf
+/// hash.
+const NODE_HASH_PREFIX: u8 = 1;
 
 /// [Merkle Tree](https://en.wikipedia.org/wiki/Merkle_tree) used to validate and prove data at
 /// each block height.
@@ -9,6 +19,7 @@ pub struct MerkleTree {
 }
 
 impl MerkleTree {
+    /// Constructor of an empty `MerkleTree`.
     pub fn new() -> Self {
         MerkleTree {
             root_node: Node::Empty,
@@ -18,24 +29,70 @@ impl MerkleTree {
     /// Builds a Merkle Tree from sorted array of `ValidBlocks`.
     //TODO: should we check or sort blocks here?
     pub fn build(&mut self, blocks: &[&ValidBlock]) {
-        //hm, can we write map(ValidBlock::hash) in Rust?
-        let mut nodes: std::collections::VecDeque<Node> = blocks
-            .iter()
-            .map(|block| Node::Leaf { hash: block.hash() })
-            .collect();
-        if nodes.len() % 2 != 0 {
-            nodes.push_back(Node::Empty);
+        *self = MerkleTree::from_hashes(blocks.iter().map(|block| block.hash()).collect());
+    }
+
+    /// Builds a Merkle Tree with leaves of the given hashes in the same order.
+    /// Levels with an odd number of nodes are padded with an `Empty` node.
+    ///
+    /// Leaves and subtrees are hashed with different prefixes, so a proof of a subtree can not be
+    /// presented as a proof of a leaf.
+    pub fn from_hashes(hashes: Vec<Hash>) -> Self {
+        let mut nodes: Vec<Node> = hashes.into_iter().map(|hash| Node::Leaf { hash }).collect();
+        if nodes.len() == 1 {
+            nodes.push(Node::Empty);
         }
         while nodes.len() > 1 {
-            if let Some(node1) = nodes.pop_front() {
-                let pop_front = nodes.pop_front();
-                nodes.push_back(match pop_front {
-                    Some(node2) => Node::from_nodes(node1, node2),
-                    None => Node::from_node(node1),
-                });
+            let mut level = nodes.into_iter();
+            let mut parents = Vec::new();
+            while let Some(left) = level.next() {
+                parents.push(Node::from_nodes(left, level.next().unwrap_or(Node::Empty)));
+            }
+            nodes = parents;
+        }
+        MerkleTree {
+            root_node: nodes.pop().unwrap_or(Node::Empty),
+        }
+    }
+
+    /// Hash of the root node. Is an array of zeros for an empty tree.
+    pub fn root_hash(&self) -> Hash {
+        self.root_node.hash()
+    }
+
+    /// Builds a proof of inclusion of the leaf with the given `index`.
+    /// Returns `None` if there is no such leaf.
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        let mut depth = 0;
+        let mut node = &self.root_node;
+        while let Node::Subtree { left, .. } = node {
+            depth += 1;
+            node = left;
+        }
+        if depth < std::mem::size_of::<usize>() * 8 && index >> depth != 0 {
+            return None;
+        }
+        let mut node = &self.root_node;
+        let mut path = Vec::new();
+        for level in (0..depth).rev() {
+            if let Node::Subtree { left, right, .. } = node {
+                if (index >> level) & 1 == 0 {
+                    path.push(ProofStep::Right(right.hash()));
+                    node = left;
+                } else {
+                    path.push(ProofStep::Left(left.hash()));
+                    node = right;
+                }
             }
         }
-        self.root_node = nodes.pop_front().unwrap_or(Node::Empty);
+        path.reverse();
+        match node {
+            Node::Leaf { hash } => Some(MerkleProof {
+                leaf_hash: *hash,
+                path,
+            }),
+            _ => None,
+        }
     }
 }
 
@@ -48,66 +105,94 @@ impl Default for MerkleTree {
 /// Binary Tree's node with possible variants: Subtree, Leaf (with data or links to data) and Empty.
 #[derive(Debug)]
 pub enum Node {
+    /// Node with two children.
     Subtree {
+        /// Left child.
         left: Box<Node>,
+        /// Right child.
         right: Box<Node>,
+        /// Hash of the children hashes.
         hash: Hash,
     },
+    /// Node with a hash of the data.
     Leaf {
+        /// Hash of the data. The node hash is calculated from it with the leaf prefix.
         hash: Hash,
     },
+    /// Node used for padding.
     Empty,
 }
 
-#[allow(clippy::wrong_self_convention)]
 impl Node {
     fn from_nodes(left: Self, right: Self) -> Self {
         Self::Subtree {
-            hash: Self::nodes_pair_hash(&left, &right),
+            hash: Self::pair_hash(&left.hash(), &right.hash()),
             left: Box::new(left),
             right: Box::new(right),
         }
     }
 
-    fn from_node(left: Self) -> Self {
-        Self::Subtree {
-            hash: left.hash(),
-            left: Box::new(left),
-            right: Box::new(Node::Empty),
-        }
-    }
-
     fn hash(&self) -> Hash {
         match &self {
             Self::Subtree { hash, .. } => *hash,
-            Self::Leaf { hash } => *hash,
+            Self::Leaf { hash } => Self::leaf_hash(hash),
             Self::Empty => [0; 32],
         }
     }
 
-    fn nodes_pair_hash(left: &Self, right: &Self) -> Hash {
-        use ursa::blake2::{
-            digest::{Input, VariableOutput},
-            VarBlake2b,
-        };
-        let left_hash = left.hash();
-        let right_hash = right.hash();
-        let sum: Vec<_> = left_hash
+    /// Hash of the prefixed data hash.
+    fn leaf_hash(hash: &Hash) -> Hash {
+        let mut bytes = vec![LEAF_HASH_PREFIX];
+        bytes.extend_from_slice(hash);
+        crypto::hash(bytes)
+    }
+
+    /// Hash of the prefixed concatenated hashes, so that a parent hash depends on the order of
+    /// children.
+    fn pair_hash(left: &Hash, right: &Hash) -> Hash {
+        let mut bytes = vec![NODE_HASH_PREFIX];
+        bytes.extend_from_slice(left);
+        bytes.extend_from_slice(right);
+        crypto::hash(bytes)
+    }
+}
+
+/// A sibling hash on the way from a leaf to the root of a `MerkleTree`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum ProofStep {
+    /// The sibling is the left child, the hashed node is the right one.
+    Left(Hash),
+    /// The sibling is the right child, the hashed node is the left one.
+    Right(Hash),
+}
+
+/// Proof of inclusion of a leaf into a `MerkleTree` with a known root hash.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct MerkleProof {
+    /// Hash of the data of the proven leaf.
+    pub leaf_hash: Hash,
+    /// Sibling hashes from the leaf up to the root.
+    pub path: Vec<ProofStep>,
+}
+
+impl MerkleProof {
+    /// Root hash of the tree which the proven leaf belongs to.
+    pub fn root_hash(&self) -> Hash {
+        self.path
             .iter()
-            .zip(right_hash.iter())
-            .map(|(left, right)| left.saturating_add(*right))
-            .take(32)
-            .collect();
-        let vector = VarBlake2b::new(32)
-            .expect("Failed to initialize VarBlake2b.")
-            .chain(sum)
-            .vec_result();
-        let mut hash = [0; 32];
-        hash.copy_from_slice(&vector);
-        hash
+            .fold(Node::leaf_hash(&self.leaf_hash), |hash, step| match step {
+                ProofStep::Left(sibling) => Node::pair_hash(sibling, &hash),
+                ProofStep::Right(sibling) => Node::pair_hash(&hash, sibling),
+            })
+    }
+
+    /// Checks that the proven leaf belongs to the tree with the given `root_hash`.
+    pub fn verify(&self, root_hash: &Hash) -> bool {
+        self.root_hash() == *root_hash
     }
 }
 
+/// Iterator over nodes of a `MerkleTree`.
 pub struct BreadthFirstIter<'a> {
     queue: Vec<&'a Node>,
 }
@@ -131,8 +216,8 @@ impl<'a> Iterator for BreadthFirstIter<'a> {
         match &self.queue.pop() {
             Some(node) => {
                 if let Node::Subtree { left, right, .. } = node {
-                    self.queue.push(&*left);
-                    self.queue.push(&*right);
+                    self.queue.push(left);
+                    self.queue.push(right);
                 }
                 Some(node)
             }
@@ -187,6 +272,38 @@ mod tests {
         assert_eq!(7, merkle_tree.into_iter().count());
     }
 
+    #[test]
+    fn proofs_should_be_verified_against_the_root_only() {
+        let hashes: Vec<Hash> = (0..5u8).map(|i| [i; 32]).collect();
+        let tree = MerkleTree::from_hashes(hashes.clone());
+        for (index, hash) in hashes.iter().enumerate() {
+            let proof = tree.proof(index).expect("Failed to build proof.");
+            assert_eq!(proof.leaf_hash, *hash);
+            assert!(proof.verify(&tree.root_hash()));
+        }
+        assert!(tree.proof(5).is_none());
+        assert!(tree.proof(100).is_none());
+        let mut forged = tree.proof(1).expect("Failed to build proof.");
+        forged.leaf_hash = [9; 32];
+        assert!(!forged.verify(&tree.root_hash()));
+        let reordered = MerkleTree::from_hashes(vec![[1; 32], [0; 32], [2; 32], [3; 32], [4; 32]]);
+        assert_ne!(tree.root_hash(), reordered.root_hash());
+    }
+
+    #[test]
+    fn subtree_should_not_be_proven_as_a_leaf() {
+        let tree = MerkleTree::from_hashes((0..4u8).map(|i| [i; 32]).collect());
+        let (left, right) = match &tree.root_node {
+            Node::Subtree { left, right, .. } => (left.hash(), right.hash()),
+            _ => panic!("Root should be a subtree."),
+        };
+        let forged = MerkleProof {
+            leaf_hash: left,
+            path: vec![ProofStep::Right(right)],
+        };
+        assert!(!forged.verify(&tree.root_hash()));
+    }
+
     #[test]
     fn three_blocks_should_built_seven_nodes() {
         let block = PendingBlock::new(Vec::new())
@@ -109,6 +109,14 @@ impl Peer {
         }
     }
 
+    /// Constructor of `Remove<Peer, Domain>` Iroha Special Instruction.
+    pub fn remove_domain(&self, object_id: <Domain as Identifiable>::Id) -> Remove<Peer, Domain> {
+        Remove {
+            object_id,
+            destination_id: self.id.clone(),
+        }
+    }
+
     /// Add new Listener to the World.
     pub fn add_listener(&mut self, listener: Instruction) {
         self.listeners.push(listener);
@@ -130,14 +138,19 @@ impl Identifiable for Peer {
 /// and the `From/Into` implementations to convert `PeerInstruction` variants into generic ISI.
 pub mod isi {
     use super::*;
-    use crate::permission::isi::PermissionInstruction;
-    use std::ops::{AddAssign, Sub};
+    use crate::{
+        domain::isi::detach_assets,
+        isi::{validate_name, RegistrationError},
+        permission::{isi::PermissionInstruction, permission_asset_definition_id},
+    };
 
     /// Enumeration of all legal Peer related Instructions.
     #[derive(Clone, Debug, Io, Encode, Decode)]
     pub enum PeerInstruction {
         /// Variant of the generic `Add` instruction for `Domain` --> `Peer`.
         AddDomain(String, PeerId),
+        /// Variant of the generic `Remove` instruction for `Domain` --> `Peer`.
+        RemoveDomain(String, PeerId),
         /// Variant of the generic `Add` instruction for `Instruction` --> `Peer`.
         AddListener(Box<Instruction>, PeerId),
         #[cfg(feature = "bridge")]
@@ -157,6 +170,11 @@ pub mod isi {
                 PeerInstruction::AddDomain(domain_name, peer_id) => {
                     Add::new(Domain::new(domain_name.to_string()), peer_id.clone())
                         .execute(authority, world_state_view)
+                        .map_err(String::from)
+                }
+                PeerInstruction::RemoveDomain(domain_name, peer_id) => {
+                    Remove::<Peer, Domain>::new(domain_name.clone(), peer_id.clone())
+                        .execute(authority, world_state_view)
                 }
                 PeerInstruction::AddListener(listener, peer_id) => {
                     Add::new(*listener.clone(), peer_id.clone())
@@ -170,20 +188,21 @@ pub mod isi {
         }
     }
 
-    impl AddAssign<Domain> for Peer {
-        fn add_assign(&mut self, domain: Domain) {
-            self.domains.insert(domain.name.clone(), domain);
-        }
-    }
-
     impl Add<Peer, Domain> {
         pub(crate) fn execute(
             self,
             authority: <Account as Identifiable>::Id,
             world_state_view: &mut WorldStateView,
-        ) -> Result<(), String> {
+        ) -> Result<(), RegistrationError> {
             PermissionInstruction::CanAddDomain(authority).execute(world_state_view)?;
-            *world_state_view.peer() += self.object;
+            validate_name(&self.object.name)?;
+            if world_state_view.read_domain(&self.object.name).is_some() {
+                return Err(RegistrationError::AlreadyExists(format!(
+                    "Domain {}",
+                    self.object.name
+                )));
+            }
+            world_state_view.add_domain(self.object);
             Ok(())
         }
     }
@@ -197,12 +216,42 @@ pub mod isi {
         }
     }
 
-    impl Sub<Domain> for Peer {
-        type Output = Self;
+    /// Removes the domain with all of its accounts and asset definitions from the peer.
+    /// Accounts of the domain should not hold non-empty assets or permissions and accounts of
+    /// other domains should not hold non-empty assets of the domain - balances should be burned or
+    /// transferred and store values removed first.
+    /// Transactions of the removed accounts and assets stay in the history and can still be queried.
+    impl Remove<Peer, Domain> {
+        pub(crate) fn execute(
+            self,
+            authority: <Account as Identifiable>::Id,
+            world_state_view: &mut WorldStateView,
+        ) -> Result<(), String> {
+            PermissionInstruction::CanRemoveDomain(authority, self.object_id.clone())
+                .execute(world_state_view)?;
+            if self.object_id == permission_asset_definition_id().domain_name {
+                return Err(
+                    "Domain of the permission asset definition can not be removed.".to_string(),
+                );
+            }
+            if world_state_view.read_domain(&self.object_id).is_none() {
+                return Err(format!("Failed to find domain {}.", self.object_id));
+            }
+            detach_assets(world_state_view, |asset| {
+                asset.id.account_id.domain_name == self.object_id
+                    || asset.id.definition_id.domain_name == self.object_id
+            })?;
+            world_state_view.remove_domain(&self.object_id);
+            Ok(())
+        }
+    }
 
-        fn sub(mut self, domain: Domain) -> Self {
-            self.domains.remove(&domain.name);
-            self
+    impl From<Remove<Peer, Domain>> for Instruction {
+        fn from(instruction: Remove<Peer, Domain>) -> Self {
+            Instruction::Peer(PeerInstruction::RemoveDomain(
+                instruction.object_id,
+                instruction.destination_id,
+            ))
         }
     }
 
@@ -218,3 +267,107 @@ pub mod isi {
         }
     }
 }
+
+/// Query module provides `IrohaQuery` Peer related implementations.
+pub mod query {
+    use super::*;
+    use crate::query::{Cursor, IrohaQuery, ListOptions, QueryError, QueryItem, Value};
+
+    /// To get identifications of all known peers including the current one,
+    /// FindAllPeers query can be used.
+    /// The current peer goes first and others are ordered by address unless `options` specify
+    /// another sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindAllPeers {
+        options: ListOptions,
+    }
+
+    /// Result of the `FindAllPeers` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindAllPeersResult {
+        /// Identifications of known peers selected by the query options.
+        pub peers: Vec<PeerId>,
+        /// Cursor to the next page of peers if there are more of them.
+        pub next_cursor: Option<Cursor>,
+    }
+
+    impl FindAllPeers {
+        /// Build a `FindAllPeers` query in the form of a `QueryRequest`.
+        pub fn build_request(options: ListOptions) -> QueryRequest {
+            let query = FindAllPeers { options };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindAllPeers {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let peer = world_state_view.read_peer();
+            let mut other_peers: Vec<&PeerId> = peer.peers.iter().collect();
+            other_peers.sort_by(|left, right| left.address.cmp(&right.address));
+            let peers: Vec<PeerId> = std::iter::once(&peer.id)
+                .chain(other_peers)
+                .cloned()
+                .collect();
+            let (peers, next_cursor) =
+                self.options.apply(peers, world_state_view.query_limits())?;
+            Ok(QueryResult::FindAllPeers(FindAllPeersResult {
+                peers,
+                next_cursor,
+            }))
+        }
+    }
+
+    /// To get all listeners registered in the peer, FindListeners query can be used.
+    /// Listeners are ordered by registration unless `options` specify another sorting.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct FindListeners {
+        options: ListOptions,
+    }
+
+    /// Result of the `FindListeners` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct FindListenersResult {
+        /// Registered listeners selected by the query options.
+        pub listeners: Vec<Instruction>,
+        /// Cursor to the next page of listeners if there are more of them.
+        pub next_cursor: Option<Cursor>,
+    }
+
+    impl FindListeners {
+        /// Build a `FindListeners` query in the form of a `QueryRequest`.
+        pub fn build_request(options: ListOptions) -> QueryRequest {
+            let query = FindListeners { options };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for FindListeners {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let (listeners, next_cursor) = self.options.apply(
+                world_state_view.read_peer().listeners.clone(),
+                world_state_view.query_limits(),
+            )?;
+            Ok(QueryResult::FindListeners(FindListenersResult {
+                listeners,
+                next_cursor,
+            }))
+        }
+    }
+
+    impl QueryItem for PeerId {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "address" => Some(Value::String(self.address.clone())),
+                _ => None,
+            }
+        }
+    }
+
+    impl QueryItem for Instruction {
+        fn field(&self, _name: &str) -> Option<Value> {
+            None
+        }
+    }
+}
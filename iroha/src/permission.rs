@@ -18,8 +18,13 @@ impl Permissions {
         Permissions::default()
     }
 
+    /// Returns `true` if no permission is granted.
+    pub fn is_empty(&self) -> bool {
+        self.origin.is_empty()
+    }
+
     fn check_anything(&self) -> Result<(), String> {
-        if self.origin.get("anything").is_some() {
+        if self.origin.contains_key("anything") {
             Ok(())
         } else {
             Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self))
@@ -27,7 +32,7 @@ impl Permissions {
     }
 
     fn check_add_domain(&self) -> Result<(), String> {
-        if self.check_anything().is_ok() || self.origin.get("add_domain").is_some() {
+        if self.check_anything().is_ok() || self.origin.contains_key("add_domain") {
             Ok(())
         } else {
             Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self))
@@ -35,7 +40,7 @@ impl Permissions {
     }
 
     fn check_add_listener(&self) -> Result<(), String> {
-        if self.check_anything().is_ok() || self.origin.get("add_listener").is_some() {
+        if self.check_anything().is_ok() || self.origin.contains_key("add_listener") {
             Ok(())
         } else {
             Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self))
@@ -123,6 +128,99 @@ impl Permissions {
             }
         }
     }
+
+    fn check_burn_asset(
+        &self,
+        asset_definition_id: &AssetDefinitionId,
+        domain: &Option<String>,
+    ) -> Result<(), String> {
+        if self.check_anything().is_ok() {
+            Ok(())
+        } else {
+            match self.origin.get("burn_asset") {
+                Some(object) => {
+                    if object
+                        == &(asset_definition_id.to_string()
+                            + domain.as_ref().unwrap_or(&"any".to_string()))
+                    {
+                        Ok(())
+                    } else {
+                        Err(format!("{}: {}", PERMISSION_OBJECT_NOT_SATISFIED, object))
+                    }
+                }
+                None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+            }
+        }
+    }
+
+    /// Checks the permission to perform the `action` on the entity identified by the `object`,
+    /// like setting values in the entity's key-value store or unregistering it.
+    fn check_action(&self, action: &str, object: &str) -> Result<(), String> {
+        if self.check_anything().is_ok() {
+            Ok(())
+        } else {
+            match self.origin.get(action) {
+                Some(permission_object) => {
+                    if permission_object == object {
+                        Ok(())
+                    } else {
+                        Err(format!(
+                            "{}: {}",
+                            PERMISSION_OBJECT_NOT_SATISFIED, permission_object
+                        ))
+                    }
+                }
+                None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+            }
+        }
+    }
+
+    fn check_read_all(&self) -> Result<(), String> {
+        if self.check_anything().is_ok()
+            || self
+                .origin
+                .get("read")
+                .is_some_and(|object| object == "all")
+        {
+            Ok(())
+        } else {
+            Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self))
+        }
+    }
+
+    fn check_read_domain(&self, domain_name: &str) -> Result<(), String> {
+        if self.check_read_all().is_ok() {
+            Ok(())
+        } else {
+            match self.origin.get("read") {
+                Some(object) => {
+                    if object == domain_name {
+                        Ok(())
+                    } else {
+                        Err(format!("{}: {}", PERMISSION_OBJECT_NOT_SATISFIED, object))
+                    }
+                }
+                None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+            }
+        }
+    }
+
+    fn check_read_account(&self, account_id: &AccountId) -> Result<(), String> {
+        if self.check_read_domain(&account_id.domain_name).is_ok() {
+            Ok(())
+        } else {
+            match self.origin.get("read") {
+                Some(object) => {
+                    if object == &format!("{}@{}", account_id.name, account_id.domain_name) {
+                        Ok(())
+                    } else {
+                        Err(format!("{}: {}", PERMISSION_OBJECT_NOT_SATISFIED, object))
+                    }
+                }
+                None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+            }
+        }
+    }
 }
 
 impl From<(String, String)> for Permissions {
@@ -139,6 +237,7 @@ pub mod isi {
     use parity_scale_codec::{Decode, Encode};
 
     /// Iroha special instructions related to `Permission`.
+    #[allow(clippy::enum_variant_names)]
     #[derive(Clone, Debug, Io, Encode, Decode)]
     pub enum PermissionInstruction {
         CanAnything(<Account as Identifiable>::Id),
@@ -162,6 +261,47 @@ pub mod isi {
             <AssetDefinition as Identifiable>::Id,
             Option<<Domain as Identifiable>::Id>,
         ),
+        CanBurnAsset(
+            <Account as Identifiable>::Id,
+            <AssetDefinition as Identifiable>::Id,
+            Option<<Domain as Identifiable>::Id>,
+        ),
+        /// Permission to set and remove values in the key-value store of the asset, granted by the
+        /// `set_key_value_in_asset` permission with the asset definition's identification as an
+        /// object. The account holding the asset needs the permission too.
+        CanSetKeyValueInAsset(<Account as Identifiable>::Id, <Asset as Identifiable>::Id),
+        /// Permission to set and remove metadata of the account, granted by the
+        /// `set_key_value_in_account` permission with the account's `name@domain` as an object.
+        /// The account itself needs the permission too, so it can not set its own trusted keys.
+        CanSetKeyValueInAccount(<Account as Identifiable>::Id, <Account as Identifiable>::Id),
+        /// Permission to set and remove metadata of the domain, granted by the
+        /// `set_key_value_in_domain` permission with the domain's name as an object.
+        CanSetKeyValueInDomain(<Account as Identifiable>::Id, <Domain as Identifiable>::Id),
+        /// Permission to set and remove metadata of the asset definition, granted by the
+        /// `set_key_value_in_asset_definition` permission with the asset definition's
+        /// identification as an object.
+        CanSetKeyValueInAssetDefinition(
+            <Account as Identifiable>::Id,
+            <AssetDefinition as Identifiable>::Id,
+        ),
+        /// Permission to unregister accounts of the domain, granted by the `unregister_account`
+        /// permission with the domain's name as an object.
+        CanUnregisterAccount(<Account as Identifiable>::Id, <Domain as Identifiable>::Id),
+        /// Permission to unregister asset definitions of the domain, granted by the
+        /// `unregister_asset_definition` permission with the domain's name as an object.
+        CanUnregisterAssetDefinition(<Account as Identifiable>::Id, <Domain as Identifiable>::Id),
+        /// Permission to remove the domain from the peer, granted by the `remove_domain`
+        /// permission with the domain's name as an object.
+        CanRemoveDomain(<Account as Identifiable>::Id, <Domain as Identifiable>::Id),
+        /// Permission to read the whole world state, granted by the `read` permission with the
+        /// `all` object.
+        CanReadAll(<Account as Identifiable>::Id),
+        /// Permission to read entities of the domain, granted by the `read` permission with the
+        /// domain's name as an object.
+        CanReadDomain(<Account as Identifiable>::Id, <Domain as Identifiable>::Id),
+        /// Permission to read the account and its assets, always granted for the account itself
+        /// and granted by the `read` permission with the account's `name@domain` as an object.
+        CanReadAccount(<Account as Identifiable>::Id, <Account as Identifiable>::Id),
     }
 
     impl PermissionInstruction {
@@ -170,7 +310,7 @@ pub mod isi {
         /// instructions execution.
         /// If permission check is satysfied - `Result::Ok(())` will be return.
         /// If permission check results in failure - `Result::Err(String)` will be return.
-        pub fn execute(&self, world_state_view: &mut WorldStateView) -> Result<(), String> {
+        pub fn execute(&self, world_state_view: &WorldStateView) -> Result<(), String> {
             match self {
                 PermissionInstruction::CanAnything(authority_account_id) => {
                     match world_state_view.read_asset(&AssetId {
@@ -244,6 +384,134 @@ pub mod isi {
                         .check_mint_asset(asset_definition_id, option_domain_id),
                     None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
                 },
+                PermissionInstruction::CanBurnAsset(
+                    authority_account_id,
+                    asset_definition_id,
+                    option_domain_id,
+                ) => match world_state_view.read_asset(&AssetId {
+                    definition_id: permission_asset_definition_id(),
+                    account_id: authority_account_id.clone(),
+                }) {
+                    Some(asset) => asset
+                        .permissions
+                        .check_burn_asset(asset_definition_id, option_domain_id),
+                    None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                },
+                PermissionInstruction::CanSetKeyValueInAsset(authority_account_id, asset_id) => {
+                    match world_state_view.read_asset(&AssetId {
+                        definition_id: permission_asset_definition_id(),
+                        account_id: authority_account_id.clone(),
+                    }) {
+                        Some(asset) => asset.permissions.check_action(
+                            "set_key_value_in_asset",
+                            &asset_id.definition_id.to_string(),
+                        ),
+                        None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                    }
+                }
+                PermissionInstruction::CanSetKeyValueInAccount(
+                    authority_account_id,
+                    account_id,
+                ) => {
+                    match world_state_view.read_asset(&AssetId {
+                        definition_id: permission_asset_definition_id(),
+                        account_id: authority_account_id.clone(),
+                    }) {
+                        Some(asset) => asset.permissions.check_action(
+                            "set_key_value_in_account",
+                            &format!("{}@{}", account_id.name, account_id.domain_name),
+                        ),
+                        None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                    }
+                }
+                PermissionInstruction::CanSetKeyValueInDomain(
+                    authority_account_id,
+                    domain_name,
+                ) => {
+                    match world_state_view.read_asset(&AssetId {
+                        definition_id: permission_asset_definition_id(),
+                        account_id: authority_account_id.clone(),
+                    }) {
+                        Some(asset) => asset
+                            .permissions
+                            .check_action("set_key_value_in_domain", domain_name),
+                        None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                    }
+                }
+                PermissionInstruction::CanSetKeyValueInAssetDefinition(
+                    authority_account_id,
+                    asset_definition_id,
+                ) => match world_state_view.read_asset(&AssetId {
+                    definition_id: permission_asset_definition_id(),
+                    account_id: authority_account_id.clone(),
+                }) {
+                    Some(asset) => asset.permissions.check_action(
+                        "set_key_value_in_asset_definition",
+                        &asset_definition_id.to_string(),
+                    ),
+                    None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                },
+                PermissionInstruction::CanUnregisterAccount(authority_account_id, domain_name) => {
+                    match world_state_view.read_asset(&AssetId {
+                        definition_id: permission_asset_definition_id(),
+                        account_id: authority_account_id.clone(),
+                    }) {
+                        Some(asset) => asset
+                            .permissions
+                            .check_action("unregister_account", domain_name),
+                        None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                    }
+                }
+                PermissionInstruction::CanUnregisterAssetDefinition(
+                    authority_account_id,
+                    domain_name,
+                ) => match world_state_view.read_asset(&AssetId {
+                    definition_id: permission_asset_definition_id(),
+                    account_id: authority_account_id.clone(),
+                }) {
+                    Some(asset) => asset
+                        .permissions
+                        .check_action("unregister_asset_definition", domain_name),
+                    None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                },
+                PermissionInstruction::CanRemoveDomain(authority_account_id, domain_name) => {
+                    match world_state_view.read_asset(&AssetId {
+                        definition_id: permission_asset_definition_id(),
+                        account_id: authority_account_id.clone(),
+                    }) {
+                        Some(asset) => asset.permissions.check_action("remove_domain", domain_name),
+                        None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                    }
+                }
+                PermissionInstruction::CanReadAll(authority_account_id) => match world_state_view
+                    .read_asset(&AssetId {
+                        definition_id: permission_asset_definition_id(),
+                        account_id: authority_account_id.clone(),
+                    }) {
+                    Some(asset) => asset.permissions.check_read_all(),
+                    None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                },
+                PermissionInstruction::CanReadDomain(authority_account_id, domain_name) => {
+                    match world_state_view.read_asset(&AssetId {
+                        definition_id: permission_asset_definition_id(),
+                        account_id: authority_account_id.clone(),
+                    }) {
+                        Some(asset) => asset.permissions.check_read_domain(domain_name),
+                        None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                    }
+                }
+                PermissionInstruction::CanReadAccount(authority_account_id, account_id) => {
+                    if authority_account_id == account_id {
+                        return Ok(());
+                    }
+                    match world_state_view.read_asset(&AssetId {
+                        definition_id: permission_asset_definition_id(),
+                        account_id: authority_account_id.clone(),
+                    }) {
+                        Some(asset) => asset.permissions.check_read_account(account_id),
+                        None => Err(format!("Error: {}, {:?}", PERMISSION_NOT_FOUND, self)),
+                    }
+                }
             }
         }
     }
@@ -252,14 +520,15 @@ pub mod isi {
     mod tests {
         use super::*;
         use crate::account::Id as AccountId;
+        use crate::isi::fixtures::*;
         use crate::peer::PeerId;
-        use std::collections::HashMap;
+        use std::collections::{BTreeMap, HashMap};
 
         #[test]
         fn test_can_anything_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -272,23 +541,20 @@ pub mod isi {
             };
             let asset =
                 Asset::with_permission(asset_id.clone(), ("anything".to_string(), "".to_string()));
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -298,7 +564,7 @@ pub mod isi {
             ));
             assert_eq!(
                 Ok(()),
-                PermissionInstruction::CanAnything(account_id).execute(&mut world_state_view)
+                PermissionInstruction::CanAnything(account_id).execute(&world_state_view)
             );
         }
 
@@ -306,29 +572,26 @@ pub mod isi {
         fn test_can_anything_without_permission_should_fail_with_permission_not_found() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
                 AssetDefinition::new(asset_definition_id.clone()),
             );
             let account_id = AccountId::new("NOT_ROOT", &domain_name);
-            let account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
-            let mut accounts = HashMap::new();
+            let account = Account::new(&account_id.name, &account_id.domain_name, public_key);
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -337,7 +600,7 @@ pub mod isi {
                 domains,
             ));
             assert!(PermissionInstruction::CanAnything(account_id)
-                .execute(&mut world_state_view)
+                .execute(&world_state_view)
                 .unwrap_err()
                 .contains(PERMISSION_NOT_FOUND));
         }
@@ -346,7 +609,7 @@ pub mod isi {
         fn test_can_anything_without_an_account_should_fail_with_permission_not_found() {
             assert!(
                 PermissionInstruction::CanAnything(AccountId::new("NOT_ROOT", "Company"))
-                    .execute(&mut WorldStateView::new(Peer::new(
+                    .execute(&WorldStateView::new(Peer::new(
                         PeerId {
                             address: "127.0.0.1:8080".to_string(),
                             public_key: [0; 32],
@@ -362,7 +625,7 @@ pub mod isi {
         fn test_can_add_domain_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -377,23 +640,20 @@ pub mod isi {
                 asset_id.clone(),
                 ("add_domain".to_string(), "".to_string()),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -403,7 +663,7 @@ pub mod isi {
             ));
             assert_eq!(
                 Ok(()),
-                PermissionInstruction::CanAddDomain(account_id).execute(&mut world_state_view)
+                PermissionInstruction::CanAddDomain(account_id).execute(&world_state_view)
             );
         }
 
@@ -411,29 +671,26 @@ pub mod isi {
         fn test_can_add_domain_without_permission_should_fail_with_permission_not_found() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
                 AssetDefinition::new(asset_definition_id.clone()),
             );
             let account_id = AccountId::new("NOT_ROOT", &domain_name);
-            let account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
-            let mut accounts = HashMap::new();
+            let account = Account::new(&account_id.name, &account_id.domain_name, public_key);
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -442,7 +699,7 @@ pub mod isi {
                 domains,
             ));
             assert!(PermissionInstruction::CanAddDomain(account_id)
-                .execute(&mut world_state_view)
+                .execute(&world_state_view)
                 .unwrap_err()
                 .contains(PERMISSION_NOT_FOUND));
         }
@@ -451,7 +708,7 @@ pub mod isi {
         fn test_can_add_domain_without_an_account_should_fail_with_permission_not_found() {
             assert!(
                 PermissionInstruction::CanAddDomain(AccountId::new("NOT_ROOT", "Company"))
-                    .execute(&mut WorldStateView::new(Peer::new(
+                    .execute(&WorldStateView::new(Peer::new(
                         PeerId {
                             address: "127.0.0.1:8080".to_string(),
                             public_key: [0; 32],
@@ -467,7 +724,7 @@ pub mod isi {
         fn test_can_add_listener_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -482,23 +739,20 @@ pub mod isi {
                 asset_id.clone(),
                 ("add_listener".to_string(), "".to_string()),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -508,7 +762,7 @@ pub mod isi {
             ));
             assert_eq!(
                 Ok(()),
-                PermissionInstruction::CanAddListener(account_id).execute(&mut world_state_view)
+                PermissionInstruction::CanAddListener(account_id).execute(&world_state_view)
             );
         }
 
@@ -516,29 +770,26 @@ pub mod isi {
         fn test_can_add_listener_without_permission_should_fail_with_permission_not_found() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
                 AssetDefinition::new(asset_definition_id.clone()),
             );
             let account_id = AccountId::new("NOT_ROOT", &domain_name);
-            let account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
-            let mut accounts = HashMap::new();
+            let account = Account::new(&account_id.name, &account_id.domain_name, public_key);
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -547,7 +798,7 @@ pub mod isi {
                 domains,
             ));
             assert!(PermissionInstruction::CanAddListener(account_id)
-                .execute(&mut world_state_view)
+                .execute(&world_state_view)
                 .unwrap_err()
                 .contains(PERMISSION_NOT_FOUND));
         }
@@ -556,7 +807,7 @@ pub mod isi {
         fn test_can_add_listener_without_an_account_should_fail_with_permission_not_found() {
             assert!(
                 PermissionInstruction::CanAddListener(AccountId::new("NOT_ROOT", "Company"))
-                    .execute(&mut WorldStateView::new(Peer::new(
+                    .execute(&WorldStateView::new(Peer::new(
                         PeerId {
                             address: "127.0.0.1:8080".to_string(),
                             public_key: [0; 32],
@@ -572,7 +823,7 @@ pub mod isi {
         fn test_can_register_account_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -587,23 +838,20 @@ pub mod isi {
                 asset_id.clone(),
                 ("register_account".to_string(), "any".to_string()),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -614,7 +862,7 @@ pub mod isi {
             assert_eq!(
                 Ok(()),
                 PermissionInstruction::CanRegisterAccount(account_id, None)
-                    .execute(&mut world_state_view)
+                    .execute(&world_state_view)
             );
         }
 
@@ -622,7 +870,7 @@ pub mod isi {
         fn test_can_register_account_in_domain_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -637,23 +885,20 @@ pub mod isi {
                 asset_id.clone(),
                 ("register_account".to_string(), domain_name.clone()),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -664,7 +909,7 @@ pub mod isi {
             assert_eq!(
                 Ok(()),
                 PermissionInstruction::CanRegisterAccount(account_id, Some(domain_name))
-                    .execute(&mut world_state_view)
+                    .execute(&world_state_view)
             );
         }
 
@@ -672,7 +917,7 @@ pub mod isi {
         fn test_can_register_account_in_domain_should_fail_with_permission_object_not_found() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -688,23 +933,20 @@ pub mod isi {
                 asset_id.clone(),
                 ("register_account".to_string(), wrong_domain_name.clone()),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -715,7 +957,7 @@ pub mod isi {
             assert_eq!(
                 Err("Permission object not satisfied.: AnotherCompany".to_string()),
                 PermissionInstruction::CanRegisterAccount(account_id, Some(domain_name))
-                    .execute(&mut world_state_view)
+                    .execute(&world_state_view)
             );
         }
 
@@ -723,29 +965,26 @@ pub mod isi {
         fn test_can_register_account_without_permission_should_fail_with_permission_not_found() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
                 AssetDefinition::new(asset_definition_id.clone()),
             );
             let account_id = AccountId::new("ROOT", &domain_name);
-            let account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
-            let mut accounts = HashMap::new();
+            let account = Account::new(&account_id.name, &account_id.domain_name, public_key);
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -754,7 +993,7 @@ pub mod isi {
                 domains,
             ));
             assert!(PermissionInstruction::CanRegisterAccount(account_id, None)
-                .execute(&mut world_state_view)
+                .execute(&world_state_view)
                 .unwrap_err()
                 .contains(PERMISSION_NOT_FOUND));
         }
@@ -765,7 +1004,7 @@ pub mod isi {
                 AccountId::new("NOT_ROOT", "Company"),
                 None
             )
-            .execute(&mut WorldStateView::new(Peer::new(
+            .execute(&WorldStateView::new(Peer::new(
                 PeerId {
                     address: "127.0.0.1:8080".to_string(),
                     public_key: [0; 32],
@@ -780,7 +1019,7 @@ pub mod isi {
         fn test_can_register_asset_definition_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -795,23 +1034,20 @@ pub mod isi {
                 asset_id.clone(),
                 ("register_asset_definition".to_string(), "any".to_string()),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -822,7 +1058,7 @@ pub mod isi {
             assert_eq!(
                 Ok(()),
                 PermissionInstruction::CanRegisterAssetDefinition(account_id, None)
-                    .execute(&mut world_state_view)
+                    .execute(&world_state_view)
             );
         }
 
@@ -830,7 +1066,7 @@ pub mod isi {
         fn test_can_register_asset_definition_in_domain_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -845,23 +1081,20 @@ pub mod isi {
                 asset_id.clone(),
                 ("register_asset_definition".to_string(), domain_name.clone()),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -872,7 +1105,7 @@ pub mod isi {
             assert_eq!(
                 Ok(()),
                 PermissionInstruction::CanRegisterAssetDefinition(account_id, Some(domain_name))
-                    .execute(&mut world_state_view)
+                    .execute(&world_state_view)
             );
         }
 
@@ -881,7 +1114,7 @@ pub mod isi {
         ) {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -900,23 +1133,20 @@ pub mod isi {
                     wrong_domain_name.clone(),
                 ),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -927,7 +1157,7 @@ pub mod isi {
             assert_eq!(
                 Err("Permission object not satisfied.: AnotherCompany".to_string()),
                 PermissionInstruction::CanRegisterAssetDefinition(account_id, Some(domain_name))
-                    .execute(&mut world_state_view)
+                    .execute(&world_state_view)
             );
         }
 
@@ -936,29 +1166,26 @@ pub mod isi {
         ) {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
                 AssetDefinition::new(asset_definition_id.clone()),
             );
             let account_id = AccountId::new("ROOT", &domain_name);
-            let account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
-            let mut accounts = HashMap::new();
+            let account = Account::new(&account_id.name, &account_id.domain_name, public_key);
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -968,7 +1195,7 @@ pub mod isi {
             ));
             assert!(
                 PermissionInstruction::CanRegisterAssetDefinition(account_id, None)
-                    .execute(&mut world_state_view)
+                    .execute(&world_state_view)
                     .unwrap_err()
                     .contains(PERMISSION_NOT_FOUND)
             );
@@ -980,7 +1207,7 @@ pub mod isi {
                 AccountId::new("NOT_ROOT", "Company"),
                 None
             )
-            .execute(&mut WorldStateView::new(Peer::new(
+            .execute(&WorldStateView::new(Peer::new(
                 PeerId {
                     address: "127.0.0.1:8080".to_string(),
                     public_key: [0; 32],
@@ -995,7 +1222,7 @@ pub mod isi {
         fn test_can_transfer_asset_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -1014,23 +1241,20 @@ pub mod isi {
                     transfer_asset_definition_id.to_string() + "any",
                 ),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -1045,7 +1269,7 @@ pub mod isi {
                     transfer_asset_definition_id,
                     None
                 )
-                .execute(&mut world_state_view)
+                .execute(&world_state_view)
             );
         }
 
@@ -1053,7 +1277,7 @@ pub mod isi {
         fn test_can_transfer_asset_in_domain_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -1072,23 +1296,20 @@ pub mod isi {
                     transfer_asset_definition_id.to_string() + &domain_name,
                 ),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -1103,7 +1324,7 @@ pub mod isi {
                     transfer_asset_definition_id,
                     Some(domain_name)
                 )
-                .execute(&mut world_state_view)
+                .execute(&world_state_view)
             );
         }
 
@@ -1111,7 +1332,7 @@ pub mod isi {
         fn test_can_transfer_asset_in_domain_should_fail_with_permission_object_not_found() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -1127,23 +1348,20 @@ pub mod isi {
                 asset_id.clone(),
                 ("transfer_asset".to_string(), wrong_domain_name.clone()),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -1158,7 +1376,7 @@ pub mod isi {
                     AssetDefinitionId::new("XOR", "SORA"),
                     Some(domain_name)
                 )
-                .execute(&mut world_state_view)
+                .execute(&world_state_view)
             );
         }
 
@@ -1166,29 +1384,26 @@ pub mod isi {
         fn test_can_transfer_asset_without_permission_should_fail_with_permission_not_found() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
                 AssetDefinition::new(asset_definition_id.clone()),
             );
             let account_id = AccountId::new("ROOT", &domain_name);
-            let account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
-            let mut accounts = HashMap::new();
+            let account = Account::new(&account_id.name, &account_id.domain_name, public_key);
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -1201,7 +1416,7 @@ pub mod isi {
                 AssetDefinitionId::new("XOR", "SORA"),
                 None
             )
-            .execute(&mut world_state_view)
+            .execute(&world_state_view)
             .unwrap_err()
             .contains(PERMISSION_NOT_FOUND));
         }
@@ -1213,7 +1428,7 @@ pub mod isi {
                 AssetDefinitionId::new("XOR", "SORA"),
                 None
             )
-            .execute(&mut WorldStateView::new(Peer::new(
+            .execute(&WorldStateView::new(Peer::new(
                 PeerId {
                     address: "127.0.0.1:8080".to_string(),
                     public_key: [0; 32],
@@ -1228,7 +1443,7 @@ pub mod isi {
         fn test_can_mint_asset_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -1247,23 +1462,20 @@ pub mod isi {
                     mint_asset_definition_id.to_string() + "any",
                 ),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -1274,15 +1486,77 @@ pub mod isi {
             assert_eq!(
                 Ok(()),
                 PermissionInstruction::CanMintAsset(account_id, mint_asset_definition_id, None)
-                    .execute(&mut world_state_view)
+                    .execute(&world_state_view)
             );
         }
 
+        #[test]
+        fn test_can_burn_asset_should_pass() {
+            let domain_name = "Company".to_string();
+            let public_key = [0; 32];
+            let mut asset_definitions = BTreeMap::new();
+            let asset_definition_id = permission_asset_definition_id();
+            asset_definitions.insert(
+                asset_definition_id.clone(),
+                AssetDefinition::new(asset_definition_id.clone()),
+            );
+            let account_id = AccountId::new("ROOT", &domain_name);
+            let asset_id = AssetId {
+                definition_id: asset_definition_id,
+                account_id: account_id.clone(),
+            };
+            let burn_asset_definition_id = AssetDefinitionId::new("XOR", "SORA");
+            let asset = Asset::with_permission(
+                asset_id.clone(),
+                (
+                    "burn_asset".to_string(),
+                    burn_asset_definition_id.to_string() + "any",
+                ),
+            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
+            account.assets.insert(asset_id.clone(), asset);
+            let mut accounts = BTreeMap::new();
+            accounts.insert(account_id.clone(), account);
+            let domain = Domain {
+                name: domain_name.clone(),
+                accounts,
+                asset_definitions,
+                metadata: BTreeMap::new(),
+            };
+            let mut domains = HashMap::new();
+            domains.insert(domain_name.clone(), domain);
+            let address = "127.0.0.1:8080".to_string();
+            let world_state_view = WorldStateView::new(Peer::with_domains(
+                PeerId {
+                    address: address.clone(),
+                    public_key,
+                },
+                &Vec::new(),
+                domains,
+            ));
+            assert_eq!(
+                Ok(()),
+                PermissionInstruction::CanBurnAsset(
+                    account_id.clone(),
+                    burn_asset_definition_id.clone(),
+                    None
+                )
+                .execute(&world_state_view)
+            );
+            assert!(PermissionInstruction::CanMintAsset(
+                account_id,
+                burn_asset_definition_id,
+                None
+            )
+            .execute(&world_state_view)
+            .is_err());
+        }
+
         #[test]
         fn test_can_mint_asset_in_domain_should_pass() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -1301,23 +1575,20 @@ pub mod isi {
                     mint_asset_definition_id.to_string() + &domain_name,
                 ),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address: address.clone(),
                     public_key,
@@ -1332,7 +1603,7 @@ pub mod isi {
                     mint_asset_definition_id,
                     Some(domain_name)
                 )
-                .execute(&mut world_state_view)
+                .execute(&world_state_view)
             );
         }
 
@@ -1340,7 +1611,7 @@ pub mod isi {
         fn test_can_mint_asset_in_domain_should_fail_with_permission_object_not_found() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -1356,23 +1627,20 @@ pub mod isi {
                 asset_id.clone(),
                 ("mint_asset".to_string(), wrong_domain_name.clone()),
             );
-            let mut account = Account::new(
-                &account_id.name,
-                &account_id.domain_name,
-                public_key.clone(),
-            );
+            let mut account = Account::new(&account_id.name, &account_id.domain_name, public_key);
             account.assets.insert(asset_id.clone(), asset);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name.clone(), domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address,
                     public_key,
@@ -1387,7 +1655,7 @@ pub mod isi {
                     AssetDefinitionId::new("XOR", "SORA"),
                     Some(domain_name)
                 )
-                .execute(&mut world_state_view)
+                .execute(&world_state_view)
             );
         }
 
@@ -1395,7 +1663,7 @@ pub mod isi {
         fn test_can_mint_asset_without_permission_should_fail_with_permission_not_found() {
             let domain_name = "Company".to_string();
             let public_key = [0; 32];
-            let mut asset_definitions = HashMap::new();
+            let mut asset_definitions = BTreeMap::new();
             let asset_definition_id = permission_asset_definition_id();
             asset_definitions.insert(
                 asset_definition_id.clone(),
@@ -1403,17 +1671,18 @@ pub mod isi {
             );
             let account_id = AccountId::new("ROOT", &domain_name);
             let account = Account::new(&account_id.name, &account_id.domain_name, public_key);
-            let mut accounts = HashMap::new();
+            let mut accounts = BTreeMap::new();
             accounts.insert(account_id.clone(), account);
             let domain = Domain {
                 name: domain_name.clone(),
                 accounts,
                 asset_definitions,
+                metadata: BTreeMap::new(),
             };
             let mut domains = HashMap::new();
             domains.insert(domain_name, domain);
             let address = "127.0.0.1:8080".to_string();
-            let mut world_state_view = WorldStateView::new(Peer::with_domains(
+            let world_state_view = WorldStateView::new(Peer::with_domains(
                 PeerId {
                     address,
                     public_key,
@@ -1426,7 +1695,7 @@ pub mod isi {
                 AssetDefinitionId::new("XOR", "SORA"),
                 None
             )
-            .execute(&mut world_state_view)
+            .execute(&world_state_view)
             .unwrap_err()
             .contains(PERMISSION_NOT_FOUND));
         }
@@ -1438,7 +1707,7 @@ pub mod isi {
                 AssetDefinitionId::new("XOR", "SORA"),
                 None
             )
-            .execute(&mut WorldStateView::new(Peer::new(
+            .execute(&WorldStateView::new(Peer::new(
                 PeerId {
                     address: "127.0.0.1:8080".to_string(),
                     public_key: [0; 32],
@@ -1448,5 +1717,362 @@ pub mod isi {
             .unwrap_err()
             .contains(PERMISSION_NOT_FOUND));
         }
+
+        #[test]
+        fn test_can_set_key_value_in_account_should_pass() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "set_key_value_in_account",
+                "alice@global",
+            );
+            assert_eq!(
+                Ok(()),
+                PermissionInstruction::CanSetKeyValueInAccount(alice_id.clone(), alice_id)
+                    .execute(&world_state_view)
+            );
+        }
+
+        #[test]
+        fn test_can_set_key_value_in_own_account_without_permission_should_fail() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            assert!(PermissionInstruction::CanSetKeyValueInAccount(
+                alice_id.clone(),
+                alice_id.clone()
+            )
+            .execute(&world_state_view)
+            .unwrap_err()
+            .contains(PERMISSION_NOT_FOUND));
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "set_key_value_in_account",
+                "bob@global",
+            );
+            assert!(
+                PermissionInstruction::CanSetKeyValueInAccount(alice_id.clone(), alice_id)
+                    .execute(&world_state_view)
+                    .unwrap_err()
+                    .contains(PERMISSION_OBJECT_NOT_SATISFIED)
+            );
+        }
+
+        #[test]
+        fn test_can_set_key_value_in_domain_should_pass() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "set_key_value_in_domain",
+                "global",
+            );
+            assert_eq!(
+                Ok(()),
+                PermissionInstruction::CanSetKeyValueInDomain(alice_id, "global".to_string())
+                    .execute(&world_state_view)
+            );
+        }
+
+        #[test]
+        fn test_can_set_key_value_in_domain_should_fail_with_permission_object_not_satisfied() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "set_key_value_in_domain",
+                "wonderland",
+            );
+            assert!(
+                PermissionInstruction::CanSetKeyValueInDomain(alice_id, "global".to_string())
+                    .execute(&world_state_view)
+                    .unwrap_err()
+                    .contains(PERMISSION_OBJECT_NOT_SATISFIED)
+            );
+        }
+
+        #[test]
+        fn test_can_set_key_value_in_asset_definition_should_pass() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "set_key_value_in_asset_definition",
+                "xor#global",
+            );
+            assert_eq!(
+                Ok(()),
+                PermissionInstruction::CanSetKeyValueInAssetDefinition(
+                    alice_id,
+                    AssetDefinitionId::new("xor", "global")
+                )
+                .execute(&world_state_view)
+            );
+        }
+
+        #[test]
+        fn test_can_set_key_value_in_asset_definition_without_permission_should_fail_with_permission_not_found(
+        ) {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            assert!(PermissionInstruction::CanSetKeyValueInAssetDefinition(
+                alice_id,
+                AssetDefinitionId::new("xor", "global")
+            )
+            .execute(&world_state_view)
+            .unwrap_err()
+            .contains(PERMISSION_NOT_FOUND));
+        }
+
+        #[test]
+        fn test_can_set_key_value_in_asset_should_pass() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            let bob_id = add_account(&mut world_state_view, "bob", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "set_key_value_in_asset",
+                "documents#global",
+            );
+            assert_eq!(
+                Ok(()),
+                PermissionInstruction::CanSetKeyValueInAsset(
+                    alice_id,
+                    AssetId {
+                        definition_id: AssetDefinitionId::new("documents", "global"),
+                        account_id: bob_id,
+                    }
+                )
+                .execute(&world_state_view)
+            );
+        }
+
+        #[test]
+        fn test_can_set_key_value_in_own_asset_without_permission_should_fail() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            let alice_documents_id = AssetId {
+                definition_id: AssetDefinitionId::new("documents", "global"),
+                account_id: alice_id.clone(),
+            };
+            assert!(PermissionInstruction::CanSetKeyValueInAsset(
+                alice_id.clone(),
+                alice_documents_id.clone()
+            )
+            .execute(&world_state_view)
+            .unwrap_err()
+            .contains(PERMISSION_NOT_FOUND));
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "set_key_value_in_asset",
+                "contracts#global",
+            );
+            assert!(
+                PermissionInstruction::CanSetKeyValueInAsset(alice_id, alice_documents_id)
+                    .execute(&world_state_view)
+                    .unwrap_err()
+                    .contains(PERMISSION_OBJECT_NOT_SATISFIED)
+            );
+        }
+
+        #[test]
+        fn test_can_burn_asset_in_domain_should_fail_with_permission_object_not_found() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "burn_asset",
+                "xor#globalwonderland",
+            );
+            assert_eq!(
+                Err("Permission object not satisfied.: xor#globalwonderland".to_string()),
+                PermissionInstruction::CanBurnAsset(
+                    alice_id,
+                    AssetDefinitionId::new("xor", "global"),
+                    Some("global".to_string())
+                )
+                .execute(&world_state_view)
+            );
+        }
+
+        #[test]
+        fn test_can_burn_asset_without_permission_should_fail_with_permission_not_found() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "mint_asset",
+                "xor#globalany",
+            );
+            assert!(PermissionInstruction::CanBurnAsset(
+                alice_id,
+                AssetDefinitionId::new("xor", "global"),
+                None
+            )
+            .execute(&world_state_view)
+            .unwrap_err()
+            .contains(PERMISSION_NOT_FOUND));
+        }
+
+        #[test]
+        fn test_can_unregister_account_should_pass() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "unregister_account",
+                "global",
+            );
+            assert_eq!(
+                Ok(()),
+                PermissionInstruction::CanUnregisterAccount(alice_id, "global".to_string())
+                    .execute(&world_state_view)
+            );
+        }
+
+        #[test]
+        fn test_can_unregister_account_should_fail_with_permission_object_not_satisfied() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "unregister_account",
+                "wonderland",
+            );
+            assert!(
+                PermissionInstruction::CanUnregisterAccount(alice_id, "global".to_string())
+                    .execute(&world_state_view)
+                    .unwrap_err()
+                    .contains(PERMISSION_OBJECT_NOT_SATISFIED)
+            );
+        }
+
+        #[test]
+        fn test_can_unregister_asset_definition_should_pass() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "unregister_asset_definition",
+                "global",
+            );
+            assert_eq!(
+                Ok(()),
+                PermissionInstruction::CanUnregisterAssetDefinition(alice_id, "global".to_string())
+                    .execute(&world_state_view)
+            );
+        }
+
+        #[test]
+        fn test_can_unregister_asset_definition_without_permission_should_fail_with_permission_not_found(
+        ) {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "unregister_account",
+                "global",
+            );
+            assert!(PermissionInstruction::CanUnregisterAssetDefinition(
+                alice_id,
+                "global".to_string()
+            )
+            .execute(&world_state_view)
+            .unwrap_err()
+            .contains(PERMISSION_NOT_FOUND));
+        }
+
+        #[test]
+        fn test_can_remove_domain_should_pass() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "remove_domain",
+                "wonderland",
+            );
+            assert_eq!(
+                Ok(()),
+                PermissionInstruction::CanRemoveDomain(alice_id, "wonderland".to_string())
+                    .execute(&world_state_view)
+            );
+        }
+
+        #[test]
+        fn test_can_remove_domain_should_fail_with_permission_object_not_satisfied() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            let mut world_state_view = world_state_view_with_root(public_key);
+            let alice_id = add_account(&mut world_state_view, "alice", public_key);
+            grant_permission(
+                &mut world_state_view,
+                &alice_id,
+                "remove_domain",
+                "wonderland",
+            );
+            assert!(
+                PermissionInstruction::CanRemoveDomain(alice_id, "global".to_string())
+                    .execute(&world_state_view)
+                    .unwrap_err()
+                    .contains(PERMISSION_OBJECT_NOT_SATISFIED)
+            );
+        }
+
+        #[test]
+        fn test_can_remove_domain_without_an_account_fail_with_permission_not_found() {
+            let (public_key, _) =
+                crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+            assert!(PermissionInstruction::CanRemoveDomain(
+                AccountId::new("alice", "global"),
+                "wonderland".to_string()
+            )
+            .execute(&world_state_view_with_root(public_key))
+            .unwrap_err()
+            .contains(PERMISSION_NOT_FOUND));
+        }
     }
 }
@@ -1,41 +1,324 @@
 //! This module contains query related Iroha functionality.
 
-use crate::{asset, prelude::*};
+#[cfg(feature = "bridge")]
+use crate::bridge;
+use crate::{
+    account, asset, block, config, crypto, domain, peer, permission::isi::PermissionInstruction,
+    prelude::*, tx,
+};
 use iroha_derive::Io;
 use parity_scale_codec::{Decode, Encode};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    time::SystemTime,
+};
 
 /// I/O ready structure to send queries.
-#[derive(Debug, Io, Encode, Decode)]
+#[derive(Clone, Debug, Io, Encode, Decode)]
 pub struct QueryRequest {
     /// Timestamp of the query creation.
     pub timestamp: String,
-    /// Optional query signature.
+    /// Account on behalf of which the query is requested, `None` for anonymous queries.
+    pub account_id: Option<<Account as Identifiable>::Id>,
+    /// Height of the block right after which the state should be queried, `None` for the
+    /// current state.
+    pub at_height: Option<u64>,
+    /// Signature of the requesting account, `None` for anonymous queries.
     pub signature: Option<Signature>,
     /// Query definition.
     pub query: IrohaQuery,
 }
 
+impl QueryRequest {
+    /// Constructs an unsigned `QueryRequest` for the given query, timestamped with the current
+    /// system time.
+    pub fn new(query: IrohaQuery) -> Self {
+        QueryRequest {
+            timestamp: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .expect("Failed to get System Time.")
+                .as_millis()
+                .to_string(),
+            account_id: Option::None,
+            at_height: Option::None,
+            signature: Option::None,
+            query,
+        }
+    }
+
+    /// Sets the height of the block right after which the state should be queried.
+    /// Should be called before signing the request.
+    pub fn at_height(mut self, height: u64) -> QueryRequest {
+        self.at_height = Some(height);
+        self
+    }
+
+    /// Signs the request on behalf of the account with `account_id` using the given key pair.
+    /// Returns `Err(String)` with error message if signing failed.
+    pub fn sign(
+        mut self,
+        account_id: <Account as Identifiable>::Id,
+        public_key: &PublicKey,
+        private_key: &PrivateKey,
+    ) -> Result<QueryRequest, String> {
+        self.account_id = Some(account_id);
+        self.signature = Some(Signature::new(*public_key, &self.payload(), private_key)?);
+        Ok(self)
+    }
+
+    /// Checks that the request is allowed to be executed on the `WorldStateView`.
+    ///
+    /// The `timestamp` of the request should be within `timestamp_window_ms` of the query limits
+    /// from the time of the peer. Signed requests should be signed by one of the signatories of
+    /// the requesting account and this account should have a permission to read the queried
+    /// entities. Anonymous requests are allowed only if `allow_unsigned_queries` is set.
+    /// Historical requests should ask for the state which is not pruned yet.
+    pub fn validate(
+        &self,
+        world_state_view: &WorldStateView,
+        allow_unsigned_queries: bool,
+    ) -> Result<(), QueryError> {
+        self.check_timestamp(world_state_view.query_limits().timestamp_window_ms)?;
+        if let Some(height) = self.at_height {
+            world_state_view.check_height(height)?;
+        }
+        match (&self.account_id, &self.signature) {
+            (Some(account_id), Some(signature)) => {
+                signature
+                    .verify(&self.payload())
+                    .map_err(QueryError::Malformed)?;
+                if !world_state_view
+                    .read_account(account_id)
+                    .is_some_and(|account| account.has_signatory(&signature.public_key))
+                {
+                    return Err(QueryError::PermissionDenied(format!(
+                        "Query is not signed by a signatory of the account: {:?}.",
+                        account_id
+                    )));
+                }
+                self.query
+                    .read_permission(account_id.clone())
+                    .execute(world_state_view)
+                    .map_err(QueryError::PermissionDenied)
+            }
+            (None, None) if allow_unsigned_queries => Ok(()),
+            (None, None) => Err(QueryError::PermissionDenied(
+                "Unsigned queries are not allowed.".to_string(),
+            )),
+            _ => Err(QueryError::Malformed(
+                "Query should have both account id and signature or none of them.".to_string(),
+            )),
+        }
+    }
+
+    /// Execute the query on the state at `at_height` if it is set or on the current state.
+    ///
+    /// Returns Ok(QueryResult) if succeeded and Err(QueryError) if failed.
+    pub fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+        match self.at_height {
+            Some(height) => self.query.execute(&world_state_view.at_height(height)?),
+            None => self.query.execute(world_state_view),
+        }
+    }
+
+    fn check_timestamp(&self, timestamp_window_ms: u64) -> Result<(), QueryError> {
+        let timestamp: u128 = self
+            .timestamp
+            .parse()
+            .map_err(|e| QueryError::Malformed(format!("Failed to parse timestamp: {}", e)))?;
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Failed to get System Time.")
+            .as_millis();
+        if now.saturating_sub(timestamp) > u128::from(timestamp_window_ms)
+            || timestamp.saturating_sub(now) > u128::from(timestamp_window_ms)
+        {
+            Err(QueryError::Malformed(format!(
+                "Query timestamp {} is more than {} ms away from the peer time {}.",
+                timestamp, timestamp_window_ms, now
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        (
+            &self.timestamp,
+            &self.account_id,
+            &self.at_height,
+            &self.query,
+        )
+            .encode()
+    }
+}
+
 /// Enumeration of all legal Iroha Queries.
-#[derive(Debug, Encode, Decode)]
+#[derive(Clone, Debug, Encode, Decode)]
 pub enum IrohaQuery {
+    /// Query all Domains registered in the Peer.
+    FindAllDomains(domain::query::FindAllDomains),
+    /// Query the Domain by its name.
+    FindDomainByName(domain::query::FindDomainByName),
+    /// Query all Accounts of all Domains.
+    FindAllAccounts(account::query::FindAllAccounts),
+    /// Query the Account by its identification.
+    FindAccountById(account::query::FindAccountById),
+    /// Query all Accounts of the Domain.
+    FindAccountsByDomain(account::query::FindAccountsByDomain),
+    /// Query all Asset Definitions of all Domains.
+    FindAllAssetDefinitions(asset::query::FindAllAssetDefinitions),
+    /// Query all Assets of the Asset Definition across all Accounts.
+    FindAssetsByDefinition(asset::query::FindAssetsByDefinition),
     /// Query all Assets related to the Account.
     GetAccountAssets(asset::query::GetAccountAssets),
+    /// Query the supply of the Asset.
+    GetAssetSupply(asset::query::GetAssetSupply),
+    /// Query Accounts holding the Asset sorted by balance.
+    FindAssetHolders(asset::query::FindAssetHolders),
+    /// Query the number of Accounts holding the Asset.
+    GetAssetHolderCount(asset::query::GetAssetHolderCount),
+    /// Query sums of balances of the Asset per Domain.
+    GetAssetBalancesByDomain(asset::query::GetAssetBalancesByDomain),
+    /// Query numbers of Assets held by Accounts.
+    GetAccountAssetCounts(asset::query::GetAccountAssetCounts),
+    /// Query a value by its key from the key-value store of the Asset.
+    FindAssetKeyValue(asset::query::FindAssetKeyValue),
+    /// Query all Peers known to the Peer.
+    FindAllPeers(peer::query::FindAllPeers),
+    /// Query all Listeners registered in the Peer.
+    FindListeners(peer::query::FindListeners),
+    /// Query all registered Bridges.
+    #[cfg(feature = "bridge")]
+    FindAllBridges(bridge::query::FindAllBridges),
+    /// Query the Bridge by its name.
+    #[cfg(feature = "bridge")]
+    FindBridgeByName(bridge::query::FindBridgeByName),
+    /// Query the final status of the Transaction.
+    GetTransactionStatus(tx::query::GetTransactionStatus),
+    /// Query the committed Block by its height.
+    FindBlockByHeight(block::query::FindBlockByHeight),
+    /// Query the committed Block by its hash.
+    FindBlockByHash(block::query::FindBlockByHash),
+    /// Query headers of committed Blocks in the range of heights.
+    FindBlockHeaders(block::query::FindBlockHeaders),
+    /// Query the latest committed Block.
+    FindLatestBlock(block::query::FindLatestBlock),
+    /// Query the committed or rejected Transaction by its hash.
+    FindTransactionByHash(block::query::FindTransactionByHash),
+    /// Query a page of committed Transactions created by the Account.
+    GetAccountTransactions(tx::query::GetAccountTransactions),
+    /// Query a page of committed Transactions which touched the Asset.
+    GetAccountAssetTransactions(tx::query::GetAccountAssetTransactions),
 }
 
 /// Result of queries execution.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Io, Encode, Decode)]
 pub enum QueryResult {
+    /// Query all Domains result.
+    FindAllDomains(domain::query::FindAllDomainsResult),
+    /// Query the Domain by its name result.
+    FindDomainByName(domain::query::FindDomainByNameResult),
+    /// Query all Accounts result.
+    FindAllAccounts(account::query::FindAllAccountsResult),
+    /// Query the Account by its identification result.
+    FindAccountById(account::query::FindAccountByIdResult),
+    /// Query all Accounts of the Domain result.
+    FindAccountsByDomain(account::query::FindAccountsByDomainResult),
+    /// Query all Asset Definitions result.
+    FindAllAssetDefinitions(asset::query::FindAllAssetDefinitionsResult),
+    /// Query all Assets of the Asset Definition result.
+    FindAssetsByDefinition(asset::query::FindAssetsByDefinitionResult),
     /// Query all Assets related to the Account result.
     GetAccountAssets(asset::query::GetAccountAssetsResult),
+    /// Query the supply of the Asset result.
+    GetAssetSupply(asset::query::GetAssetSupplyResult),
+    /// Query Accounts holding the Asset sorted by balance result.
+    FindAssetHolders(asset::query::FindAssetHoldersResult),
+    /// Query the number of Accounts holding the Asset result.
+    GetAssetHolderCount(asset::query::GetAssetHolderCountResult),
+    /// Query sums of balances of the Asset per Domain result.
+    GetAssetBalancesByDomain(asset::query::GetAssetBalancesByDomainResult),
+    /// Query numbers of Assets held by Accounts result.
+    GetAccountAssetCounts(asset::query::GetAccountAssetCountsResult),
+    /// Query a value by its key from the key-value store of the Asset result.
+    FindAssetKeyValue(asset::query::FindAssetKeyValueResult),
+    /// Query all Peers result.
+    FindAllPeers(peer::query::FindAllPeersResult),
+    /// Query all Listeners result.
+    FindListeners(peer::query::FindListenersResult),
+    /// Query all Bridges result.
+    #[cfg(feature = "bridge")]
+    FindAllBridges(bridge::query::FindAllBridgesResult),
+    /// Query the Bridge by its name result.
+    #[cfg(feature = "bridge")]
+    FindBridgeByName(bridge::query::FindBridgeByNameResult),
+    /// Query the final status of the Transaction result.
+    GetTransactionStatus(tx::query::GetTransactionStatusResult),
+    /// Query the committed Block by its height result.
+    FindBlockByHeight(block::query::FindBlockResult),
+    /// Query the committed Block by its hash result.
+    FindBlockByHash(block::query::FindBlockResult),
+    /// Query headers of committed Blocks in the range of heights result.
+    FindBlockHeaders(block::query::FindBlockHeadersResult),
+    /// Query the latest committed Block result.
+    FindLatestBlock(block::query::FindBlockResult),
+    /// Query the committed or rejected Transaction by its hash result.
+    FindTransactionByHash(block::query::FindTransactionByHashResult),
+    /// Query a page of committed Transactions created by the Account result.
+    GetAccountTransactions(tx::query::TransactionsPage),
+    /// Query a page of committed Transactions which touched the Asset result.
+    GetAccountAssetTransactions(tx::query::TransactionsPage),
 }
 
 impl IrohaQuery {
     /// Execute query on the `WorldStateView`.
     ///
-    /// Returns Ok(QueryResult) if succeeded and Err(String) if failed.
-    pub fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, String> {
+    /// Returns Ok(QueryResult) if succeeded and Err(QueryError) if failed.
+    pub fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+        self.as_query().execute(world_state_view)
+    }
+
+    /// Permission the `authority` account should have to request this query.
+    pub fn read_permission(
+        &self,
+        authority: <Account as Identifiable>::Id,
+    ) -> PermissionInstruction {
+        self.as_query().read_permission(authority)
+    }
+
+    fn as_query(&self) -> &dyn Query {
         match self {
-            IrohaQuery::GetAccountAssets(query) => query.execute(world_state_view),
+            IrohaQuery::FindAllDomains(query) => query,
+            IrohaQuery::FindDomainByName(query) => query,
+            IrohaQuery::FindAllAccounts(query) => query,
+            IrohaQuery::FindAccountById(query) => query,
+            IrohaQuery::FindAccountsByDomain(query) => query,
+            IrohaQuery::FindAllAssetDefinitions(query) => query,
+            IrohaQuery::FindAssetsByDefinition(query) => query,
+            IrohaQuery::GetAccountAssets(query) => query,
+            IrohaQuery::GetAssetSupply(query) => query,
+            IrohaQuery::FindAssetHolders(query) => query,
+            IrohaQuery::GetAssetHolderCount(query) => query,
+            IrohaQuery::GetAssetBalancesByDomain(query) => query,
+            IrohaQuery::GetAccountAssetCounts(query) => query,
+            IrohaQuery::FindAssetKeyValue(query) => query,
+            IrohaQuery::FindAllPeers(query) => query,
+            IrohaQuery::FindListeners(query) => query,
+            #[cfg(feature = "bridge")]
+            IrohaQuery::FindAllBridges(query) => query,
+            #[cfg(feature = "bridge")]
+            IrohaQuery::FindBridgeByName(query) => query,
+            IrohaQuery::GetTransactionStatus(query) => query,
+            IrohaQuery::FindBlockByHeight(query) => query,
+            IrohaQuery::FindBlockByHash(query) => query,
+            IrohaQuery::FindBlockHeaders(query) => query,
+            IrohaQuery::FindLatestBlock(query) => query,
+            IrohaQuery::FindTransactionByHash(query) => query,
+            IrohaQuery::GetAccountTransactions(query) => query,
+            IrohaQuery::GetAccountAssetTransactions(query) => query,
         }
     }
 }
@@ -44,6 +327,800 @@ impl IrohaQuery {
 pub trait Query {
     /// Execute query on the `WorldStateView`.
     ///
-    /// Returns Ok(QueryResult) if succeeded and Err(String) if failed.
-    fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, String>;
+    /// Returns Ok(QueryResult) if succeeded and Err(QueryError) if failed.
+    fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError>;
+
+    /// Permission the `authority` account should have to request the query. By default queries
+    /// read the whole world state, so a permission to read everything is required.
+    fn read_permission(&self, authority: <Account as Identifiable>::Id) -> PermissionInstruction {
+        PermissionInstruction::CanReadAll(authority)
+    }
+}
+
+/// Reason of a query rejection, which is sent back to the client.
+#[derive(Clone, Debug, PartialEq, Eq, Io, Encode, Decode)]
+pub enum QueryError {
+    /// Requested entity does not exist.
+    NotFound(String),
+    /// Requesting account is not allowed to execute the query.
+    PermissionDenied(String),
+    /// Request can not be decoded or has invalid signature, cursor or filter.
+    Malformed(String),
+    /// Request or its result exceeds a limit of the peer.
+    LimitExceeded(String),
+    /// World state violates an invariant, so the query can not be answered consistently.
+    InconsistentState(String),
+    /// Requested historical state is older than the oldest retained snapshot of the peer.
+    Pruned(String),
+}
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::NotFound(reason) => write!(f, "Not found: {}", reason),
+            QueryError::PermissionDenied(reason) => write!(f, "Permission denied: {}", reason),
+            QueryError::Malformed(reason) => write!(f, "Malformed query: {}", reason),
+            QueryError::LimitExceeded(reason) => write!(f, "Limit exceeded: {}", reason),
+            QueryError::InconsistentState(reason) => write!(f, "Inconsistent state: {}", reason),
+            QueryError::Pruned(reason) => write!(f, "Pruned state: {}", reason),
+        }
+    }
+}
+
+/// Value of an entity field which `Predicate` and `SortKey` operate on.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
+pub enum Value {
+    /// Numeric field, like a quantity or a count of nested entities.
+    Number(u128),
+    /// Textual field, like a name.
+    String(String),
+}
+
+/// This trait should be implemented for all entities returned by list queries, so they can be
+/// filtered and sorted by their fields.
+pub trait QueryItem {
+    /// Value of the field with the given `name` or `None` if the entity has no such field.
+    fn field(&self, name: &str) -> Option<Value>;
+
+    /// Metadata value with the given `key` or `None` if the entity has no such key or no metadata
+    /// at all.
+    fn metadata(&self, _key: &str) -> Option<&str> {
+        None
+    }
+}
+
+/// Condition on entity fields. Conditions can be combined with `And`, `Or` and `Not`.
+#[derive(Clone, Debug, Encode, Decode)]
+pub enum Predicate {
+    /// The field is equal to the value.
+    Equals(String, Value),
+    /// The field is less than the value.
+    LessThan(String, Value),
+    /// The field is greater than the value.
+    GreaterThan(String, Value),
+    /// The string field contains the substring.
+    Contains(String, String),
+    /// The entity metadata has the key.
+    HasMetadataKey(String),
+    /// The entity metadata has the key with the value.
+    MetadataEquals(String, String),
+    /// All of the predicates hold.
+    And(Vec<Predicate>),
+    /// At least one of the predicates holds.
+    Or(Vec<Predicate>),
+    /// The predicate does not hold.
+    Not(Box<Predicate>),
+}
+
+impl Predicate {
+    /// Checks whether the `item` satisfies the predicate.
+    ///
+    /// Returns `Err(QueryError)` if the predicate refers to an unknown field or compares values of
+    /// different types.
+    pub fn evaluate<T: QueryItem>(&self, item: &T) -> Result<bool, QueryError> {
+        match self {
+            Predicate::Equals(field, value) => {
+                Ok(compare(field, &field_value(item, field)?, value)? == Ordering::Equal)
+            }
+            Predicate::LessThan(field, value) => {
+                Ok(compare(field, &field_value(item, field)?, value)? == Ordering::Less)
+            }
+            Predicate::GreaterThan(field, value) => {
+                Ok(compare(field, &field_value(item, field)?, value)? == Ordering::Greater)
+            }
+            Predicate::Contains(field, substring) => match field_value(item, field)? {
+                Value::String(string) => Ok(string.contains(substring.as_str())),
+                Value::Number(_) => Err(QueryError::Malformed(format!(
+                    "Field {} is not a string.",
+                    field
+                ))),
+            },
+            Predicate::HasMetadataKey(key) => Ok(item.metadata(key).is_some()),
+            Predicate::MetadataEquals(key, value) => Ok(item.metadata(key) == Some(value.as_str())),
+            Predicate::And(predicates) => predicates.iter().try_fold(true, |result, predicate| {
+                Ok(predicate.evaluate(item)? && result)
+            }),
+            Predicate::Or(predicates) => predicates.iter().try_fold(false, |result, predicate| {
+                Ok(predicate.evaluate(item)? || result)
+            }),
+            Predicate::Not(predicate) => Ok(!predicate.evaluate(item)?),
+        }
+    }
+}
+
+fn field_value<T: QueryItem>(item: &T, field: &str) -> Result<Value, QueryError> {
+    item.field(field)
+        .ok_or_else(|| QueryError::Malformed(format!("Unknown field: {}.", field)))
+}
+
+fn compare(field: &str, left: &Value, right: &Value) -> Result<Ordering, QueryError> {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => Ok(left.cmp(right)),
+        (Value::String(left), Value::String(right)) => Ok(left.cmp(right)),
+        _ => Err(QueryError::Malformed(format!(
+            "Field {} can not be compared with {:?}.",
+            field, right
+        ))),
+    }
+}
+
+/// Field to sort the list query results by.
+#[derive(Clone, Debug, Encode, Decode)]
+pub struct SortKey {
+    /// Name of the field.
+    pub field: String,
+    /// Whether the results should be sorted in descending order.
+    pub descending: bool,
+}
+
+impl SortKey {
+    /// Sort by the `field` in ascending order.
+    pub fn ascending(field: &str) -> Self {
+        SortKey {
+            field: field.to_string(),
+            descending: false,
+        }
+    }
+
+    /// Sort by the `field` in descending order.
+    pub fn descending(field: &str) -> Self {
+        SortKey {
+            field: field.to_string(),
+            descending: true,
+        }
+    }
+}
+
+/// Opaque continuation cursor returned with a page of list query results. Passing it back with
+/// the same filter and sorting returns the next page.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct Cursor(Vec<u8>);
+
+/// Limits of the list and aggregate query results, which keep the responses of the peer bounded,
+/// and of the age of accepted query requests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueryLimits {
+    /// Number of results returned if the query does not specify a `limit`.
+    pub default_page_size: u32,
+    /// Maximum `limit` a query can specify.
+    pub max_page_size: u32,
+    /// Maximum number of entries an aggregate query can request.
+    pub max_aggregate_results: u32,
+    /// Maximum difference in milliseconds between the `timestamp` of a request and the time of
+    /// the peer, so a captured signed request can not be replayed later.
+    pub timestamp_window_ms: u64,
+}
+
+impl Default for QueryLimits {
+    fn default() -> Self {
+        QueryLimits {
+            default_page_size: config::DEFAULT_QUERY_DEFAULT_PAGE_SIZE,
+            max_page_size: config::DEFAULT_QUERY_MAX_PAGE_SIZE,
+            max_aggregate_results: config::DEFAULT_QUERY_MAX_AGGREGATE_RESULTS,
+            timestamp_window_ms: config::DEFAULT_QUERY_TIMESTAMP_WINDOW_MS,
+        }
+    }
+}
+
+/// Filtering, sorting and paging of list query results.
+///
+/// Results are filtered by `filter`, then sorted by `sorting` keys in order (ties keep the
+/// default order of the query), then `offset` results after the `cursor` position are skipped
+/// and at most `limit` results are returned. The rest of the results can be requested with the
+/// returned cursor.
+#[derive(Clone, Debug, Default, Encode, Decode)]
+pub struct ListOptions {
+    /// Condition results should satisfy, `None` to return all of them.
+    pub filter: Option<Predicate>,
+    /// Keys to sort results by.
+    pub sorting: Vec<SortKey>,
+    /// Number of results to skip.
+    pub offset: u32,
+    /// Maximum number of results to return, `None` for the default page size of the peer. Should
+    /// not exceed the maximum page size of the peer.
+    pub limit: Option<u32>,
+    /// Cursor returned with the previous page, `None` for the first page.
+    pub cursor: Option<Cursor>,
+}
+
+impl ListOptions {
+    /// Filters, sorts and pages `items` according to the options and the `limits` of the peer.
+    ///
+    /// Returns the page and a cursor to the next page if there are more results.
+    ///
+    /// # Errors
+    /// Fails if `limit` exceeds the maximum page size, or the filter, sorting or cursor are invalid.
+    pub fn apply<T: QueryItem>(
+        &self,
+        items: Vec<T>,
+        limits: QueryLimits,
+    ) -> Result<(Vec<T>, Option<Cursor>), QueryError> {
+        let limit = match self.limit {
+            Some(limit) if limit > limits.max_page_size => {
+                return Err(QueryError::LimitExceeded(format!(
+                    "Page size {} is requested, while maximum is {}.",
+                    limit, limits.max_page_size
+                )))
+            }
+            Some(limit) => limit,
+            None => limits.default_page_size,
+        };
+        let mut selected = Vec::new();
+        for item in items {
+            let matches = match &self.filter {
+                Some(predicate) => predicate.evaluate(&item)?,
+                None => true,
+            };
+            if matches {
+                let keys = self
+                    .sorting
+                    .iter()
+                    .map(|key| field_value(&item, &key.field))
+                    .collect::<Result<Vec<Value>, QueryError>>()?;
+                selected.push((keys, item));
+            }
+        }
+        selected.sort_by(|(left, _), (right, _)| {
+            self.sorting
+                .iter()
+                .zip(left.iter().zip(right.iter()))
+                .map(|(key, (left, right))| {
+                    if key.descending {
+                        right.cmp(left)
+                    } else {
+                        left.cmp(right)
+                    }
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+        let position = match &self.cursor {
+            Some(cursor) => self.cursor_position(cursor)?,
+            None => 0,
+        };
+        let start = position
+            .saturating_add(self.offset as usize)
+            .min(selected.len());
+        let end = start.saturating_add(limit as usize).min(selected.len());
+        let next_cursor = if end < selected.len() {
+            Some(Cursor((self.selection_hash(), end as u32).encode()))
+        } else {
+            None
+        };
+        let page = selected
+            .into_iter()
+            .skip(start)
+            .take(end - start)
+            .map(|(_, item)| item)
+            .collect();
+        Ok((page, next_cursor))
+    }
+
+    fn cursor_position(&self, cursor: &Cursor) -> Result<usize, QueryError> {
+        let (selection_hash, position) = <(Hash, u32)>::decode(&mut cursor.0.as_slice())
+            .map_err(|_| QueryError::Malformed("Cursor can not be decoded.".to_string()))?;
+        if selection_hash != self.selection_hash() {
+            return Err(QueryError::Malformed(
+                "Cursor was issued for a different filter or sorting.".to_string(),
+            ));
+        }
+        Ok(position as usize)
+    }
+
+    fn selection_hash(&self) -> Hash {
+        crypto::hash((&self.filter, &self.sorting).encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::isi::fixtures::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn find_accounts_and_assets() {
+        let world_state_view = world_state_view_with_holders([0; 32]);
+        match domain::query::FindDomainByName::build_request("global".to_string())
+            .query
+            .execute(&world_state_view)
+            .expect("Failed to execute query.")
+        {
+            QueryResult::FindDomainByName(result) => assert_eq!(result.domain.accounts.len(), 3),
+            _ => panic!("Wrong Query Result Type."),
+        }
+        match account::query::FindAccountById::build_request(AccountId::new("bob", "global"))
+            .query
+            .execute(&world_state_view)
+            .expect("Failed to execute query.")
+        {
+            QueryResult::FindAccountById(result) => assert_eq!(result.account.id.name, "bob"),
+            _ => panic!("Wrong Query Result Type."),
+        }
+        match asset::query::FindAssetsByDefinition::build_request(
+            AssetDefinitionId::new("xor", "global"),
+            ListOptions::default(),
+        )
+        .query
+        .execute(&world_state_view)
+        .expect("Failed to execute query.")
+        {
+            QueryResult::FindAssetsByDefinition(result) => assert_eq!(result.assets.len(), 2),
+            _ => panic!("Wrong Query Result Type."),
+        }
+        assert!(account::query::FindAccountsByDomain::build_request(
+            "unknown".to_string(),
+            ListOptions::default(),
+        )
+        .query
+        .execute(&world_state_view)
+        .is_err());
+    }
+
+    #[test]
+    fn query_result_round_trip() {
+        let world_state_view = world_state_view_with_holders([0; 32]);
+        let bytes: Vec<u8> = domain::query::FindAllDomains::build_request(ListOptions::default())
+            .query
+            .execute(&world_state_view)
+            .expect("Failed to execute query.")
+            .into();
+        match QueryResult::try_from(bytes).expect("Failed to decode query result.") {
+            QueryResult::FindAllDomains(result) => {
+                assert_eq!(result.domains.len(), 1);
+                assert_eq!(result.domains[0].accounts.len(), 3);
+            }
+            _ => panic!("Wrong Query Result Type."),
+        }
+    }
+
+    #[test]
+    fn signed_query_should_be_checked_against_signatories_and_permissions() {
+        let (public_key, private_key) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let world_state_view = world_state_view_with_holders(public_key);
+        let alice_id = AccountId::new("alice", "global");
+        let own_assets =
+            asset::query::GetAccountAssets::build_request(alice_id.clone(), ListOptions::default())
+                .sign(alice_id.clone(), &public_key, &private_key)
+                .expect("Failed to sign query.");
+        assert!(own_assets.validate(&world_state_view, false).is_ok());
+        let other_assets = asset::query::GetAccountAssets::build_request(
+            AccountId::new("bob", "global"),
+            ListOptions::default(),
+        )
+        .sign(alice_id.clone(), &public_key, &private_key)
+        .expect("Failed to sign query.");
+        assert!(other_assets.validate(&world_state_view, false).is_err());
+        let all_domains = domain::query::FindAllDomains::build_request(ListOptions::default())
+            .sign(alice_id.clone(), &public_key, &private_key)
+            .expect("Failed to sign query.");
+        assert!(all_domains.validate(&world_state_view, false).is_err());
+        let (other_public_key, other_private_key) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let foreign_signature =
+            asset::query::GetAccountAssets::build_request(alice_id.clone(), ListOptions::default())
+                .sign(alice_id, &other_public_key, &other_private_key)
+                .expect("Failed to sign query.");
+        assert!(foreign_signature
+            .validate(&world_state_view, false)
+            .is_err());
+    }
+
+    #[test]
+    fn replayed_and_future_queries_should_be_rejected() {
+        let (public_key, private_key) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let world_state_view =
+            world_state_view_with_holders(public_key).with_query_limits(QueryLimits {
+                timestamp_window_ms: 1000,
+                ..QueryLimits::default()
+            });
+        let alice_id = AccountId::new("alice", "global");
+        let signed_at = |timestamp: u128| {
+            let mut request = asset::query::GetAccountAssets::build_request(
+                alice_id.clone(),
+                ListOptions::default(),
+            );
+            request.timestamp = timestamp.to_string();
+            request
+                .sign(alice_id.clone(), &public_key, &private_key)
+                .expect("Failed to sign query.")
+        };
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Failed to get System Time.")
+            .as_millis();
+        assert!(signed_at(now).validate(&world_state_view, false).is_ok());
+        match signed_at(now - 60_000).validate(&world_state_view, false) {
+            Err(QueryError::Malformed(_)) => (),
+            result => panic!("Unexpected validation result: {:?}", result),
+        }
+        assert!(signed_at(now + 60_000)
+            .validate(&world_state_view, false)
+            .is_err());
+        let mut request = signed_at(now);
+        request.timestamp = "yesterday".to_string();
+        assert!(request.validate(&world_state_view, true).is_err());
+    }
+
+    #[test]
+    fn unsigned_query_should_be_rejected_unless_allowed() {
+        let world_state_view = world_state_view_with_holders([0; 32]);
+        let request = domain::query::FindAllDomains::build_request(ListOptions::default());
+        assert!(request.validate(&world_state_view, false).is_err());
+        assert!(request.validate(&world_state_view, true).is_ok());
+    }
+
+    #[test]
+    fn list_queries_should_be_filtered_sorted_and_paged() {
+        let mut world_state_view = world_state_view_with_holders([0; 32]);
+        let domain = world_state_view
+            .domain("global")
+            .expect("Failed to find domain.");
+        for name in &["carol", "dave", "eve"] {
+            let account = Account::new(name, "global", [0; 32]);
+            domain.accounts.insert(account.id.clone(), account);
+        }
+        let options = ListOptions {
+            filter: Some(Predicate::And(vec![
+                Predicate::Equals(
+                    "domain_name".to_string(),
+                    Value::String("global".to_string()),
+                ),
+                Predicate::Not(Box::new(Predicate::Or(vec![
+                    Predicate::Equals("name".to_string(), Value::String("bob".to_string())),
+                    Predicate::Contains("name".to_string(), "ro".to_string()),
+                ]))),
+            ])),
+            sorting: vec![SortKey::descending("name")],
+            limit: Some(2),
+            ..ListOptions::default()
+        };
+        let (first_page, next_cursor) =
+            match account::query::FindAllAccounts::build_request(options.clone())
+                .query
+                .execute(&world_state_view)
+                .expect("Failed to execute query.")
+            {
+                QueryResult::FindAllAccounts(result) => (result.accounts, result.next_cursor),
+                _ => panic!("Wrong Query Result Type."),
+            };
+        let names: Vec<&str> = first_page
+            .iter()
+            .map(|account| account.id.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["eve", "dave"]);
+        assert!(next_cursor.is_some());
+        let second_page_options = ListOptions {
+            cursor: next_cursor,
+            ..options.clone()
+        };
+        match account::query::FindAllAccounts::build_request(second_page_options.clone())
+            .query
+            .execute(&world_state_view)
+            .expect("Failed to execute query.")
+        {
+            QueryResult::FindAllAccounts(result) => {
+                assert_eq!(result.accounts.len(), 1);
+                assert_eq!(result.accounts[0].id.name, "alice");
+                assert!(result.next_cursor.is_none());
+            }
+            _ => panic!("Wrong Query Result Type."),
+        }
+        let other_sorting = ListOptions {
+            sorting: vec![SortKey::ascending("name")],
+            ..second_page_options
+        };
+        assert!(
+            account::query::FindAllAccounts::build_request(other_sorting)
+                .query
+                .execute(&world_state_view)
+                .is_err()
+        );
+        let unknown_field = ListOptions {
+            filter: Some(Predicate::GreaterThan(
+                "unknown".to_string(),
+                Value::Number(0),
+            )),
+            ..ListOptions::default()
+        };
+        assert!(
+            account::query::FindAllAccounts::build_request(unknown_field)
+                .query
+                .execute(&world_state_view)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn list_queries_should_be_paged_within_query_limits() {
+        let mut world_state_view =
+            world_state_view_with_holders([0; 32]).with_query_limits(QueryLimits {
+                default_page_size: 2,
+                max_page_size: 3,
+                ..QueryLimits::default()
+            });
+        let domain = world_state_view
+            .domain("global")
+            .expect("Failed to find domain.");
+        for name in &["carol", "dave", "eve"] {
+            let account = Account::new(name, "global", [0; 32]);
+            domain.accounts.insert(account.id.clone(), account);
+        }
+        let page_size =
+            |options: ListOptions| match account::query::FindAllAccounts::build_request(options)
+                .query
+                .execute(&world_state_view)?
+            {
+                QueryResult::FindAllAccounts(result) => {
+                    Ok((result.accounts.len(), result.next_cursor.is_some()))
+                }
+                _ => panic!("Wrong Query Result Type."),
+            };
+        assert_eq!(page_size(ListOptions::default()), Ok((2, true)));
+        let limit = |limit| ListOptions {
+            limit: Some(limit),
+            ..ListOptions::default()
+        };
+        assert_eq!(page_size(limit(3)), Ok((3, true)));
+        match page_size(limit(4)) {
+            Err(QueryError::LimitExceeded(_)) => (),
+            result => panic!("Unexpected result: {:?}.", result),
+        }
+    }
+
+    #[test]
+    fn list_queries_should_be_filtered_by_metadata() {
+        let mut world_state_view = world_state_view_with_holders([0; 32]);
+        let domain = world_state_view
+            .domain("global")
+            .expect("Failed to find domain.");
+        for (name, country) in &[("carol", "jp"), ("dave", "ru"), ("eve", "jp")] {
+            let mut account = Account::new(name, "global", [0; 32]);
+            account
+                .metadata
+                .insert("country".to_string(), country.to_string());
+            domain.accounts.insert(account.id.clone(), account);
+        }
+        let names = |filter: Predicate| -> Vec<String> {
+            let options = ListOptions {
+                filter: Some(filter),
+                sorting: vec![SortKey::ascending("name")],
+                ..ListOptions::default()
+            };
+            match account::query::FindAllAccounts::build_request(options)
+                .query
+                .execute(&world_state_view)
+                .expect("Failed to execute query.")
+            {
+                QueryResult::FindAllAccounts(result) => result
+                    .accounts
+                    .into_iter()
+                    .map(|account| account.id.name)
+                    .collect(),
+                _ => panic!("Wrong Query Result Type."),
+            }
+        };
+        assert_eq!(
+            names(Predicate::MetadataEquals(
+                "country".to_string(),
+                "jp".to_string()
+            )),
+            vec!["carol", "eve"]
+        );
+        assert_eq!(
+            names(Predicate::HasMetadataKey("country".to_string())),
+            vec!["carol", "dave", "eve"]
+        );
+        assert_eq!(
+            names(Predicate::Not(Box::new(Predicate::HasMetadataKey(
+                "country".to_string()
+            )))),
+            vec!["alice", "bob", "root"]
+        );
+    }
+
+    #[test]
+    fn query_errors_should_be_typed_and_should_not_leak_the_state() {
+        let world_state_view = world_state_view_with_holders([0; 32]);
+        match asset::query::GetAccountAssets::build_request(
+            AccountId::new("carol", "global"),
+            ListOptions::default(),
+        )
+        .query
+        .execute(&world_state_view)
+        {
+            Err(QueryError::NotFound(reason)) => {
+                assert!(reason.contains("carol"));
+                assert!(!reason.contains("alice"));
+            }
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        let malformed_cursor = ListOptions {
+            cursor: Some(Cursor(vec![1, 2, 3])),
+            ..ListOptions::default()
+        };
+        match domain::query::FindAllDomains::build_request(malformed_cursor)
+            .query
+            .execute(&world_state_view)
+        {
+            Err(QueryError::Malformed(_)) => (),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        let error = domain::query::FindAllDomains::build_request(ListOptions::default())
+            .validate(&world_state_view, false)
+            .expect_err("Unsigned query should be rejected.");
+        assert_eq!(
+            QueryError::try_from(Vec::from(&error)).expect("Failed to decode query error."),
+            QueryError::PermissionDenied("Unsigned queries are not allowed.".to_string())
+        );
+    }
+
+    #[test]
+    fn aggregate_queries_should_be_computed_over_the_world_state() {
+        use crate::asset::query::*;
+
+        let max_aggregate_results = 10;
+        let mut world_state_view =
+            world_state_view_with_holders([0; 32]).with_query_limits(QueryLimits {
+                max_aggregate_results,
+                ..QueryLimits::default()
+            });
+        let xor_id = AssetDefinitionId::new("xor", "global");
+        world_state_view
+            .asset(&AssetId {
+                definition_id: xor_id.clone(),
+                account_id: AccountId::new("bob", "global"),
+            })
+            .expect("Failed to find asset.")
+            .quantity = 25;
+        let mut carol = Account::new("carol", "wonderland", [0; 32]);
+        let carol_asset_id = AssetId {
+            definition_id: xor_id.clone(),
+            account_id: carol.id.clone(),
+        };
+        carol.assets.insert(
+            carol_asset_id.clone(),
+            Asset::with_big_quantity(carol_asset_id, 100),
+        );
+        let mut wonderland = Domain::new("wonderland".to_string());
+        wonderland.accounts.insert(carol.id.clone(), carol);
+        world_state_view.add_domain(wonderland);
+        match FindAssetHolders::build_request(xor_id.clone(), 2)
+            .query
+            .execute(&world_state_view)
+        {
+            Ok(QueryResult::FindAssetHolders(result)) => assert_eq!(
+                result.holders,
+                vec![
+                    AssetHolder {
+                        account_id: AccountId::new("carol", "wonderland"),
+                        balance: 100,
+                    },
+                    AssetHolder {
+                        account_id: AccountId::new("bob", "global"),
+                        balance: 25,
+                    },
+                ]
+            ),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        match GetAssetHolderCount::build_request(xor_id.clone())
+            .query
+            .execute(&world_state_view)
+        {
+            Ok(QueryResult::GetAssetHolderCount(result)) => assert_eq!(result.count, 3),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        match GetAssetBalancesByDomain::build_request(xor_id.clone(), max_aggregate_results)
+            .query
+            .execute(&world_state_view)
+        {
+            Ok(QueryResult::GetAssetBalancesByDomain(result)) => assert_eq!(
+                result.balances,
+                vec![
+                    DomainBalance {
+                        domain_name: "global".to_string(),
+                        balance: 35,
+                    },
+                    DomainBalance {
+                        domain_name: "wonderland".to_string(),
+                        balance: 100,
+                    },
+                ]
+            ),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        match GetAccountAssetCounts::build_request(max_aggregate_results)
+            .query
+            .execute(&world_state_view)
+        {
+            Ok(QueryResult::GetAccountAssetCounts(result)) => {
+                assert_eq!(result.counts.len(), 4);
+                assert!(result.counts.iter().all(|count| count.count == 1));
+            }
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        match FindAssetHolders::build_request(xor_id, max_aggregate_results + 1)
+            .query
+            .execute(&world_state_view)
+        {
+            Err(QueryError::LimitExceeded(_)) => (),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        match GetAssetHolderCount::build_request(AssetDefinitionId::new("dot", "global"))
+            .query
+            .execute(&world_state_view)
+        {
+            Err(QueryError::NotFound(_)) => (),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn aggregate_queries_should_reject_limits_above_the_configured_maximum() {
+        use crate::asset::query::*;
+
+        let max_aggregate_results = 2;
+        let world_state_view =
+            world_state_view_with_holders([0; 32]).with_query_limits(QueryLimits {
+                max_aggregate_results,
+                ..QueryLimits::default()
+            });
+        let xor_id = AssetDefinitionId::new("xor", "global");
+        let requests = vec![
+            FindAssetHolders::build_request(xor_id.clone(), max_aggregate_results + 1),
+            GetAssetBalancesByDomain::build_request(xor_id.clone(), max_aggregate_results + 1),
+            GetAssetBalancesByDomain::build_request(xor_id.clone(), u32::MAX),
+            GetAccountAssetCounts::build_request(max_aggregate_results + 1),
+            GetAccountAssetCounts::build_request(u32::MAX),
+        ];
+        for request in requests {
+            match request.query.execute(&world_state_view) {
+                Err(QueryError::LimitExceeded(_)) => (),
+                result => panic!("Unexpected query result: {:?}", result),
+            }
+        }
+        match GetAccountAssetCounts::build_request(max_aggregate_results)
+            .query
+            .execute(&world_state_view)
+        {
+            Ok(QueryResult::GetAccountAssetCounts(result)) => {
+                assert_eq!(result.counts.len(), max_aggregate_results as usize)
+            }
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+        // The holder count is a single entry, so it is not bounded by the aggregate limit even
+        // when there are more holders than `max_aggregate_results`.
+        match GetAssetHolderCount::build_request(xor_id).query.execute(
+            &world_state_view.with_query_limits(QueryLimits {
+                max_aggregate_results: 1,
+                ..QueryLimits::default()
+            }),
+        ) {
+            Ok(QueryResult::GetAssetHolderCount(result)) => assert_eq!(result.count, 2),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+    }
 }
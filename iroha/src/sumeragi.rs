@@ -8,6 +8,7 @@ use crate::{
     peer::PeerId,
     prelude::*,
     torii::uri,
+    tx::TransactionStatus,
 };
 use async_std::sync::RwLock;
 use iroha_derive::*;
@@ -21,6 +22,7 @@ use std::{
     time::{Duration, SystemTime},
 };
 
+#[allow(dead_code)]
 trait Consensus {
     fn round(&mut self, transactions: Vec<AcceptedTransaction>) -> Option<PendingBlock>;
 }
@@ -54,6 +56,10 @@ impl Message {
         .await?
         {
             Response::Ok(_) => Ok(()),
+            Response::Rejected(_) => Err(format!(
+                "Failed to send message - Rejected by peer: {:?}",
+                peer
+            )),
             Response::InternalError => Err(format!(
                 "Failed to send message - Internal Error on peer: {:?}",
                 peer
@@ -261,9 +267,15 @@ impl Sumeragi {
             return Ok(());
         }
         if let Role::Leader = self.role() {
+            self.set_transactions_status(
+                transactions.iter().map(AcceptedTransaction::hash),
+                TransactionStatus::InVotingBlock,
+            )
+            .await;
             let block = PendingBlock::new(transactions)
                 //TODO: actually chain block?
                 .chain_first()
+                .with_state_root(&*self.world_state_view.read().await)
                 .sign(&self.public_key, &self.private_key)?;
             let minimum_quorum_of_peers = 2;
             if self.sorted_peers.len() < minimum_quorum_of_peers {
@@ -289,6 +301,11 @@ impl Sumeragi {
             }
         } else {
             //Sends transactions to leader
+            self.set_transactions_status(
+                transactions.iter().map(AcceptedTransaction::hash),
+                TransactionStatus::Forwarded,
+            )
+            .await;
             let mut send_futures = Vec::new();
             for transaction in &transactions {
                 send_futures.push(
@@ -303,7 +320,7 @@ impl Sumeragi {
                     .await
                     .insert(transaction.hash());
                 let pending_forwarded_tx_hashes = self.pending_forwarded_tx_hashes.clone();
-                let mut no_tx_receipt = NoTransactionReceiptReceived::new(&transaction);
+                let mut no_tx_receipt = NoTransactionReceiptReceived::new(transaction);
                 if self.role() == Role::ValidatingPeer || self.role() == Role::ProxyTail {
                     no_tx_receipt
                         .sign(&self.public_key, &self.private_key)
@@ -356,8 +373,19 @@ impl Sumeragi {
         }
     }
 
+    async fn set_transactions_status(
+        &self,
+        hashes: impl Iterator<Item = Hash>,
+        status: TransactionStatus,
+    ) {
+        let mut world_state_view = self.world_state_view.write().await;
+        for hash in hashes {
+            world_state_view.set_pending_transaction_status(hash, status.clone());
+        }
+    }
+
     /// This method is used to sort list of peers.
-    pub fn sort_peers(peers: &mut Vec<PeerId>, block_hash: Option<Hash>) {
+    pub fn sort_peers(peers: &mut [PeerId], block_hash: Option<Hash>) {
         peers.sort_by(|p1, p2| p1.address.cmp(&p2.address));
         if let Some(block_hash) = block_hash {
             let mut rng = StdRng::from_seed(block_hash);
@@ -481,16 +509,20 @@ impl Sumeragi {
         &mut self,
         forwarded_tx: TransactionForwarded,
     ) -> Result<(), String> {
+        let transaction = RequestedTransaction::from(&forwarded_tx.transaction).accept()?;
         let _result = Message::TransactionReceived(TransactionReceipt::new(
-            &forwarded_tx.transaction,
+            &transaction,
             &self.public_key,
             &self.private_key,
         )?)
         .send_to(&forwarded_tx.peer)
         .await;
-        self.transaction_sender
-            .send(forwarded_tx.transaction.clone())
-            .await;
+        self.set_transactions_status(
+            std::iter::once(transaction.hash()),
+            TransactionStatus::Queued,
+        )
+        .await;
+        self.transaction_sender.send(transaction).await;
         Ok(())
     }
 
@@ -516,8 +548,14 @@ impl Sumeragi {
 
     #[log]
     async fn handle_block_created(&mut self, block: SignedBlock) -> Result<(), String> {
+        self.set_transactions_status(
+            block.transactions.iter().map(SignedTransaction::hash),
+            TransactionStatus::InVotingBlock,
+        )
+        .await;
         match self.role() {
             Role::ValidatingPeer => {
+                self.check_state_root(&block).await?;
                 if let Err(e) =
                     Message::BlockSigned(block.clone().sign(&self.public_key, &self.private_key)?)
                         .send_to(self.proxy_tail())
@@ -533,10 +571,9 @@ impl Sumeragi {
                 self.start_commit_countdown(voting_block.clone()).await;
                 //TODO: send to set b so they can observe
             }
-            Role::ProxyTail => {
-                if self.voting_block.write().await.is_none() {
-                    *self.voting_block.write().await = Some(VotingBlock::new(block));
-                }
+            Role::ProxyTail if self.voting_block.write().await.is_none() => {
+                self.check_state_root(&block).await?;
+                *self.voting_block.write().await = Some(VotingBlock::new(block));
             }
             _ => (),
         }
@@ -558,7 +595,10 @@ impl Sumeragi {
                     }
                     *self.voting_block.write().await = Some(voting_block);
                 }
-                None => *self.voting_block.write().await = Some(VotingBlock::new(block)),
+                None => {
+                    self.check_state_root(&block).await?;
+                    *self.voting_block.write().await = Some(VotingBlock::new(block))
+                }
             };
             let voting_block = self.voting_block.write().await.clone();
             if let Some(VotingBlock { block, .. }) = voting_block {
@@ -590,6 +630,20 @@ impl Sumeragi {
         Ok(())
     }
 
+    /// Checks that applying the block to the current world state of this peer results in the
+    /// state root of the block, so that the peer does not sign a block it can not reproduce.
+    async fn check_state_root(&self, block: &SignedBlock) -> Result<(), String> {
+        let state_root_hash = block.state_root_after(&*self.world_state_view.read().await);
+        if block.header.state_root_hash == state_root_hash {
+            Ok(())
+        } else {
+            Err(format!(
+                "Block state root {:?} differs from the state root {:?} of the peer.",
+                block.header.state_root_hash, state_root_hash
+            ))
+        }
+    }
+
     #[log]
     async fn handle_block_committed(&mut self, block: SignedBlock) -> Result<(), String> {
         //TODO: check if the block is the same as pending
@@ -711,9 +765,8 @@ impl Debug for Sumeragi {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{account, config, crypto, torii::Torii};
-    use async_std::{prelude::*, sync, task};
-    use std::time::Duration;
+    use crate::{config, crypto};
+    use async_std::sync;
 
     #[test]
     #[should_panic]
@@ -729,13 +782,13 @@ mod tests {
         };
         Sumeragi::new(
             private_key,
-            &[this_peer.clone()],
+            std::slice::from_ref(&this_peer),
             this_peer.clone(),
             3,
             Arc::new(RwLock::new(blocks_sender)),
             Arc::new(RwLock::new(WorldStateView::new(Peer::new(
                 this_peer.clone(),
-                &[this_peer],
+                std::slice::from_ref(&this_peer),
             )))),
             transaction_sender,
             config::DEFAULT_COMMIT_TIME_MS,
@@ -820,7 +873,14 @@ mod tests {
                 },
                 &ids,
             ))));
-            let mut torii = Torii::new(ids[i].address.as_str(), wsv.clone(), tx, message_sender);
+            let mut torii = Torii::new(
+                ids[i].address.as_str(),
+                wsv.clone(),
+                tx,
+                message_sender,
+                TransactionLimits::default(),
+                false,
+            );
             task::spawn(async move {
                 torii.start().await.expect("Torii failed.");
             });
@@ -904,7 +964,14 @@ mod tests {
                 },
                 &ids,
             ))));
-            let mut torii = Torii::new(ids[i].address.as_str(), wsv.clone(), tx, message_sender);
+            let mut torii = Torii::new(
+                ids[i].address.as_str(),
+                wsv.clone(),
+                tx,
+                message_sender,
+                TransactionLimits::default(),
+                false,
+            );
             task::spawn(async move {
                 torii.start().await.expect("Torii failed.");
             });
@@ -1008,6 +1075,8 @@ mod tests {
                 wsv.clone(),
                 transaction_sender.clone(),
                 message_sender,
+                TransactionLimits::default(),
+                false,
             );
             task::spawn(async move {
                 torii.start().await.expect("Torii failed.");
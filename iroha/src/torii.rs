@@ -1,21 +1,34 @@
 //! This module contains incoming requests handling logic of Iroha.
 //! `Torii` is used to receive, accept and route incoming instructions, queries and messages.
 
-use crate::{prelude::*, sumeragi::Message, MessageSender};
+use crate::{
+    prelude::*,
+    query::QueryError,
+    sumeragi::Message,
+    tx::{TransactionLimits, TransactionRejectionReason, TransactionStatus},
+    MessageSender,
+};
 use async_std::{sync::RwLock, task};
 use iroha_derive::*;
-#[cfg(feature = "mock")]
+#[cfg(feature = "network-mock")]
 use iroha_network::mock::prelude::*;
-#[cfg(not(feature = "mock"))]
+#[cfg(not(feature = "network-mock"))]
 use iroha_network::prelude::*;
+use parity_scale_codec::Decode;
 use std::{convert::TryFrom, sync::Arc};
 
+/// The maximum size of an encoded query result, leaving room for the response envelope within
+/// the network message size limit.
+const MAX_QUERY_RESULT_SIZE: usize = iroha_network::MAX_MESSAGE_SIZE - 1024;
+
 /// Main network handler and the only entrypoint of the Iroha.
 pub struct Torii {
     url: String,
     world_state_view: Arc<RwLock<WorldStateView>>,
     transaction_sender: Arc<RwLock<TransactionSender>>,
     message_sender: Arc<RwLock<MessageSender>>,
+    transaction_limits: TransactionLimits,
+    allow_unsigned_queries: bool,
 }
 
 impl Torii {
@@ -25,12 +38,16 @@ impl Torii {
         world_state_view: Arc<RwLock<WorldStateView>>,
         transaction_sender: TransactionSender,
         message_sender: MessageSender,
+        transaction_limits: TransactionLimits,
+        allow_unsigned_queries: bool,
     ) -> Self {
         Torii {
             url: url.to_string(),
             world_state_view,
             transaction_sender: Arc::new(RwLock::new(transaction_sender)),
             message_sender: Arc::new(RwLock::new(message_sender)),
+            transaction_limits,
+            allow_unsigned_queries,
         }
     }
 
@@ -44,6 +61,8 @@ impl Torii {
             world_state_view,
             transaction_sender,
             message_sender,
+            transaction_limits: self.transaction_limits,
+            allow_unsigned_queries: self.allow_unsigned_queries,
         };
         Network::listen(Arc::new(RwLock::new(state)), url, handle_connection).await?;
         Ok(())
@@ -55,6 +74,8 @@ struct ToriiState {
     world_state_view: Arc<RwLock<WorldStateView>>,
     transaction_sender: Arc<RwLock<TransactionSender>>,
     message_sender: Arc<RwLock<MessageSender>>,
+    transaction_limits: TransactionLimits,
+    allow_unsigned_queries: bool,
 }
 
 async fn handle_connection(
@@ -74,42 +95,81 @@ async fn handle_connection(
 #[log]
 async fn handle_request(state: State<ToriiState>, request: Request) -> Result<Response, String> {
     match request.url() {
-        uri::INSTRUCTIONS_URI => match RequestedTransaction::try_from(request.payload().to_vec()) {
-            Ok(transaction) => {
-                state
-                    .write()
-                    .await
-                    .transaction_sender
-                    .write()
-                    .await
-                    .send(transaction.accept()?)
-                    .await;
-                Ok(Response::empty_ok())
-            }
-            Err(e) => {
-                eprintln!("Failed to decode transaction: {}", e);
-                Ok(Response::InternalError)
+        uri::INSTRUCTIONS_URI => {
+            let transaction_limits = state.read().await.transaction_limits;
+            match RequestedTransaction::decode_within_limits(request.payload(), &transaction_limits)
+                .and_then(|transaction| {
+                    transaction
+                        .check_limits(request.payload().len(), &transaction_limits)
+                        .map(|_| transaction)
+                })
+                .and_then(|transaction| {
+                    transaction
+                        .accept()
+                        .map_err(TransactionRejectionReason::UnsatisfiedSignatureCondition)
+                }) {
+                Ok(transaction) => {
+                    state
+                        .read()
+                        .await
+                        .world_state_view
+                        .write()
+                        .await
+                        .set_pending_transaction_status(
+                            transaction.hash(),
+                            TransactionStatus::Queued,
+                        );
+                    state
+                        .write()
+                        .await
+                        .transaction_sender
+                        .write()
+                        .await
+                        .send(transaction)
+                        .await;
+                    Ok(Response::empty_ok())
+                }
+                Err(reason) => {
+                    eprintln!("Transaction rejected: {}", reason);
+                    Ok(Response::Rejected(reason.into()))
+                }
             }
-        },
-        uri::QUERY_URI => match QueryRequest::try_from(request.payload().to_vec()) {
-            Ok(request) => match request
-                .query
-                .execute(&*state.read().await.world_state_view.read().await)
-            {
-                Ok(result) => {
-                    let result = &result;
-                    Ok(Response::Ok(result.into()))
+        }
+        uri::QUERY_URI => {
+            let result = match QueryRequest::decode(&mut request.payload()) {
+                Ok(request) => {
+                    let state = state.read().await;
+                    let world_state_view = state.world_state_view.read().await;
+                    request
+                        .validate(&world_state_view, state.allow_unsigned_queries)
+                        .and_then(|()| request.execute(&world_state_view))
+                        .map(|result| Vec::from(&result))
+                        .and_then(|result| {
+                            if result.len() > MAX_QUERY_RESULT_SIZE {
+                                Err(QueryError::LimitExceeded(format!(
+                                    "Query result size {} bytes exceeds the limit of {} bytes, \
+                                     a smaller page should be requested.",
+                                    result.len(),
+                                    MAX_QUERY_RESULT_SIZE
+                                )))
+                            } else {
+                                Ok(result)
+                            }
+                        })
                 }
-                Err(e) => {
-                    eprintln!("Failed to execute Query: {}", e);
-                    Ok(Response::InternalError)
+                Err(e) => Err(QueryError::Malformed(format!(
+                    "Failed to decode query request: {}",
+                    e
+                ))),
+            };
+            match result {
+                Ok(result) => Ok(Response::Ok(result)),
+                Err(error) => {
+                    eprintln!("Query rejected: {}", error);
+                    Ok(Response::Rejected(error.into()))
                 }
-            },
-            Err(e) => {
-                eprintln!("Failed to decode transaction: {}", e);
-                Ok(Response::InternalError)
             }
-        },
+        }
         uri::CONSENSUS_URI => match Message::try_from(request.payload().to_vec()) {
             Ok(message) => {
                 state
@@ -165,6 +225,8 @@ mod tests {
             )))),
             tx_tx,
             ms_tx,
+            config.transaction_limits,
+            config.allow_unsigned_queries,
         );
         task::spawn(async move {
             if let Err(e) = torii.start().await {
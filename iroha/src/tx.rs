@@ -2,10 +2,13 @@
 //!
 //! `RequestedTransaction` is the start of the Transaction lifecycle.
 
-use crate::prelude::*;
+use crate::{config, crypto, isi::validate_name, prelude::*};
 use iroha_derive::Io;
-use parity_scale_codec::{Decode, Encode};
-use std::time::SystemTime;
+use parity_scale_codec::{Decode, DecodeLimit, Encode};
+use std::{
+    fmt::{self, Display, Formatter},
+    time::SystemTime,
+};
 
 /// This structure represents transaction in non-trusted form.
 ///
@@ -28,7 +31,50 @@ struct Payload {
     creation_time: String,
 }
 
+impl Payload {
+    /// Calculate payload `Hash` which identifies the transaction during all its lifecycle.
+    ///
+    /// Signatures are not hashed, so the hash stays the same when signatures are added and a
+    /// client knows the hash of its transaction before peers sign it. Two transactions with the
+    /// same payload have the same hash even if their signatures differ.
+    fn hash(&self) -> Hash {
+        crypto::hash(self.into())
+    }
+}
+
+/// Nesting of the containers in an encoded transaction allowed on top of the instructions nesting,
+/// e.g. the list of instructions and the collections inside of the instructions.
+const MAX_CONTAINER_NESTING: u64 = 16;
+
 impl RequestedTransaction {
+    /// Decodes a transaction received from the network. The size is checked before and the
+    /// nesting is bounded during decoding, so a deeply nested instruction is rejected before it
+    /// can exhaust the stack.
+    ///
+    /// Returns `Ok(RequestedTransaction)` if succeeded and `Err(TransactionRejectionReason)` if not.
+    pub fn decode_within_limits(
+        bytes: &[u8],
+        limits: &TransactionLimits,
+    ) -> Result<RequestedTransaction, TransactionRejectionReason> {
+        let transaction_size = bytes.len() as u64;
+        if transaction_size > limits.max_transaction_size {
+            return Err(TransactionRejectionReason::TransactionTooLarge(
+                transaction_size,
+                limits.max_transaction_size,
+            ));
+        }
+        let depth_limit = limits
+            .max_instruction_depth
+            .saturating_add(MAX_CONTAINER_NESTING)
+            .min(u32::MAX as u64) as u32;
+        RequestedTransaction::decode_all_with_depth_limit(depth_limit, bytes).map_err(|e| {
+            TransactionRejectionReason::MalformedTransaction(format!(
+                "Failed to decode transaction: {}",
+                e.what()
+            ))
+        })
+    }
+
     /// Default `RequestedTransaction` constructor.
     pub fn new(
         instructions: Vec<Instruction>,
@@ -48,16 +94,67 @@ impl RequestedTransaction {
         }
     }
 
+    /// Calculate transaction `Hash` - the hash of its payload without signatures.
+    pub fn hash(&self) -> Hash {
+        self.payload.hash()
+    }
+
+    /// Stateless validation of the transaction against the `TransactionLimits`.
+    /// `transaction_size` is the length of the encoded transaction in bytes.
+    ///
+    /// Returns `Ok(())` if all limits are satisfied and `Err(TransactionRejectionReason)` if not.
+    pub fn check_limits(
+        &self,
+        transaction_size: usize,
+        limits: &TransactionLimits,
+    ) -> Result<(), TransactionRejectionReason> {
+        let transaction_size = transaction_size as u64;
+        if transaction_size > limits.max_transaction_size {
+            return Err(TransactionRejectionReason::TransactionTooLarge(
+                transaction_size,
+                limits.max_transaction_size,
+            ));
+        }
+        let instructions = &self.payload.instructions;
+        if instructions.is_empty() {
+            return Err(TransactionRejectionReason::NoInstructions);
+        }
+        let instruction_number = instructions.len() as u64;
+        if instruction_number > limits.max_instruction_number {
+            return Err(TransactionRejectionReason::TooManyInstructions(
+                instruction_number,
+                limits.max_instruction_number,
+            ));
+        }
+        let instruction_depth = instructions
+            .iter()
+            .map(Instruction::depth)
+            .max()
+            .unwrap_or(0);
+        if instruction_depth > limits.max_instruction_depth {
+            return Err(TransactionRejectionReason::InstructionTooDeep(
+                instruction_depth,
+                limits.max_instruction_depth,
+            ));
+        }
+        let account_id = &self.payload.account_id;
+        if validate_name(&account_id.name).is_err()
+            || validate_name(&account_id.domain_name).is_err()
+        {
+            return Err(TransactionRejectionReason::MalformedAccountId(format!(
+                "{}@{}",
+                account_id.name, account_id.domain_name
+            )));
+        }
+        Ok(())
+    }
+
     /// Transaction acceptance will check that transaction signatures are valid and move state one
     /// step forward.
     ///
     /// Returns `Ok(AcceptedTransaction)` if succeeded and `Err(String)` if failed.
     pub fn accept(self) -> Result<AcceptedTransaction, String> {
-        for signature in &self.signatures {
-            if let Err(e) = signature.verify(&Vec::from(&self.payload)) {
-                return Err(format!("Failed to verify signatures: {}", e));
-            }
-        }
+        verified_signatories(&self.payload, &self.signatures)?;
         Ok(AcceptedTransaction {
             payload: self.payload,
             signatures: self.signatures,
@@ -65,6 +162,104 @@ impl RequestedTransaction {
     }
 }
 
+/// Verifies every signature against the transaction payload.
+///
+/// Returns public keys of the signatures if all of them are valid and `Err(String)` if not.
+fn verified_signatories(
+    payload: &Payload,
+    signatures: &[Signature],
+) -> Result<Vec<PublicKey>, String> {
+    let payload = Vec::from(payload);
+    signatures
+        .iter()
+        .map(|signature| {
+            signature
+                .verify(&payload)
+                .map(|_| signature.public_key)
+                .map_err(|e| format!("Failed to verify signatures: {}", e))
+        })
+        .collect()
+}
+
+/// Limits which `RequestedTransaction` should satisfy to pass stateless validation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransactionLimits {
+    /// Maximum number of instructions in a single transaction.
+    pub max_instruction_number: u64,
+    /// Maximum depth of nested `Compose`, `If` and listener instructions.
+    pub max_instruction_depth: u64,
+    /// Maximum size of the encoded transaction in bytes.
+    pub max_transaction_size: u64,
+}
+
+impl Default for TransactionLimits {
+    fn default() -> Self {
+        TransactionLimits {
+            max_instruction_number: config::DEFAULT_MAX_INSTRUCTION_NUMBER,
+            max_instruction_depth: config::DEFAULT_MAX_INSTRUCTION_DEPTH,
+            max_transaction_size: config::DEFAULT_MAX_TRANSACTION_SIZE,
+        }
+    }
+}
+
+/// Reason of a transaction rejection.
+#[derive(Clone, Debug, PartialEq, Eq, Io, Encode, Decode)]
+pub enum TransactionRejectionReason {
+    /// Encoded transaction size and the maximum allowed size.
+    TransactionTooLarge(u64, u64),
+    /// Transaction contains no instructions.
+    NoInstructions,
+    /// Number of instructions and the maximum allowed number.
+    TooManyInstructions(u64, u64),
+    /// Depth of instructions nesting and the maximum allowed depth.
+    InstructionTooDeep(u64, u64),
+    /// Transaction's account id is malformed.
+    MalformedAccountId(String),
+    /// Transaction's signatures failed verification.
+    UnsatisfiedSignatureCondition(String),
+    /// Execution of the transaction's instructions failed.
+    InstructionExecutionFailed(String),
+    /// Transaction could not be decoded within the limits.
+    MalformedTransaction(String),
+}
+
+impl Display for TransactionRejectionReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TransactionRejectionReason::TransactionTooLarge(size, max) => write!(
+                f,
+                "Transaction size {} bytes exceeds the limit of {} bytes.",
+                size, max
+            ),
+            TransactionRejectionReason::NoInstructions => {
+                write!(f, "Transaction contains no instructions.")
+            }
+            TransactionRejectionReason::TooManyInstructions(number, max) => write!(
+                f,
+                "Transaction contains {} instructions, the limit is {}.",
+                number, max
+            ),
+            TransactionRejectionReason::InstructionTooDeep(depth, max) => write!(
+                f,
+                "Instructions nesting depth {} exceeds the limit of {}.",
+                depth, max
+            ),
+            TransactionRejectionReason::MalformedAccountId(account_id) => {
+                write!(f, "Malformed account id: {}.", account_id)
+            }
+            TransactionRejectionReason::UnsatisfiedSignatureCondition(reason) => {
+                write!(f, "Failed to verify signatures: {}", reason)
+            }
+            TransactionRejectionReason::InstructionExecutionFailed(reason) => {
+                write!(f, "Failed to execute instructions: {}", reason)
+            }
+            TransactionRejectionReason::MalformedTransaction(reason) => {
+                write!(f, "Malformed transaction: {}", reason)
+            }
+        }
+    }
+}
+
 /// An ordered set of instructions, which is applied to the ledger atomically.
 ///
 /// Transactions received by `Iroha` from external resources (clients, peers, etc.)
@@ -98,24 +293,15 @@ impl AcceptedTransaction {
         })
     }
 
-    /// Calculate transaction `Hash`.
+    /// Calculate transaction `Hash` - the hash of its payload without signatures.
     pub fn hash(&self) -> Hash {
-        use ursa::blake2::{
-            digest::{Input, VariableOutput},
-            VarBlake2b,
-        };
-        let bytes: Vec<u8> = self.payload.clone().into();
-        let vec_hash = VarBlake2b::new(32)
-            .expect("Failed to initialize variable size hash")
-            .chain(bytes)
-            .vec_result();
-        let mut hash = [0; 32];
-        hash.copy_from_slice(&vec_hash);
-        hash
+        self.payload.hash()
     }
 }
 
-/// `SignedTransaction` represents transaction with signatures accumulated from Peer/Peers.
+/// `SignedTransaction` represents transaction with signatures of the creator's signatories.
+/// Peers sign blocks, not the transactions in them, so these signatures are the ones which
+/// instructions like `SwapAssets` count.
 #[derive(Clone, Debug, Io, Encode, Decode)]
 pub struct SignedTransaction {
     payload: Payload,
@@ -137,34 +323,48 @@ impl SignedTransaction {
     /// Move transaction lifecycle forward by checking an ability to apply instructions to the
     /// `WorldStateView`.
     ///
-    /// Returns `Ok(ValidTransaction)` if succeeded and `Err(String)` if failed.
+    /// Instructions are applied atomically - if any of them fails, `WorldStateView` stays
+    /// unchanged.
+    ///
+    /// Returns `Ok(ValidTransaction)` if succeeded and `Err(RejectedTransaction)` with the reason
+    /// of rejection if failed.
+    #[allow(clippy::result_large_err)]
     pub fn validate(
         self,
         world_state_view: &mut WorldStateView,
-    ) -> Result<ValidTransaction, String> {
-        for instruction in &self.payload.instructions {
-            instruction.execute(self.payload.account_id.clone(), world_state_view)?;
+    ) -> Result<ValidTransaction, RejectedTransaction> {
+        let payload = &self.payload;
+        let signatories = match verified_signatories(payload, &self.signatures) {
+            Ok(signatories) => signatories,
+            Err(e) => {
+                return Err(RejectedTransaction {
+                    payload: self.payload,
+                    signatures: self.signatures,
+                    rejection_reason: TransactionRejectionReason::UnsatisfiedSignatureCondition(e),
+                })
+            }
+        };
+        match world_state_view.atomically_signed_by(signatories, |world_state_view| {
+            for instruction in &payload.instructions {
+                instruction.execute(payload.account_id.clone(), world_state_view)?;
+            }
+            Ok(())
+        }) {
+            Ok(()) => Ok(ValidTransaction {
+                payload: self.payload,
+                signatures: self.signatures,
+            }),
+            Err(e) => Err(RejectedTransaction {
+                payload: self.payload,
+                signatures: self.signatures,
+                rejection_reason: TransactionRejectionReason::InstructionExecutionFailed(e),
+            }),
         }
-        Ok(ValidTransaction {
-            payload: self.payload,
-            signatures: self.signatures,
-        })
     }
 
-    /// Calculate transaction `Hash`.
+    /// Calculate transaction `Hash` - the hash of its payload without signatures.
     pub fn hash(&self) -> Hash {
-        use ursa::blake2::{
-            digest::{Input, VariableOutput},
-            VarBlake2b,
-        };
-        let bytes: Vec<u8> = self.into();
-        let vec_hash = VarBlake2b::new(32)
-            .expect("Failed to initialize variable size hash")
-            .chain(bytes)
-            .vec_result();
-        let mut hash = [0; 32];
-        hash.copy_from_slice(&vec_hash);
-        hash
+        self.payload.hash()
     }
 }
 
@@ -176,14 +376,108 @@ pub struct ValidTransaction {
 }
 
 impl ValidTransaction {
+    /// Calculate transaction `Hash` - the hash of its payload without signatures.
+    pub fn hash(&self) -> Hash {
+        self.payload.hash()
+    }
+
+    /// Account ID of the transaction creator.
+    pub fn account_id(&self) -> &<Account as Identifiable>::Id {
+        &self.payload.account_id
+    }
+
+    /// Signatures of the transaction.
+    pub fn signatures(&self) -> &[Signature] {
+        &self.signatures
+    }
+
+    /// Instructions of the transaction.
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.payload.instructions
+    }
+
+    /// Identifications of all assets touched by the transaction's instructions.
+    pub fn asset_ids(&self) -> Vec<<Asset as Identifiable>::Id> {
+        let mut asset_ids: Vec<_> = self
+            .payload
+            .instructions
+            .iter()
+            .flat_map(Instruction::asset_ids)
+            .collect();
+        asset_ids.sort();
+        asset_ids.dedup();
+        asset_ids
+    }
+
     /// Apply instructions to the `WorldStateView`.
+    /// Instructions are applied atomically - if any of them fails, none of them is applied.
     pub fn proceed(&self, world_state_view: &mut WorldStateView) -> Result<(), String> {
-        for instruction in &self.payload.instructions {
-            if let Err(e) = instruction.execute(self.payload.account_id.clone(), world_state_view) {
-                eprintln!("Failed to invoke instruction on WSV: {}", e);
+        let signatories = verified_signatories(&self.payload, &self.signatures)?;
+        world_state_view.atomically_signed_by(signatories, |world_state_view| {
+            for instruction in &self.payload.instructions {
+                instruction.execute(self.payload.account_id.clone(), world_state_view)?;
             }
-        }
-        Ok(())
+            Ok(())
+        })
+    }
+}
+
+/// `RejectedTransaction` represents transaction which failed validation, it is stored in the
+/// block together with the reason of rejection.
+#[derive(Clone, Debug, Io, Encode, Decode)]
+pub struct RejectedTransaction {
+    payload: Payload,
+    signatures: Vec<Signature>,
+    /// Reason of the transaction rejection.
+    pub rejection_reason: TransactionRejectionReason,
+}
+
+impl RejectedTransaction {
+    /// Calculate transaction `Hash` - the hash of its payload without signatures.
+    pub fn hash(&self) -> Hash {
+        self.payload.hash()
+    }
+
+    /// Account ID of the transaction creator.
+    pub fn account_id(&self) -> &<Account as Identifiable>::Id {
+        &self.payload.account_id
+    }
+
+    /// Instructions of the transaction.
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.payload.instructions
+    }
+
+    /// Signatures of the transaction.
+    pub fn signatures(&self) -> &[Signature] {
+        &self.signatures
+    }
+}
+
+/// Status of the transaction in its lifecycle.
+#[derive(Clone, Debug, PartialEq, Eq, Io, Encode, Decode)]
+pub enum TransactionStatus {
+    /// Transaction was accepted by the peer and waits in the queue.
+    Queued,
+    /// Transaction was forwarded to the leader.
+    Forwarded,
+    /// Transaction is a part of the block which is currently in discussion.
+    InVotingBlock,
+    /// Transaction was committed in the block with the given height.
+    Committed(u64),
+    /// Transaction was rejected in the block with the given height.
+    Rejected(u64, TransactionRejectionReason),
+    /// Transaction is not known to the peer.
+    Unknown,
+}
+
+impl TransactionStatus {
+    /// Returns `true` if the transaction was either committed or rejected.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            TransactionStatus::Committed(_) | TransactionStatus::Rejected(_, _)
+        )
     }
 }
 
@@ -197,6 +491,15 @@ impl From<&AcceptedTransaction> for RequestedTransaction {
     }
 }
 
+impl From<AcceptedTransaction> for SignedTransaction {
+    fn from(transaction: AcceptedTransaction) -> SignedTransaction {
+        SignedTransaction {
+            payload: transaction.payload,
+            signatures: transaction.signatures,
+        }
+    }
+}
+
 impl From<&SignedTransaction> for RequestedTransaction {
     fn from(transaction: &SignedTransaction) -> RequestedTransaction {
         let transaction = transaction.clone();
@@ -222,3 +525,492 @@ impl From<&ValidTransaction> for RequestedTransaction {
         }
     }
 }
+
+/// Query module provides `IrohaQuery` Transaction related implementations.
+pub mod query {
+    use super::*;
+    use crate::{
+        permission::isi::PermissionInstruction,
+        query::{IrohaQuery, QueryError},
+    };
+    use iroha_derive::{log, IntoQuery};
+
+    /// To get the status of a transaction by its hash, `GetTransactionStatus` query can be used.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct GetTransactionStatus {
+        hash: Hash,
+    }
+
+    /// Result of the `GetTransactionStatus` execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct GetTransactionStatusResult {
+        /// Status of the requested transaction.
+        pub status: TransactionStatus,
+    }
+
+    impl GetTransactionStatus {
+        /// Build a `GetTransactionStatus` query in the form of a `QueryRequest`.
+        pub fn build_request(hash: Hash) -> QueryRequest {
+            let query = GetTransactionStatus { hash };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for GetTransactionStatus {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            Ok(QueryResult::GetTransactionStatus(
+                GetTransactionStatusResult {
+                    status: world_state_view.transaction_status(&self.hash),
+                },
+            ))
+        }
+    }
+
+    /// The maximum number of transactions returned by a single page of transactions history.
+    pub const MAX_TRANSACTIONS_PAGE_SIZE: u32 = 100;
+
+    /// To get committed transactions created by an account in descending time order,
+    /// `GetAccountTransactions` query can be used.
+    ///
+    /// Results are paged - `cursor` is the hash of the last transaction of the previous page
+    /// (`None` for the first page) and `limit` is capped by `MAX_TRANSACTIONS_PAGE_SIZE`.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct GetAccountTransactions {
+        account_id: <Account as Identifiable>::Id,
+        cursor: Option<Hash>,
+        limit: u32,
+    }
+
+    /// To get committed transactions which touched an asset in descending time order,
+    /// `GetAccountAssetTransactions` query can be used.
+    ///
+    /// Paging works the same way as for `GetAccountTransactions`.
+    #[derive(Clone, Debug, Io, IntoQuery, Encode, Decode)]
+    pub struct GetAccountAssetTransactions {
+        asset_id: <Asset as Identifiable>::Id,
+        cursor: Option<Hash>,
+        limit: u32,
+    }
+
+    /// Result of the transactions history queries execution.
+    #[derive(Debug, Encode, Decode)]
+    pub struct TransactionsPage {
+        /// Transactions of the page in descending time order.
+        pub transactions: Vec<ValidTransaction>,
+        /// Cursor to request the next page with, `None` if this page is the last one.
+        pub next_cursor: Option<Hash>,
+    }
+
+    impl GetAccountTransactions {
+        /// Build a `GetAccountTransactions` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            account_id: <Account as Identifiable>::Id,
+            cursor: Option<Hash>,
+            limit: u32,
+        ) -> QueryRequest {
+            let query = GetAccountTransactions {
+                account_id,
+                cursor,
+                limit,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for GetAccountTransactions {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let (transactions, next_cursor) = world_state_view
+                .account_transactions(
+                    &self.account_id,
+                    self.cursor.as_ref(),
+                    self.limit.min(MAX_TRANSACTIONS_PAGE_SIZE) as usize,
+                )
+                .map_err(QueryError::Malformed)?;
+            Ok(QueryResult::GetAccountTransactions(TransactionsPage {
+                transactions,
+                next_cursor,
+            }))
+        }
+
+        fn read_permission(
+            &self,
+            authority: <Account as Identifiable>::Id,
+        ) -> PermissionInstruction {
+            PermissionInstruction::CanReadAccount(authority, self.account_id.clone())
+        }
+    }
+
+    impl GetAccountAssetTransactions {
+        /// Build a `GetAccountAssetTransactions` query in the form of a `QueryRequest`.
+        pub fn build_request(
+            asset_id: <Asset as Identifiable>::Id,
+            cursor: Option<Hash>,
+            limit: u32,
+        ) -> QueryRequest {
+            let query = GetAccountAssetTransactions {
+                asset_id,
+                cursor,
+                limit,
+            };
+            QueryRequest::new(query.into())
+        }
+    }
+
+    impl Query for GetAccountAssetTransactions {
+        #[log]
+        fn execute(&self, world_state_view: &WorldStateView) -> Result<QueryResult, QueryError> {
+            let (transactions, next_cursor) = world_state_view
+                .asset_transactions(
+                    &self.asset_id,
+                    self.cursor.as_ref(),
+                    self.limit.min(MAX_TRANSACTIONS_PAGE_SIZE) as usize,
+                )
+                .map_err(QueryError::Malformed)?;
+            Ok(QueryResult::GetAccountAssetTransactions(TransactionsPage {
+                transactions,
+                next_cursor,
+            }))
+        }
+
+        fn read_permission(
+            &self,
+            authority: <Account as Identifiable>::Id,
+        ) -> PermissionInstruction {
+            PermissionInstruction::CanReadAccount(authority, self.asset_id.account_id.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        block::BlockHeader,
+        isi::fixtures::*,
+        query::QueryError,
+        tx::query::{GetAccountAssetTransactions, GetAccountTransactions},
+    };
+
+    fn notification() -> Instruction {
+        Instruction::Notify("Test".to_string())
+    }
+
+    /// Commits `transactions_number` transactions minting `xor` to the `root` account in blocks
+    /// of `block_size` transactions. Returns their hashes in descending time order.
+    async fn commit_xor_mints(
+        world_state_view: &mut WorldStateView,
+        (public_key, private_key): (PublicKey, PrivateKey),
+        transactions_number: u32,
+        block_size: usize,
+    ) -> Vec<Hash> {
+        let transactions: Vec<ValidTransaction> = (1..=transactions_number)
+            .map(|quantity| {
+                RequestedTransaction::new(
+                    vec![mint_xor(quantity)],
+                    AccountId::new("root", "global"),
+                )
+                .accept()
+                .expect("Failed to accept transaction.")
+                .sign(&public_key, &private_key)
+                .expect("Failed to sign transaction.")
+                .validate(&mut world_state_view.clone())
+                .expect("Failed to validate transaction.")
+            })
+            .collect();
+        for (height, transactions) in transactions.chunks(block_size).enumerate() {
+            let block = CommittedBlock {
+                header: BlockHeader {
+                    timestamp: 0,
+                    height: height as u64,
+                    previous_block_hash: [0; 32],
+                    merkle_root_hash: [0; 32],
+                    state_root_hash: [0; 32],
+                },
+                transactions: transactions.to_vec(),
+                rejected_transactions: Vec::new(),
+                signatures: Vec::new(),
+            };
+            commit_block(world_state_view, block).await;
+        }
+        transactions
+            .iter()
+            .rev()
+            .map(ValidTransaction::hash)
+            .collect()
+    }
+
+    /// Hashes of the transactions of the page returned by the request and its next cursor.
+    fn page_hashes(
+        request: QueryRequest,
+        world_state_view: &WorldStateView,
+    ) -> Result<(Vec<Hash>, Option<Hash>), QueryError> {
+        match request.query.execute(world_state_view)? {
+            QueryResult::GetAccountTransactions(page)
+            | QueryResult::GetAccountAssetTransactions(page) => Ok((
+                page.transactions
+                    .iter()
+                    .map(ValidTransaction::hash)
+                    .collect(),
+                page.next_cursor,
+            )),
+            result => panic!("Unexpected query result: {:?}", result),
+        }
+    }
+
+    fn root_xor_id() -> AssetId {
+        AssetId {
+            definition_id: AssetDefinitionId::new("xor", "global"),
+            account_id: AccountId::new("root", "global"),
+        }
+    }
+
+    #[test]
+    fn failed_transaction_should_not_change_world_state_view() {
+        let (public_key, private_key) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = world_state_view_with_root(public_key);
+        let transaction = RequestedTransaction::new(
+            vec![mint_xor(100), mint_unknown_asset()],
+            AccountId::new("root", "global"),
+        )
+        .accept()
+        .expect("Failed to accept transaction.")
+        .sign(&public_key, &private_key)
+        .expect("Failed to sign transaction.");
+        assert!(transaction.validate(&mut world_state_view).is_err());
+        assert_eq!(None, xor_quantity(&mut world_state_view));
+    }
+
+    #[test]
+    fn rejected_transaction_should_keep_hash_and_reason() {
+        let (public_key, private_key) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = world_state_view_with_root(public_key);
+        let transaction =
+            RequestedTransaction::new(vec![mint_unknown_asset()], AccountId::new("root", "global"))
+                .accept()
+                .expect("Failed to accept transaction.");
+        let hash = transaction.hash();
+        let rejected_transaction = transaction
+            .sign(&public_key, &private_key)
+            .expect("Failed to sign transaction.")
+            .validate(&mut world_state_view)
+            .expect_err("Transaction should be rejected.");
+        assert_eq!(hash, rejected_transaction.hash());
+        match rejected_transaction.rejection_reason {
+            TransactionRejectionReason::InstructionExecutionFailed(_) => (),
+            reason => panic!("Unexpected rejection reason: {}", reason),
+        }
+    }
+
+    #[test]
+    fn failed_condition_of_if_instruction_should_be_rolled_back() {
+        let (public_key, _) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = world_state_view_with_root(public_key);
+        let instruction = Instruction::If(
+            Box::new(Instruction::Compose(
+                Box::new(mint_xor(100)),
+                Box::new(mint_unknown_asset()),
+            )),
+            Box::new(notification()),
+            Some(Box::new(mint_xor(1))),
+        );
+        instruction
+            .execute(AccountId::new("root", "global"), &mut world_state_view)
+            .expect("Failed to execute instruction.");
+        assert_eq!(Some(1), xor_quantity(&mut world_state_view));
+    }
+
+    #[test]
+    fn transaction_within_limits_should_pass() {
+        let transaction = RequestedTransaction::new(
+            vec![notification()],
+            <Account as Identifiable>::Id::new("root", "global"),
+        );
+        assert_eq!(
+            Ok(()),
+            transaction.check_limits(100, &TransactionLimits::default())
+        );
+    }
+
+    #[test]
+    fn transaction_without_instructions_should_be_rejected() {
+        let transaction = RequestedTransaction::new(
+            Vec::new(),
+            <Account as Identifiable>::Id::new("root", "global"),
+        );
+        assert_eq!(
+            Err(TransactionRejectionReason::NoInstructions),
+            transaction.check_limits(100, &TransactionLimits::default())
+        );
+    }
+
+    #[test]
+    fn too_large_transaction_should_be_rejected() {
+        let transaction = RequestedTransaction::new(
+            vec![notification()],
+            <Account as Identifiable>::Id::new("root", "global"),
+        );
+        let limits = TransactionLimits {
+            max_transaction_size: 10,
+            ..TransactionLimits::default()
+        };
+        assert_eq!(
+            Err(TransactionRejectionReason::TransactionTooLarge(100, 10)),
+            transaction.check_limits(100, &limits)
+        );
+    }
+
+    #[test]
+    fn too_deep_transaction_should_be_rejected() {
+        let instruction = Instruction::Compose(
+            Box::new(Instruction::Compose(
+                Box::new(notification()),
+                Box::new(notification()),
+            )),
+            Box::new(notification()),
+        );
+        let transaction = RequestedTransaction::new(
+            vec![instruction],
+            <Account as Identifiable>::Id::new("root", "global"),
+        );
+        let limits = TransactionLimits {
+            max_instruction_depth: 2,
+            ..TransactionLimits::default()
+        };
+        assert_eq!(
+            Err(TransactionRejectionReason::InstructionTooDeep(3, 2)),
+            transaction.check_limits(100, &limits)
+        );
+    }
+
+    #[test]
+    fn too_deep_transaction_should_be_rejected_while_decoding() {
+        let instruction = (0..64).fold(notification(), |instruction, _| {
+            Instruction::Compose(Box::new(instruction), Box::new(notification()))
+        });
+        let transaction = RequestedTransaction::new(
+            vec![instruction],
+            <Account as Identifiable>::Id::new("root", "global"),
+        );
+        let bytes: Vec<u8> = transaction.into();
+        match RequestedTransaction::decode_within_limits(&bytes, &TransactionLimits::default()) {
+            Err(TransactionRejectionReason::MalformedTransaction(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+        let limits = TransactionLimits {
+            max_instruction_depth: 128,
+            ..TransactionLimits::default()
+        };
+        assert!(RequestedTransaction::decode_within_limits(&bytes, &limits).is_ok());
+    }
+
+    #[test]
+    fn too_large_transaction_should_be_rejected_before_decoding() {
+        let limits = TransactionLimits {
+            max_transaction_size: 10,
+            ..TransactionLimits::default()
+        };
+        match RequestedTransaction::decode_within_limits(&[0; 11], &limits) {
+            Err(TransactionRejectionReason::TransactionTooLarge(11, 10)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn transaction_with_malformed_account_id_should_be_rejected() {
+        let transaction = RequestedTransaction::new(
+            vec![notification()],
+            <Account as Identifiable>::Id::new("ro ot", ""),
+        );
+        assert_eq!(
+            Err(TransactionRejectionReason::MalformedAccountId(
+                "ro ot@".to_string()
+            )),
+            transaction.check_limits(100, &TransactionLimits::default())
+        );
+        let transaction = RequestedTransaction::new(
+            vec![notification()],
+            <Account as Identifiable>::Id::new("r\u{43e}\u{43e}t", "global"),
+        );
+        assert!(transaction
+            .check_limits(100, &TransactionLimits::default())
+            .is_err());
+    }
+
+    #[async_std::test]
+    async fn transactions_history_pages_should_cross_block_boundaries() {
+        let key_pair = crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = world_state_view_with_root(key_pair.0);
+        let hashes = commit_xor_mints(&mut world_state_view, key_pair, 6, 2).await;
+        let requests: Vec<fn(Option<Hash>, u32) -> QueryRequest> = vec![
+            |cursor, limit| {
+                GetAccountTransactions::build_request(
+                    AccountId::new("root", "global"),
+                    cursor,
+                    limit,
+                )
+            },
+            |cursor, limit| {
+                GetAccountAssetTransactions::build_request(root_xor_id(), cursor, limit)
+            },
+        ];
+        for request in requests {
+            let (page, cursor) = page_hashes(request(None, 3), &world_state_view)
+                .expect("Failed to get the first page.");
+            assert_eq!(page, hashes[..3].to_vec());
+            assert_eq!(cursor, Some(hashes[2]));
+            let (page, cursor) = page_hashes(request(cursor, 3), &world_state_view)
+                .expect("Failed to get the last page.");
+            assert_eq!(page, hashes[3..].to_vec());
+            assert_eq!(cursor, None);
+        }
+    }
+
+    #[async_std::test]
+    async fn transactions_history_with_unknown_cursor_should_be_rejected() {
+        let key_pair = crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = world_state_view_with_root(key_pair.0);
+        commit_xor_mints(&mut world_state_view, key_pair, 2, 1).await;
+        let requests = vec![
+            GetAccountTransactions::build_request(
+                AccountId::new("root", "global"),
+                Some([1; 32]),
+                10,
+            ),
+            GetAccountAssetTransactions::build_request(root_xor_id(), Some([1; 32]), 10),
+        ];
+        for request in requests {
+            match page_hashes(request, &world_state_view) {
+                Err(QueryError::Malformed(_)) => (),
+                result => panic!("Unexpected query result: {:?}", result),
+            }
+        }
+    }
+
+    #[async_std::test]
+    async fn transactions_history_page_size_should_be_capped() {
+        let key_pair = crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = world_state_view_with_root(key_pair.0);
+        let transactions_number = query::MAX_TRANSACTIONS_PAGE_SIZE + 5;
+        let hashes =
+            commit_xor_mints(&mut world_state_view, key_pair, transactions_number, 50).await;
+        let request = |cursor| {
+            GetAccountTransactions::build_request(AccountId::new("root", "global"), cursor, 1000)
+        };
+        let (page, cursor) =
+            page_hashes(request(None), &world_state_view).expect("Failed to get the first page.");
+        assert_eq!(page.len(), query::MAX_TRANSACTIONS_PAGE_SIZE as usize);
+        assert_eq!(cursor, Some(hashes[page.len() - 1]));
+        let (page, cursor) =
+            page_hashes(request(cursor), &world_state_view).expect("Failed to get the last page.");
+        assert_eq!(
+            page,
+            hashes[query::MAX_TRANSACTIONS_PAGE_SIZE as usize..].to_vec()
+        );
+        assert_eq!(cursor, None);
+    }
+}
@@ -1,33 +1,526 @@
 //! This module provides `WorldStateView` - in-memory representations of the current blockchain
 //! state.
 
-use crate::prelude::*;
+use crate::{
+    block::SignedBlockHeader,
+    config, crypto,
+    kura::CommittedBlocks,
+    merkle::{MerkleProof, MerkleTree},
+    prelude::*,
+    query::{QueryError, QueryLimits},
+    tx::TransactionStatus,
+};
+use parity_scale_codec::{Decode, Encode};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Time after which the status of a transaction which is not committed is forgotten.
+const PENDING_TRANSACTION_STATUS_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Rules of retaining snapshots of the world state, which historical queries are answered from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapshotRetention {
+    /// Number of committed blocks between two consecutive snapshots.
+    pub snapshot_interval: u64,
+    /// Maximum number of retained snapshots. The state before the oldest of them is pruned.
+    pub max_snapshots: u64,
+}
+
+impl Default for SnapshotRetention {
+    fn default() -> Self {
+        SnapshotRetention {
+            snapshot_interval: config::DEFAULT_WSV_SNAPSHOT_INTERVAL,
+            max_snapshots: config::DEFAULT_WSV_MAX_SNAPSHOTS,
+        }
+    }
+}
+
+/// Limits of the key-value stores of the world state entities, which keep the state bounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StoreLimits {
+    /// Maximum number of entries in one store.
+    pub max_entries: u64,
+    /// Maximum length of a key in bytes.
+    pub max_key_length: u64,
+    /// Maximum length of a value in bytes.
+    pub max_value_length: u64,
+}
+
+impl StoreLimits {
+    /// Checks that `value` can be set by `key` in the `store` without exceeding the limits.
+    pub fn check(
+        &self,
+        store: &BTreeMap<String, String>,
+        key: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        self.check_entry(key, value)?;
+        if !store.contains_key(key) && store.len() as u64 >= self.max_entries {
+            return Err(format!(
+                "Store already has {} entries, while maximum is {}.",
+                store.len(),
+                self.max_entries
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that the whole `store`, like the metadata of an entity supplied at its
+    /// registration, does not exceed the limits.
+    pub fn check_store(&self, store: &BTreeMap<String, String>) -> Result<(), String> {
+        if store.len() as u64 > self.max_entries {
+            return Err(format!(
+                "Store has {} entries, while maximum is {}.",
+                store.len(),
+                self.max_entries
+            ));
+        }
+        store
+            .iter()
+            .try_for_each(|(key, value)| self.check_entry(key, value))
+    }
+
+    fn check_entry(&self, key: &str, value: &str) -> Result<(), String> {
+        if key.len() as u64 > self.max_key_length {
+            return Err(format!(
+                "Key is {} bytes long, while maximum is {}.",
+                key.len(),
+                self.max_key_length
+            ));
+        }
+        if value.len() as u64 > self.max_value_length {
+            return Err(format!(
+                "Value is {} bytes long, while maximum is {}.",
+                value.len(),
+                self.max_value_length
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sets `value` by `key` in the `store` if it does not exceed the limits.
+    pub fn insert(
+        &self,
+        store: &mut BTreeMap<String, String>,
+        key: String,
+        value: String,
+    ) -> Result<(), String> {
+        self.check(store, &key, &value)?;
+        store.insert(key, value);
+        Ok(())
+    }
+}
+
+impl Default for StoreLimits {
+    fn default() -> Self {
+        StoreLimits {
+            max_entries: config::DEFAULT_STORE_MAX_ENTRIES,
+            max_key_length: config::DEFAULT_STORE_MAX_KEY_LENGTH,
+            max_value_length: config::DEFAULT_STORE_MAX_VALUE_LENGTH,
+        }
+    }
+}
+
+/// Entry of the world state which the state root commits to - a leaf of the state Merkle tree.
+#[derive(Clone, Debug, Encode, Decode)]
+pub enum StateEntry {
+    /// Domain with its asset definitions and metadata.
+    Domain(String, Vec<AssetDefinition>, BTreeMap<String, String>),
+    /// Account with its signatories and metadata.
+    Account(
+        <Account as Identifiable>::Id,
+        Vec<PublicKey>,
+        BTreeMap<String, String>,
+    ),
+    /// Asset of an account.
+    Asset(Asset),
+}
+
+impl StateEntry {
+    /// Calculate hash of the entry, which is used as a leaf of the state Merkle tree.
+    pub fn hash(&self) -> Hash {
+        crypto::hash(self.encode())
+    }
+}
+
+/// Proof of inclusion of a `StateEntry` into the world state with a known state root hash.
+#[derive(Clone, Debug, Encode, Decode)]
+pub struct StateProof {
+    /// Proven entry.
+    pub entry: StateEntry,
+    /// Proof of inclusion of the entry's hash into the state Merkle tree.
+    pub proof: MerkleProof,
+}
+
+impl StateProof {
+    /// Checks that the entry belongs to the world state with the given `state_root_hash`, which
+    /// should be taken from a `BlockHeader` signed by enough peers.
+    pub fn verify(&self, state_root_hash: &Hash) -> bool {
+        self.proof.leaf_hash == self.entry.hash() && self.proof.verify(state_root_hash)
+    }
+}
+
+/// Location of a transaction in the committed blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionLocation {
+    /// Index of the block and index of the transaction among the valid ones of the block.
+    Committed(usize, usize),
+    /// Index of the block and index of the transaction among the rejected ones of the block.
+    Rejected(usize, usize),
+}
+
+/// Value of a part of the world state before it was first modified inside of
+/// `WorldStateView::atomically`, which is put back if the changes are rolled back.
+#[derive(Debug, Clone)]
+enum UndoEntry {
+    Peer(Box<Peer>),
+    Domain(String, Option<Domain>),
+    Account(Account),
+    AssetDefinition(AssetDefinition),
+}
+
+impl UndoEntry {
+    /// Checks if putting back this entry restores the domain with the given `name`.
+    fn covers_domain(&self, name: &str) -> bool {
+        match self {
+            UndoEntry::Peer(_) => true,
+            UndoEntry::Domain(domain_name, _) => domain_name == name,
+            _ => false,
+        }
+    }
+}
+
+/// Indexes of the transactions of the committed blocks and the retained snapshots of the state.
+/// Copies of the `WorldStateView` share it instead of cloning it.
+#[derive(Debug, Clone, Default)]
+struct History {
+    /// Locations of committed and rejected transactions by their hashes.
+    transaction_locations: BTreeMap<Hash, TransactionLocation>,
+    /// Locations (block index, transaction index) of committed transactions by their creators.
+    /// Entries stay after the creator is unregistered, as the blocks themselves do.
+    account_transactions: BTreeMap<<Account as Identifiable>::Id, Vec<(usize, usize)>>,
+    /// Locations (block index, transaction index) of committed transactions by touched assets.
+    /// Entries stay after the asset is removed, as the blocks themselves do.
+    asset_transactions: BTreeMap<<Asset as Identifiable>::Id, Vec<(usize, usize)>>,
+    /// Snapshots of the `peer` by the number of blocks applied before they were taken.
+    snapshots: BTreeMap<usize, Peer>,
+}
 
 /// Current state of the blockchain alligned with `Iroha` module.
 #[derive(Debug, Clone)]
 pub struct WorldStateView {
     peer: Peer,
-    blocks: Vec<CommittedBlock>,
+    history: Arc<History>,
+    /// Committed blocks stored by `Kura`, which block and transaction lookups are served from.
+    committed_blocks: CommittedBlocks,
+    /// Number of the committed blocks this view sees, it is less than the number of committed
+    /// blocks for the views of the past states returned by `at_height`.
+    blocks_number: usize,
+    /// Statuses of transactions which are known to the peer, but not committed yet, with the time
+    /// they were set.
+    pending_transactions: BTreeMap<Hash, (TransactionStatus, Instant)>,
+    /// Hashes of `pending_transactions` in order of setting their statuses, used to evict the
+    /// statuses older than `PENDING_TRANSACTION_STATUS_TTL`.
+    pending_transactions_order: VecDeque<(Instant, Hash)>,
+    snapshot_retention: SnapshotRetention,
+    store_limits: StoreLimits,
+    query_limits: QueryLimits,
+    /// Public keys of the signatures of the transaction which instructions are being executed.
+    transaction_signatories: Vec<PublicKey>,
+    /// Values of the parts of the `peer` modified inside of `atomically`, in order of modification.
+    undo_log: Vec<UndoEntry>,
+    /// Lengths of the `undo_log` at the starts of the nested `atomically` calls.
+    savepoints: Vec<usize>,
 }
 
 impl WorldStateView {
     /// Default `WorldStateView` constructor.
     pub fn new(peer: Peer) -> Self {
+        WorldStateView::with_snapshot_retention(peer, SnapshotRetention::default())
+    }
+
+    /// `WorldStateView` constructor with the given rules of retaining snapshots for historical
+    /// queries.
+    pub fn with_snapshot_retention(peer: Peer, snapshot_retention: SnapshotRetention) -> Self {
+        let mut history = History::default();
+        history.snapshots.insert(0, peer.clone());
         WorldStateView {
             peer,
-            blocks: Vec::new(),
+            history: Arc::new(history),
+            committed_blocks: CommittedBlocks::default(),
+            blocks_number: 0,
+            pending_transactions: BTreeMap::new(),
+            pending_transactions_order: VecDeque::new(),
+            snapshot_retention,
+            store_limits: StoreLimits::default(),
+            query_limits: QueryLimits::default(),
+            transaction_signatories: Vec::new(),
+            undo_log: Vec::new(),
+            savepoints: Vec::new(),
         }
     }
 
+    /// Set the committed blocks stored by `Kura` - will overwrite the default empty ones. Blocks
+    /// should be stored there before they are put into the world state.
+    pub fn with_committed_blocks(mut self, committed_blocks: CommittedBlocks) -> Self {
+        self.committed_blocks = committed_blocks;
+        self
+    }
+
+    /// Committed blocks which block and transaction lookups are served from.
+    pub fn committed_blocks(&self) -> &CommittedBlocks {
+        &self.committed_blocks
+    }
+
+    /// Set limits of the key-value stores of the world state entities - will overwrite the
+    /// default ones.
+    pub fn with_store_limits(mut self, store_limits: StoreLimits) -> Self {
+        self.store_limits = store_limits;
+        self
+    }
+
+    /// Limits of the key-value stores of the world state entities.
+    pub fn store_limits(&self) -> StoreLimits {
+        self.store_limits
+    }
+
+    /// Set limits of the list and aggregate query results - will overwrite the default ones.
+    pub fn with_query_limits(mut self, query_limits: QueryLimits) -> Self {
+        self.query_limits = query_limits;
+        self
+    }
+
+    /// Limits of the list and aggregate query results.
+    pub fn query_limits(&self) -> QueryLimits {
+        self.query_limits
+    }
+
+    /// Executes `f` on the `WorldStateView` atomically - if `f` fails, all changes made by it
+    /// are rolled back and the state stays the same as before the call.
+    ///
+    /// The state is not copied up front, instead the accessors with an ability to modify the
+    /// state keep the original value of the modified part in an undo log. Nested calls only mark
+    /// a position in this log.
+    pub fn atomically<T, F>(&mut self, f: F) -> Result<T, String>
+    where
+        F: FnOnce(&mut WorldStateView) -> Result<T, String>,
+    {
+        self.savepoints.push(self.undo_log.len());
+        let result = f(self);
+        let savepoint = self.savepoints.pop().expect("Failed to get the savepoint.");
+        if result.is_err() {
+            while self.undo_log.len() > savepoint {
+                let entry = self.undo_log.pop().expect("Failed to get the undo entry.");
+                self.undo(entry);
+            }
+        }
+        if self.savepoints.is_empty() {
+            self.undo_log.clear();
+        }
+        result
+    }
+
+    /// Puts back the value kept in the undo `entry`.
+    fn undo(&mut self, entry: UndoEntry) {
+        match entry {
+            UndoEntry::Peer(peer) => self.peer = *peer,
+            UndoEntry::Domain(name, Some(domain)) => {
+                self.peer.domains.insert(name, domain);
+            }
+            UndoEntry::Domain(name, None) => {
+                self.peer.domains.remove(&name);
+            }
+            UndoEntry::Account(account) => {
+                if let Some(domain) = self.peer.domains.get_mut(&account.id.domain_name) {
+                    domain.accounts.insert(account.id.clone(), account);
+                }
+            }
+            UndoEntry::AssetDefinition(asset_definition) => {
+                if let Some(domain) = self.peer.domains.get_mut(&asset_definition.id.domain_name) {
+                    domain
+                        .asset_definitions
+                        .insert(asset_definition.id.clone(), asset_definition);
+                }
+            }
+        }
+    }
+
+    /// Checks if the current `atomically` call either does not exist or already keeps an undo
+    /// entry satisfying `is_covered`, so the modified part should not be kept again.
+    fn is_undo_kept(&self, is_covered: impl Fn(&UndoEntry) -> bool) -> bool {
+        match self.savepoints.last() {
+            Some(&savepoint) => self.undo_log[savepoint..].iter().any(is_covered),
+            None => true,
+        }
+    }
+
+    fn keep_domain_undo(&mut self, name: &str) {
+        if !self.is_undo_kept(|entry| entry.covers_domain(name)) {
+            let domain = self.peer.domains.get(name).cloned();
+            self.undo_log
+                .push(UndoEntry::Domain(name.to_string(), domain));
+        }
+    }
+
+    fn keep_account_undo(&mut self, id: &<Account as Identifiable>::Id) {
+        if !self.is_undo_kept(|entry| match entry {
+            UndoEntry::Account(account) => account.id == *id,
+            entry => entry.covers_domain(&id.domain_name),
+        }) {
+            if let Some(account) = self.read_account(id).cloned() {
+                self.undo_log.push(UndoEntry::Account(account));
+            }
+        }
+    }
+
+    fn keep_asset_definition_undo(&mut self, id: &<AssetDefinition as Identifiable>::Id) {
+        if !self.is_undo_kept(|entry| match entry {
+            UndoEntry::AssetDefinition(asset_definition) => asset_definition.id == *id,
+            entry => entry.covers_domain(&id.domain_name),
+        }) {
+            if let Some(asset_definition) = self.read_asset_definition(id).cloned() {
+                self.undo_log
+                    .push(UndoEntry::AssetDefinition(asset_definition));
+            }
+        }
+    }
+
+    /// Executes `f` atomically on behalf of a transaction signed by the `signatories`, instructions
+    /// can check them through `transaction_signatories`.
+    pub fn atomically_signed_by<T, F>(
+        &mut self,
+        signatories: Vec<PublicKey>,
+        f: F,
+    ) -> Result<T, String>
+    where
+        F: FnOnce(&mut WorldStateView) -> Result<T, String>,
+    {
+        self.transaction_signatories = signatories;
+        let result = self.atomically(f);
+        self.transaction_signatories.clear();
+        result
+    }
+
+    /// Public keys of the signatures of the transaction which instructions are being executed,
+    /// empty if instructions are executed outside of a transaction.
+    pub fn transaction_signatories(&self) -> &[PublicKey] {
+        &self.transaction_signatories
+    }
+
     /// Put `ValidBlock` of information with changes in form of **Iroha Special Instructions**
-    /// into the world.
+    /// into the world. The block should be already stored in the `committed_blocks`.
     pub async fn put(&mut self, block: &CommittedBlock) {
+        self.apply(block);
+        for transaction in &block.transactions {
+            self.pending_transactions.remove(&transaction.hash());
+        }
+        for transaction in &block.rejected_transactions {
+            self.pending_transactions.remove(&transaction.hash());
+        }
+        self.push_block(block);
+        if (self.blocks_number as u64)
+            .is_multiple_of(self.snapshot_retention.snapshot_interval.max(1))
+        {
+            let max_snapshots = self.snapshot_retention.max_snapshots.max(1);
+            let snapshots = &mut Arc::make_mut(&mut self.history).snapshots;
+            snapshots.insert(self.blocks_number, self.peer.clone());
+            while snapshots.len() as u64 > max_snapshots {
+                let oldest = *snapshots
+                    .keys()
+                    .next()
+                    .expect("Failed to get the oldest snapshot.");
+                snapshots.remove(&oldest);
+            }
+        }
+    }
+
+    /// Reconstructs the state right after the block with the given `height` was committed.
+    ///
+    /// Only the blocks after the closest retained snapshot taken before the block are replayed,
+    /// the committed blocks and their indexes are shared with the current state, but the returned
+    /// view does not see the blocks after the given one.
+    /// Returns `Err(QueryError::NotFound)` if the block is not committed yet and
+    /// `Err(QueryError::Pruned)` if the state is already pruned.
+    pub fn at_height(&self, height: u64) -> Result<WorldStateView, QueryError> {
+        let (snapshot_blocks_number, snapshot) = self.snapshot_before(height)?;
+        let blocks_number = height as usize + 1;
+        let mut world_state_view = WorldStateView {
+            peer: snapshot.clone(),
+            history: Arc::clone(&self.history),
+            committed_blocks: self.committed_blocks.clone(),
+            blocks_number,
+            pending_transactions: BTreeMap::new(),
+            pending_transactions_order: VecDeque::new(),
+            snapshot_retention: self.snapshot_retention,
+            store_limits: self.store_limits,
+            query_limits: self.query_limits,
+            transaction_signatories: Vec::new(),
+            undo_log: Vec::new(),
+            savepoints: Vec::new(),
+        };
+        self.committed_blocks.with_first(blocks_number, |blocks| {
+            for block in &blocks[snapshot_blocks_number..] {
+                world_state_view.apply(block);
+            }
+        });
+        Ok(world_state_view)
+    }
+
+    /// Checks that the state right after the block with the given `height` was committed can be
+    /// reconstructed by `at_height`.
+    pub fn check_height(&self, height: u64) -> Result<(), QueryError> {
+        self.snapshot_before(height).map(|_| ())
+    }
+
+    fn snapshot_before(&self, height: u64) -> Result<(usize, &Peer), QueryError> {
+        match self.blocks_number as u64 {
+            0 => {
+                return Err(QueryError::NotFound(
+                    "No blocks are committed yet.".to_string(),
+                ))
+            }
+            blocks_number if height >= blocks_number => {
+                return Err(QueryError::NotFound(format!(
+                    "Block with height {} is not committed yet, the latest height is {}.",
+                    height,
+                    blocks_number - 1
+                )))
+            }
+            _ => (),
+        }
+        self.history
+            .snapshots
+            .range(..=height as usize + 1)
+            .next_back()
+            .map(|(blocks_number, snapshot)| (*blocks_number, snapshot))
+            .ok_or_else(|| {
+                QueryError::Pruned(format!(
+                    "State at height {} is pruned, the oldest available height is {}.",
+                    height,
+                    self.history
+                        .snapshots
+                        .keys()
+                        .next()
+                        .map_or(0, |blocks_number| blocks_number.saturating_sub(1))
+                ))
+            })
+    }
+
+    /// Applies transactions of the block and executes listeners afterwards.
+    fn apply(&mut self, block: &CommittedBlock) {
         for transaction in &block.transactions {
             if let Err(e) = &transaction.proceed(self) {
                 eprintln!("Failed to procced transaction on WSV: {}", e);
             }
         }
-        self.blocks.push(block.clone());
+        self.execute_listeners();
+    }
+
+    /// Executes listeners on the state after the transactions of a block are applied.
+    pub(crate) fn execute_listeners(&mut self) {
         for listener in self.peer.listeners.clone() {
             if let Err(e) = listener.execute(self.peer.authority(), self) {
                 eprintln!("Failed to execute listener on WSV: {}", e);
@@ -35,6 +528,241 @@ impl WorldStateView {
         }
     }
 
+    /// Indexes transactions of the block and makes it visible to the view.
+    fn push_block(&mut self, block: &CommittedBlock) {
+        let history = Arc::make_mut(&mut self.history);
+        let block_index = self.blocks_number;
+        for (transaction_index, transaction) in block.rejected_transactions.iter().enumerate() {
+            history.transaction_locations.insert(
+                transaction.hash(),
+                TransactionLocation::Rejected(block_index, transaction_index),
+            );
+        }
+        for (transaction_index, transaction) in block.transactions.iter().enumerate() {
+            history.transaction_locations.insert(
+                transaction.hash(),
+                TransactionLocation::Committed(block_index, transaction_index),
+            );
+            let location = (block_index, transaction_index);
+            history
+                .account_transactions
+                .entry(transaction.account_id().clone())
+                .or_default()
+                .push(location);
+            for asset_id in transaction.asset_ids() {
+                history
+                    .asset_transactions
+                    .entry(asset_id)
+                    .or_default()
+                    .push(location);
+            }
+        }
+        self.blocks_number += 1;
+    }
+
+    /// Set the status of the transaction with the given `hash` which is not committed yet.
+    ///
+    /// Statuses of transactions which are not committed in `PENDING_TRANSACTION_STATUS_TTL` are
+    /// forgotten.
+    pub fn set_pending_transaction_status(&mut self, hash: Hash, status: TransactionStatus) {
+        let now = Instant::now();
+        while let Some(&(set_at, expired_hash)) = self.pending_transactions_order.front() {
+            if now.duration_since(set_at) < PENDING_TRANSACTION_STATUS_TTL {
+                break;
+            }
+            self.pending_transactions_order.pop_front();
+            if self
+                .pending_transactions
+                .get(&expired_hash)
+                .is_some_and(|(_, last_set_at)| *last_set_at == set_at)
+            {
+                self.pending_transactions.remove(&expired_hash);
+            }
+        }
+        self.pending_transactions.insert(hash, (status, now));
+        self.pending_transactions_order.push_back((now, hash));
+    }
+
+    /// Get the status of the transaction with the given `hash` - the final one from the committed
+    /// blocks or the pending one if the transaction is not committed yet.
+    pub fn transaction_status(&self, hash: &Hash) -> TransactionStatus {
+        match self.transaction_location(hash) {
+            Some(TransactionLocation::Committed(block_index, _)) => self.with_blocks(|blocks| {
+                TransactionStatus::Committed(blocks[block_index].header.height)
+            }),
+            Some(TransactionLocation::Rejected(block_index, transaction_index)) => self
+                .with_blocks(|blocks| {
+                    let block = &blocks[block_index];
+                    TransactionStatus::Rejected(
+                        block.header.height,
+                        block.rejected_transactions[transaction_index]
+                            .rejection_reason
+                            .clone(),
+                    )
+                }),
+            None => self
+                .pending_transactions
+                .get(hash)
+                .map(|(status, _)| status.clone())
+                .unwrap_or(TransactionStatus::Unknown),
+        }
+    }
+
+    /// Get the location of the committed or rejected transaction with the given `hash` in the
+    /// committed blocks.
+    pub fn transaction_location(&self, hash: &Hash) -> Option<TransactionLocation> {
+        self.history
+            .transaction_locations
+            .get(hash)
+            .copied()
+            .filter(|location| match location {
+                TransactionLocation::Committed(block_index, _)
+                | TransactionLocation::Rejected(block_index, _) => {
+                    *block_index < self.blocks_number
+                }
+            })
+    }
+
+    /// Get a page of committed transactions created by the account in descending time order.
+    /// Transactions of unregistered accounts are kept and returned too.
+    ///
+    /// The page starts right after the transaction with the `cursor` hash if it is provided.
+    /// Returns transactions of the page and the cursor for the next page if there is one.
+    pub fn account_transactions(
+        &self,
+        account_id: &<Account as Identifiable>::Id,
+        cursor: Option<&Hash>,
+        limit: usize,
+    ) -> Result<(Vec<ValidTransaction>, Option<Hash>), String> {
+        self.transactions_page(
+            self.history
+                .account_transactions
+                .get(account_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
+            cursor,
+            limit,
+        )
+    }
+
+    /// Get a page of committed transactions which touched the asset in descending time order.
+    /// Transactions with removed assets are kept and returned too.
+    ///
+    /// Paging works the same way as for `account_transactions`.
+    pub fn asset_transactions(
+        &self,
+        asset_id: &<Asset as Identifiable>::Id,
+        cursor: Option<&Hash>,
+        limit: usize,
+    ) -> Result<(Vec<ValidTransaction>, Option<Hash>), String> {
+        self.transactions_page(
+            self.history
+                .asset_transactions
+                .get(asset_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
+            cursor,
+            limit,
+        )
+    }
+
+    fn transactions_page(
+        &self,
+        locations: &[(usize, usize)],
+        cursor: Option<&Hash>,
+        limit: usize,
+    ) -> Result<(Vec<ValidTransaction>, Option<Hash>), String> {
+        let visible =
+            locations.partition_point(|(block_index, _)| *block_index < self.blocks_number);
+        self.with_blocks(|blocks| {
+            let mut transactions = locations[..visible]
+                .iter()
+                .rev()
+                .map(|(block_index, transaction_index)| {
+                    &blocks[*block_index].transactions[*transaction_index]
+                })
+                .peekable();
+            if let Some(cursor) = cursor {
+                transactions
+                    .by_ref()
+                    .find(|transaction| &transaction.hash() == cursor)
+                    .ok_or(format!("Unknown transactions page cursor: {:?}.", cursor))?;
+            }
+            let page: Vec<ValidTransaction> = transactions.by_ref().take(limit).cloned().collect();
+            let next_cursor = match (page.last(), transactions.peek()) {
+                (Some(last), Some(_)) => Some(last.hash()),
+                _ => None,
+            };
+            Ok((page, next_cursor))
+        })
+    }
+
+    /// Root hash of the Merkle tree of the world state entries.
+    ///
+    /// Domains are ordered by name and each of them is followed by its accounts, each account is
+    /// followed by its assets, so the root does not depend on the order of insertions.
+    ///
+    /// The state after the block at some height is committed to by the header of the block, see
+    /// `state_root_header`.
+    pub fn state_root_hash(&self) -> Hash {
+        MerkleTree::from_hashes(self.state_entries().iter().map(StateEntry::hash).collect())
+            .root_hash()
+    }
+
+    /// Header of the committed block which state root commits to this state - the latest block
+    /// this view sees, so proofs of this state can be verified against it.
+    ///
+    /// Returns `None` if no blocks are committed yet.
+    pub fn state_root_header(&self) -> Option<SignedBlockHeader> {
+        self.with_blocks(|blocks| blocks.last().map(CommittedBlock::signed_header))
+    }
+
+    /// Builds proofs of inclusion of the state entries selected by `is_proven` into the current
+    /// state. Proofs are ordered the same way as the state entries.
+    pub fn state_proofs<F>(&self, is_proven: F) -> Vec<StateProof>
+    where
+        F: Fn(&StateEntry) -> bool,
+    {
+        let entries = self.state_entries();
+        let tree = MerkleTree::from_hashes(entries.iter().map(StateEntry::hash).collect());
+        entries
+            .into_iter()
+            .enumerate()
+            .filter(|(_, entry)| is_proven(entry))
+            .filter_map(|(index, entry)| tree.proof(index).map(|proof| StateProof { entry, proof }))
+            .collect()
+    }
+
+    fn state_entries(&self) -> Vec<StateEntry> {
+        let mut domains: Vec<&Domain> = self.peer.domains.values().collect();
+        domains.sort_by(|left, right| left.name.cmp(&right.name));
+        let mut entries = Vec::new();
+        for domain in domains {
+            entries.push(StateEntry::Domain(
+                domain.name.clone(),
+                domain.asset_definitions.values().cloned().collect(),
+                domain.metadata.clone(),
+            ));
+            for account in domain.accounts.values() {
+                entries.push(StateEntry::Account(
+                    account.id.clone(),
+                    account.signatories().to_vec(),
+                    account.metadata.clone(),
+                ));
+                entries.extend(account.assets.values().cloned().map(StateEntry::Asset));
+            }
+        }
+        entries
+    }
+
+    /// Executes `f` on the committed blocks this view sees, ordered by height.
+    pub fn with_blocks<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&[CommittedBlock]) -> R,
+    {
+        self.committed_blocks.with_first(self.blocks_number, f)
+    }
+
     /// Get `Peer` without an ability to modify it.
     pub fn read_peer(&self) -> &Peer {
         &self.peer
@@ -42,14 +770,25 @@ impl WorldStateView {
 
     /// Get `Peer` with an ability to modify it.
     pub fn peer(&mut self) -> &mut Peer {
+        if !self.is_undo_kept(|entry| matches!(entry, UndoEntry::Peer(_))) {
+            self.undo_log
+                .push(UndoEntry::Peer(Box::new(self.peer.clone())));
+        }
         &mut self.peer
     }
 
     /// Add new `Domain` entity.
     pub fn add_domain(&mut self, domain: Domain) {
+        self.keep_domain_undo(&domain.name);
         self.peer.domains.insert(domain.name.clone(), domain);
     }
 
+    /// Remove the `Domain` entity with all of its accounts and asset definitions.
+    pub fn remove_domain(&mut self, name: &str) -> Option<Domain> {
+        self.keep_domain_undo(name);
+        self.peer.domains.remove(name)
+    }
+
     /// Get `Domain` without an ability to modify it.
     pub fn read_domain(&self, name: &str) -> Option<&Domain> {
         self.peer.domains.get(name)
@@ -57,6 +796,7 @@ impl WorldStateView {
 
     /// Get `Domain` with an ability to modify it.
     pub fn domain(&mut self, name: &str) -> Option<&mut Domain> {
+        self.keep_domain_undo(name);
         self.peer.domains.get_mut(name)
     }
 
@@ -67,12 +807,17 @@ impl WorldStateView {
 
     /// Get `Account` with an ability to modify it.
     pub fn account(&mut self, id: &<Account as Identifiable>::Id) -> Option<&mut Account> {
-        self.domain(&id.domain_name)?.accounts.get_mut(id)
+        self.keep_account_undo(id);
+        self.peer
+            .domains
+            .get_mut(&id.domain_name)?
+            .accounts
+            .get_mut(id)
     }
 
     /// Get `Asset` without an ability to modify it.
-    pub fn read_asset(&mut self, id: &<Asset as Identifiable>::Id) -> Option<&mut Asset> {
-        self.account(&id.account_id)?.assets.get_mut(id)
+    pub fn read_asset(&self, id: &<Asset as Identifiable>::Id) -> Option<&Asset> {
+        self.read_account(&id.account_id)?.assets.get(id)
     }
 
     /// Get `Asset` with an ability to modify it.
@@ -81,11 +826,15 @@ impl WorldStateView {
     }
 
     /// Add new `Asset` entity.
-    pub fn add_asset(&mut self, asset: Asset) {
+    ///
+    /// # Errors
+    /// Fails if the account which should hold the asset does not exist.
+    pub fn add_asset(&mut self, asset: Asset) -> Result<(), String> {
         self.account(&asset.id.account_id)
-            .expect("Failed to find an account.")
+            .ok_or_else(|| format!("Failed to find account {:?}.", asset.id.account_id))?
             .assets
             .insert(asset.id.clone(), asset);
+        Ok(())
     }
 
     /// Get `AssetDefinition` without an ability to modify it.
@@ -96,12 +845,48 @@ impl WorldStateView {
         self.read_domain(&id.domain_name)?.asset_definitions.get(id)
     }
 
+    /// Checks the supply invariant of the asset - the sum of the asset's quantities over all
+    /// accounts should be equal to the total minted minus the total burned quantity.
+    ///
+    /// Returns the sum over all accounts if the invariant holds and `Err(String)` if not.
+    pub fn check_asset_supply(
+        &self,
+        id: &<AssetDefinition as Identifiable>::Id,
+    ) -> Result<u128, String> {
+        let asset_definition = self
+            .read_asset_definition(id)
+            .ok_or(format!("No asset definition with id: {:?} found.", id))?;
+        let held = self
+            .peer
+            .domains
+            .values()
+            .flat_map(|domain| domain.accounts.values())
+            .flat_map(|account| account.assets.values())
+            .filter(|asset| &asset.id.definition_id == id)
+            .try_fold(0u128, |held, asset| {
+                held.checked_add(asset.balance()?)
+                    .ok_or(format!("Asset {:?} held by all accounts overflows.", id))
+            })?;
+        if held != asset_definition.circulating()? {
+            return Err(format!(
+                "Asset {:?} supply is broken: accounts hold {}, while {} minted and {} burned.",
+                id, held, asset_definition.total_minted, asset_definition.total_burned
+            ));
+        }
+        Ok(held)
+    }
+
     /// Get `AssetDefinition` with an ability to modify it.
     pub fn asset_definition(
         &mut self,
         id: &<AssetDefinition as Identifiable>::Id,
     ) -> Option<&mut AssetDefinition> {
-        self.domain(&id.domain_name)?.asset_definitions.get_mut(id)
+        self.keep_asset_definition_undo(id);
+        self.peer
+            .domains
+            .get_mut(&id.domain_name)?
+            .asset_definitions
+            .get_mut(id)
     }
 }
 
@@ -110,9 +895,9 @@ mod tests {
     use super::*;
     use crate::{
         block::BlockHeader,
-        peer::{Peer, PeerId},
+        isi::{self, fixtures::*},
     };
-    use std::collections::HashMap;
+    use std::collections::BTreeMap;
 
     #[async_std::test]
     async fn test_listeners() {
@@ -122,59 +907,359 @@ mod tests {
                 height: 0,
                 previous_block_hash: [0; 32],
                 merkle_root_hash: [0; 32],
+                state_root_hash: [0; 32],
             },
             transactions: Vec::new(),
+            rejected_transactions: Vec::new(),
             signatures: Vec::new(),
         };
-        let domain_name = "global".to_string();
-        let mut asset_definitions = HashMap::new();
-        let asset_definition_id = crate::permission::permission_asset_definition_id();
-        asset_definitions.insert(
-            asset_definition_id.clone(),
-            AssetDefinition::new(asset_definition_id.clone()),
+        let mut world_state_view = world_state_view_with_root([0; 32]);
+        let listener = Instruction::If(
+            Box::new(Instruction::Notify("Test".to_string())),
+            Box::new(
+                world_state_view
+                    .read_peer()
+                    .add_domain(Domain::new("Test".to_string()))
+                    .into(),
+            ),
+            None,
         );
-        let public_key = [0; 32];
-        let account_id = AccountId::new("root", &domain_name);
-        let asset_id = AssetId {
-            definition_id: asset_definition_id,
-            account_id: account_id.clone(),
-        };
-        let asset =
-            Asset::with_permission(asset_id.clone(), ("anything".to_string(), "".to_string()));
-        let mut account = Account::new(
-            &account_id.name,
-            &account_id.domain_name,
-            public_key.clone(),
+        world_state_view.peer().add_listener(listener);
+        world_state_view.put(&block).await;
+        assert!(world_state_view.domain("Test").is_some());
+    }
+
+    #[async_std::test]
+    async fn account_transactions_are_paged_in_descending_order() {
+        let (public_key, private_key) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = world_state_view_with_root(public_key);
+        let account_id = add_account(&mut world_state_view, "alice", public_key);
+        let transactions: Vec<ValidTransaction> = (0..5)
+            .map(|i| {
+                RequestedTransaction::new(
+                    vec![Instruction::Notify(format!("Transaction #{}", i))],
+                    account_id.clone(),
+                )
+                .accept()
+                .expect("Failed to accept transaction.")
+                .sign(&public_key, &private_key)
+                .expect("Failed to sign transaction.")
+                .validate(&mut world_state_view)
+                .expect("Failed to validate transaction.")
+            })
+            .collect();
+        for (height, transactions) in transactions.chunks(2).enumerate() {
+            let block = CommittedBlock {
+                header: BlockHeader {
+                    timestamp: 0,
+                    height: height as u64,
+                    previous_block_hash: [0; 32],
+                    merkle_root_hash: [0; 32],
+                    state_root_hash: [0; 32],
+                },
+                transactions: transactions.to_vec(),
+                rejected_transactions: Vec::new(),
+                signatures: Vec::new(),
+            };
+            commit_block(&mut world_state_view, block).await;
+        }
+        let hashes: Vec<Hash> = transactions
+            .iter()
+            .rev()
+            .map(ValidTransaction::hash)
+            .collect();
+        let (page, cursor) = world_state_view
+            .account_transactions(&account_id, None, 2)
+            .expect("Failed to get the first page.");
+        assert_eq!(
+            page.iter().map(ValidTransaction::hash).collect::<Vec<_>>(),
+            hashes[..2].to_vec()
         );
-        account.assets.insert(asset_id.clone(), asset);
-        let mut accounts = HashMap::new();
-        accounts.insert(account_id.clone(), account);
-        let domain = Domain {
-            name: domain_name.clone(),
-            accounts,
-            asset_definitions,
-        };
-        let mut domains = HashMap::new();
-        domains.insert(domain_name.clone(), domain);
-        let address = "127.0.0.1:8080".to_string();
-        let mut peer = Peer::with_domains(
-            PeerId {
-                address: address.clone(),
-                public_key,
+        assert_eq!(cursor, Some(hashes[1]));
+        let (page, cursor) = world_state_view
+            .account_transactions(&account_id, cursor.as_ref(), 2)
+            .expect("Failed to get the second page.");
+        assert_eq!(
+            page.iter().map(ValidTransaction::hash).collect::<Vec<_>>(),
+            hashes[2..4].to_vec()
+        );
+        let (page, cursor) = world_state_view
+            .account_transactions(&account_id, cursor.as_ref(), 2)
+            .expect("Failed to get the last page.");
+        assert_eq!(
+            page.iter().map(ValidTransaction::hash).collect::<Vec<_>>(),
+            hashes[4..].to_vec()
+        );
+        assert_eq!(cursor, None);
+        assert!(world_state_view
+            .account_transactions(&account_id, Some(&[1; 32]), 2)
+            .is_err());
+    }
+
+    #[async_std::test]
+    async fn historical_state_should_be_replayed_from_retained_snapshots() {
+        let (public_key, private_key) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = WorldStateView::with_snapshot_retention(
+            peer_with_root(public_key),
+            SnapshotRetention {
+                snapshot_interval: 2,
+                max_snapshots: 2,
             },
-            &Vec::new(),
-            domains,
         );
-        peer.add_listener(Instruction::If(
-            Box::new(Instruction::Notify("Test".to_string())),
-            Box::new(peer.add_domain(Domain::new("Test".to_string())).into()),
-            None,
+        let root_account_id = AccountId::new("root", "global");
+        let mut hashes = Vec::new();
+        for height in 0..5 {
+            let transaction = RequestedTransaction::new(
+                vec![world_state_view
+                    .read_peer()
+                    .add_domain(Domain::new(format!("domain{}", height)))
+                    .into()],
+                root_account_id.clone(),
+            )
+            .accept()
+            .expect("Failed to accept transaction.")
+            .sign(&public_key, &private_key)
+            .expect("Failed to sign transaction.")
+            .validate(&mut world_state_view.clone())
+            .expect("Failed to validate transaction.");
+            hashes.push(transaction.hash());
+            let block = CommittedBlock {
+                header: BlockHeader {
+                    timestamp: 0,
+                    height,
+                    previous_block_hash: [0; 32],
+                    merkle_root_hash: [0; 32],
+                    state_root_hash: [0; 32],
+                },
+                transactions: vec![transaction],
+                rejected_transactions: Vec::new(),
+                signatures: Vec::new(),
+            };
+            commit_block(&mut world_state_view, block).await;
+        }
+        let state = world_state_view
+            .at_height(2)
+            .expect("Failed to get state at height 2.");
+        assert!(state.read_domain("domain2").is_some());
+        assert!(state.read_domain("domain3").is_none());
+        assert_eq!(state.with_blocks(<[CommittedBlock]>::len), 3);
+        assert!(Arc::ptr_eq(&state.history, &world_state_view.history));
+        assert!(state.transaction_location(&hashes[2]).is_some());
+        assert!(state.transaction_location(&hashes[3]).is_none());
+        assert!(Arc::ptr_eq(
+            &world_state_view.clone().history,
+            &world_state_view.history
         ));
-        let mut world_state_view = WorldStateView {
-            peer,
-            blocks: Vec::new(),
+        let state = world_state_view
+            .at_height(1)
+            .expect("Failed to get state at height 1.");
+        assert!(state.read_domain("domain1").is_some());
+        assert!(state.read_domain("domain2").is_none());
+        match world_state_view.at_height(0) {
+            Err(QueryError::Pruned(_)) => (),
+            result => panic!("Unexpected result: {:?}.", result.map(|_| ())),
+        }
+        match world_state_view.at_height(5) {
+            Err(QueryError::NotFound(_)) => (),
+            result => panic!("Unexpected result: {:?}.", result.map(|_| ())),
+        }
+        let request = crate::domain::query::FindDomainByName::build_request("domain4".to_string());
+        assert!(request
+            .clone()
+            .at_height(3)
+            .execute(&world_state_view)
+            .is_err());
+        assert!(request.at_height(4).execute(&world_state_view).is_ok());
+    }
+
+    #[test]
+    fn failed_nested_atomic_changes_should_be_rolled_back_without_the_outer_ones() {
+        let mut world_state_view = world_state_view_with_root([0; 32]);
+        let root_id = AccountId::new("root", "global");
+        world_state_view
+            .atomically(|world_state_view| {
+                world_state_view
+                    .account(&root_id)
+                    .expect("Failed to find root.")
+                    .metadata
+                    .insert("outer".to_string(), "kept".to_string());
+                let nested: Result<(), String> = world_state_view.atomically(|world_state_view| {
+                    world_state_view
+                        .account(&root_id)
+                        .expect("Failed to find root.")
+                        .metadata
+                        .insert("nested".to_string(), "rolled back".to_string());
+                    world_state_view.add_domain(Domain::new("wonderland".to_string()));
+                    Err("Nested failure.".to_string())
+                });
+                assert!(nested.is_err());
+                Ok(())
+            })
+            .expect("Failed to execute atomically.");
+        let metadata = &world_state_view
+            .read_account(&root_id)
+            .expect("Failed to find root.")
+            .metadata;
+        assert_eq!(Some(&"kept".to_string()), metadata.get("outer"));
+        assert_eq!(None, metadata.get("nested"));
+        assert!(world_state_view.read_domain("wonderland").is_none());
+        let state_root_hash = world_state_view.state_root_hash();
+        let result: Result<(), String> = world_state_view.atomically(|world_state_view| {
+            world_state_view
+                .account(&root_id)
+                .expect("Failed to find root.")
+                .metadata
+                .clear();
+            world_state_view.peer().domains.clear();
+            Err("Outer failure.".to_string())
+        });
+        assert!(result.is_err());
+        assert_eq!(state_root_hash, world_state_view.state_root_hash());
+        assert!(world_state_view.undo_log.is_empty());
+    }
+
+    #[test]
+    fn expired_pending_transaction_statuses_should_be_forgotten() {
+        let mut world_state_view = world_state_view_with_root([0; 32]);
+        let expired_at = match Instant::now().checked_sub(PENDING_TRANSACTION_STATUS_TTL) {
+            Some(expired_at) => expired_at,
+            None => return,
         };
-        world_state_view.put(&block).await;
-        assert!(world_state_view.domain("Test").is_some());
+        world_state_view
+            .pending_transactions
+            .insert([1; 32], (TransactionStatus::Queued, expired_at));
+        world_state_view
+            .pending_transactions_order
+            .push_back((expired_at, [1; 32]));
+        world_state_view.set_pending_transaction_status([2; 32], TransactionStatus::Queued);
+        assert_eq!(
+            TransactionStatus::Unknown,
+            world_state_view.transaction_status(&[1; 32])
+        );
+        assert_eq!(
+            TransactionStatus::Queued,
+            world_state_view.transaction_status(&[2; 32])
+        );
+    }
+
+    #[test]
+    fn state_proofs_should_be_verified_against_the_state_root() {
+        let mut world_state_view = world_state_view_with_root([0; 32]);
+        let root_id = AccountId::new("root", "global");
+        let state_root_hash = world_state_view.state_root_hash();
+        let proofs = world_state_view.state_proofs(|entry| match entry {
+            StateEntry::Account(id, _, _) => *id == root_id,
+            _ => false,
+        });
+        assert_eq!(proofs.len(), 1);
+        assert!(proofs[0].verify(&state_root_hash));
+        let mut forged = proofs[0].clone();
+        forged.entry = StateEntry::Account(root_id.clone(), vec![[1; 32]], BTreeMap::new());
+        assert!(!forged.verify(&state_root_hash));
+        let result = crate::asset::query::GetAccountAssets::build_request_with_proofs(
+            root_id,
+            ListOptions::default(),
+        )
+        .query
+        .execute(&world_state_view)
+        .expect("Failed to execute query.");
+        if let QueryResult::GetAccountAssets(result) = result {
+            assert_eq!(result.proofs.len(), result.assets.len());
+            assert!(result
+                .proofs
+                .iter()
+                .all(|proof| proof.verify(&state_root_hash)));
+        } else {
+            panic!("Wrong Query Result Type.");
+        }
+        world_state_view.add_domain(Domain::new("test".to_string()));
+        assert_ne!(world_state_view.state_root_hash(), state_root_hash);
+        assert!(!proofs[0].verify(&world_state_view.state_root_hash()));
+    }
+
+    #[async_std::test]
+    async fn state_proofs_should_be_verified_against_the_latest_block_header() {
+        let (public_key, private_key) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = world_state_view_with_root(public_key);
+        let root_id = AccountId::new("root", "global");
+        for height in 0..2 {
+            let transaction = RequestedTransaction::new(
+                vec![world_state_view
+                    .read_peer()
+                    .add_domain(Domain::new(format!("Domain #{}", height)))
+                    .into()],
+                root_id.clone(),
+            )
+            .accept()
+            .expect("Failed to accept transaction.");
+            let block = PendingBlock::new(vec![transaction])
+                .chain(height, [0; 32])
+                .with_state_root(&world_state_view)
+                .sign(&public_key, &private_key)
+                .expect("Failed to sign block.")
+                .validate(&world_state_view)
+                .expect("Failed to validate block.")
+                .commit();
+            commit_block(&mut world_state_view, block.clone()).await;
+            assert_eq!(
+                block.header.state_root_hash,
+                world_state_view.state_root_hash()
+            );
+        }
+        let request = crate::account::query::FindAccountById::build_request_with_proof(root_id);
+        for (request, height) in [(request.clone().at_height(0), 0), (request, 1)] {
+            match request.execute(&world_state_view) {
+                Ok(QueryResult::FindAccountById(result)) => {
+                    let state_header = result.state_header.expect("Failed to get state header.");
+                    assert_eq!(state_header.header.height, height);
+                    assert!(state_header.verify_signatures().is_ok());
+                    assert!(result
+                        .proof
+                        .expect("Failed to get proof.")
+                        .verify(&state_header.header.state_root_hash));
+                }
+                _ => panic!("Wrong Query Result Type."),
+            }
+        }
+    }
+
+    #[test]
+    fn adding_and_removing_entities_should_keep_only_the_touched_parts_for_undo() {
+        let (public_key, _) =
+            crate::crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let mut world_state_view = world_state_view_with_root(public_key);
+        let root_id = AccountId::new("root", "global");
+        let alice_id = add_account(&mut world_state_view, "alice", public_key);
+        world_state_view.add_domain(Domain::new("wonderland".to_string()));
+        let peer_id = world_state_view.read_peer().id.clone();
+        let result: Result<(), String> = world_state_view.atomically(|world_state_view| {
+            Instruction::from(isi::Add::<Peer, Domain>::new(
+                Domain::new("looking_glass".to_string()),
+                peer_id.clone(),
+            ))
+            .execute(root_id.clone(), world_state_view)?;
+            Instruction::from(isi::Unregister::<Domain, Account>::new(
+                alice_id.clone(),
+                "global".to_string(),
+            ))
+            .execute(root_id.clone(), world_state_view)?;
+            Instruction::from(isi::Remove::<Peer, Domain>::new(
+                "wonderland".to_string(),
+                peer_id,
+            ))
+            .execute(root_id.clone(), world_state_view)?;
+            assert!(!world_state_view
+                .undo_log
+                .iter()
+                .any(|entry| matches!(entry, UndoEntry::Peer(_))));
+            Err("Rolled back.".to_string())
+        });
+        assert!(result.is_err());
+        assert!(world_state_view.read_account(&alice_id).is_some());
+        assert!(world_state_view.read_domain("wonderland").is_some());
+        assert!(world_state_view.read_domain("looking_glass").is_none());
     }
 }
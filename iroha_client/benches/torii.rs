@@ -9,7 +9,7 @@ use tempfile::TempDir;
 const CONFIGURATION_PATH: &str = "tests/test_config.json";
 
 fn query_requests(criterion: &mut Criterion) {
-    thread::spawn(|| create_and_start_iroha());
+    thread::spawn(create_and_start_iroha);
     thread::sleep(std::time::Duration::from_millis(50));
     let mut group = criterion.benchmark_group("query-reqeuests");
     let configuration =
@@ -21,7 +21,9 @@ fn query_requests(criterion: &mut Criterion) {
     };
     let account_name = "account";
     let account_id = AccountId::new(account_name, domain_name);
-    let (public_key, _) = configuration.key_pair();
+    let public_key = configuration
+        .client_public_key
+        .expect("Failed to get the client public key.");
     let create_account = isi::Register {
         object: Account::new(account_name, domain_name, public_key),
         destination_id: String::from(domain_name),
@@ -49,7 +51,7 @@ fn query_requests(criterion: &mut Criterion) {
         mint_asset.into(),
     ]))
     .expect("Failed to prepare state.");
-    let request = assets::by_account_id(account_id);
+    let request = assets::by_account_id(account_id, ListOptions::default());
     thread::sleep(std::time::Duration::from_millis(1500));
     let mut success_count = 0;
     let mut failures_count = 0;
@@ -58,9 +60,12 @@ fn query_requests(criterion: &mut Criterion) {
         b.iter(
             || match executor::block_on(iroha_client.request(&request)) {
                 Ok(query_result) => {
-                    let QueryResult::GetAccountAssets(result) = query_result;
-                    assert!(!result.assets.is_empty());
-                    success_count += 1;
+                    if let QueryResult::GetAccountAssets(result) = query_result {
+                        assert!(!result.assets.is_empty());
+                        success_count += 1;
+                    } else {
+                        panic!("Wrong Query Result Type.");
+                    }
                 }
                 Err(e) => {
                     eprintln!("Query failed: {}", e);
@@ -77,7 +82,7 @@ fn query_requests(criterion: &mut Criterion) {
 }
 
 fn instruction_submits(criterion: &mut Criterion) {
-    thread::spawn(|| create_and_start_iroha());
+    thread::spawn(create_and_start_iroha);
     thread::sleep(std::time::Duration::from_millis(50));
     let mut group = criterion.benchmark_group("command-reqeuests");
     let configuration =
@@ -89,7 +94,9 @@ fn instruction_submits(criterion: &mut Criterion) {
     };
     let account_name = "account";
     let account_id = AccountId::new(account_name, domain_name);
-    let (public_key, _) = configuration.key_pair();
+    let public_key = configuration
+        .client_public_key
+        .expect("Failed to get the client public key.");
     let create_account = isi::Register {
         object: Account::new(account_name, domain_name, public_key),
         destination_id: String::from(domain_name),
@@ -144,7 +151,9 @@ fn create_and_start_iroha() {
     let iroha = Iroha::new(configuration);
     task::block_on(iroha.start()).expect("Failed to start Iroha.");
     //Prevents temp_dir from clean up untill the end of the tests.
-    loop {}
+    loop {
+        std::thread::park();
+    }
 }
 
 criterion_group!(instructions, instruction_submits);
@@ -1,13 +1,26 @@
-use iroha::{crypto, prelude::*, torii::uri};
+use async_std::task;
+use iroha::{
+    prelude::*,
+    query::QueryError,
+    torii::uri,
+    tx::{TransactionRejectionReason, TransactionStatus},
+};
 use iroha_derive::log;
 use iroha_network::{prelude::*, Network};
+use parity_scale_codec::Decode;
 use std::{
-    convert::{TryFrom, TryInto},
+    convert::TryFrom,
     fmt::{self, Debug, Formatter},
+    time::{Duration, Instant},
 };
 
+/// The interval at which `Client::submit_blocking` and `Client::submit_all_blocking` poll the
+/// status of the submitted transaction.
+const TRANSACTION_STATUS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 pub struct Client {
     torii_url: String,
+    account_id: AccountId,
     public_key: PublicKey,
     private_key: PrivateKey,
 }
@@ -15,6 +28,7 @@ pub struct Client {
 impl Debug for Client {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Client")
+            .field("account_id", &self.account_id)
             .field("public_key", &self.public_key)
             .field("torii_url", &self.torii_url)
             .finish()
@@ -23,54 +37,43 @@ impl Debug for Client {
 
 /// Representation of `Iroha` client.
 impl Client {
+    /// Constructs a client which acts on behalf of the configured `account_id` and signs
+    /// transactions with the configured client key pair.
+    ///
+    /// # Panics
+    /// If `client_public_key` or `client_private_key` is not configured.
     pub fn new(config: &Configuration) -> Self {
-        let (public_key, private_key) =
-            crypto::generate_key_pair().expect("Failed to generate key pair.");
+        let public_key = config
+            .client_public_key
+            .expect("CLIENT_PUBLIC_KEY should be set.");
+        let private_key = config
+            .client_private_key
+            .expect("CLIENT_PRIVATE_KEY should be set.");
         Client {
             torii_url: config.peer_id.address.clone(),
-            public_key: public_key[..]
-                .try_into()
-                .expect("Public key should be [u8;32]"),
+            account_id: config.account_id.clone(),
+            public_key,
             private_key,
         }
     }
 
     /// Contract API entry point. Submits contract to `Iroha` peers.
+    /// Returns `Hash` of the submitted transaction which can be used to track its status.
     #[log]
-    pub async fn submit(&mut self, command: Instruction) -> Result<(), String> {
-        let network = Network::new(&self.torii_url);
-        let transaction: RequestedTransaction =
-            RequestedTransaction::new(vec![command], iroha::account::Id::new("root", "global"))
-                .accept()?
-                .sign(&self.public_key, &self.private_key)?
-                .into();
-        if let Response::InternalError = network
-            .send_request(Request::new(
-                uri::INSTRUCTIONS_URI.to_string(),
-                Vec::from(&transaction),
-            ))
-            .await
-            .map_err(|e| {
-                format!(
-                    "Error: {}, Failed to write a transaction request: {:?}",
-                    e, &transaction
-                )
-            })?
-        {
-            return Err("Server error.".to_string());
-        }
-        Ok(())
+    pub async fn submit(&mut self, command: Instruction) -> Result<Hash, String> {
+        self.submit_all(vec![command]).await
     }
 
     /// Contract API entry point. Submits contracts to `Iroha` peers.
-    pub async fn submit_all(&mut self, commands: Vec<Instruction>) -> Result<(), String> {
+    /// Returns `Hash` of the submitted transaction which can be used to track its status.
+    pub async fn submit_all(&mut self, commands: Vec<Instruction>) -> Result<Hash, String> {
         let network = Network::new(&self.torii_url);
         let transaction: RequestedTransaction =
-            RequestedTransaction::new(commands, iroha::account::Id::new("root", "global"))
+            RequestedTransaction::new(commands, self.account_id.clone())
                 .accept()?
                 .sign(&self.public_key, &self.private_key)?
                 .into();
-        if let Response::InternalError = network
+        match network
             .send_request(Request::new(
                 uri::INSTRUCTIONS_URI.to_string(),
                 Vec::from(&transaction),
@@ -81,17 +84,82 @@ impl Client {
                     "Error: {}, Failed to write a transaction request: {:?}",
                     e, &transaction
                 )
-            })?
-        {
-            return Err("Server error.".to_string());
+            })? {
+            Response::Ok(_) => Ok(transaction.hash()),
+            Response::Rejected(payload) => Err(format!(
+                "Transaction rejected: {}",
+                TransactionRejectionReason::try_from(payload)?
+            )),
+            Response::InternalError => Err("Server error.".to_string()),
         }
-        Ok(())
     }
 
-    /// Query API entry point. Requests queries from `Iroha` peers.
+    /// Contract API entry point. Submits contract to `Iroha` peers and waits until the transaction
+    /// will be committed or rejected, but not longer than `timeout`.
+    /// Returns the final `TransactionStatus` of the submitted transaction.
+    #[log]
+    pub async fn submit_blocking(
+        &mut self,
+        command: Instruction,
+        timeout: Duration,
+    ) -> Result<TransactionStatus, String> {
+        self.submit_all_blocking(vec![command], timeout).await
+    }
+
+    /// Contract API entry point. Submits contracts to `Iroha` peers and waits until the
+    /// transaction will be committed or rejected, but not longer than `timeout`.
+    /// Returns the final `TransactionStatus` of the submitted transaction.
+    pub async fn submit_all_blocking(
+        &mut self,
+        commands: Vec<Instruction>,
+        timeout: Duration,
+    ) -> Result<TransactionStatus, String> {
+        let hash = self.submit_all(commands).await?;
+        self.poll_final_status(hash, timeout).await
+    }
+
+    /// Polls the status of the transaction with the given `hash` until it is final, but not
+    /// longer than `timeout`.
+    async fn poll_final_status(
+        &mut self,
+        hash: Hash,
+        timeout: Duration,
+    ) -> Result<TransactionStatus, String> {
+        let start = Instant::now();
+        loop {
+            let status = self.transaction_status(hash).await?;
+            if status.is_final() {
+                break Ok(status);
+            }
+            if start.elapsed() >= timeout {
+                break Err(format!(
+                    "Transaction was not committed in {:?}, last status: {:?}",
+                    timeout, status
+                ));
+            }
+            task::sleep(TRANSACTION_STATUS_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Transaction status API entry point. Requests the status of the transaction with the given
+    /// `hash` from `Iroha` peers with the `GetTransactionStatus` query, so the configured account
+    /// should have the same permission to read it as for the other queries.
+    pub async fn transaction_status(&mut self, hash: Hash) -> Result<TransactionStatus, String> {
+        match self.request(&transactions::status_by_hash(hash)).await? {
+            QueryResult::GetTransactionStatus(result) => Ok(result.status),
+            _ => Err("Wrong Query Result Type.".to_string()),
+        }
+    }
+
+    /// Query API entry point. Requests queries from `Iroha` peers on behalf of the configured
+    /// account, signing them with the configured key pair.
     #[log]
     pub async fn request(&mut self, request: &QueryRequest) -> Result<QueryResult, String> {
         let network = Network::new(&self.torii_url);
+        let request =
+            request
+                .clone()
+                .sign(self.account_id.clone(), &self.public_key, &self.private_key)?;
         match network
             .send_request(Request::new(uri::QUERY_URI.to_string(), request.into()))
             .await
@@ -100,16 +168,195 @@ impl Client {
             Response::Ok(payload) => Ok(
                 QueryResult::try_from(payload).expect("Failed to try Query Result from vector.")
             ),
+            Response::Rejected(payload) => Err(format!(
+                "Query rejected: {}",
+                QueryError::decode(&mut payload.as_slice()).map_err(|e| e.to_string())?
+            )),
             Response::InternalError => Err("Server error.".to_string()),
         }
     }
 }
 
+pub mod domains {
+    use super::*;
+    use iroha::domain::query::{FindAllDomains, FindDomainByName};
+
+    pub fn all(options: ListOptions) -> QueryRequest {
+        FindAllDomains::build_request(options)
+    }
+
+    pub fn by_name(name: String) -> QueryRequest {
+        FindDomainByName::build_request(name)
+    }
+}
+
+pub mod accounts {
+    use super::*;
+    use iroha::account::query::{FindAccountById, FindAccountsByDomain, FindAllAccounts};
+
+    pub fn all(options: ListOptions) -> QueryRequest {
+        FindAllAccounts::build_request(options)
+    }
+
+    pub fn by_id(account_id: <Account as Identifiable>::Id) -> QueryRequest {
+        FindAccountById::build_request(account_id)
+    }
+
+    pub fn by_id_with_proof(account_id: <Account as Identifiable>::Id) -> QueryRequest {
+        FindAccountById::build_request_with_proof(account_id)
+    }
+
+    pub fn by_domain_name(domain_name: String, options: ListOptions) -> QueryRequest {
+        FindAccountsByDomain::build_request(domain_name, options)
+    }
+}
+
 pub mod assets {
     use super::*;
-    use iroha::asset::query::GetAccountAssets;
+    use iroha::asset::query::{
+        FindAllAssetDefinitions, FindAssetHolders, FindAssetKeyValue, FindAssetsByDefinition,
+        GetAccountAssetCounts, GetAccountAssets, GetAssetBalancesByDomain, GetAssetHolderCount,
+        GetAssetSupply,
+    };
+
+    pub fn by_account_id(
+        account_id: <Account as Identifiable>::Id,
+        options: ListOptions,
+    ) -> QueryRequest {
+        GetAccountAssets::build_request(account_id, options)
+    }
+
+    pub fn by_account_id_with_proofs(
+        account_id: <Account as Identifiable>::Id,
+        options: ListOptions,
+    ) -> QueryRequest {
+        GetAccountAssets::build_request_with_proofs(account_id, options)
+    }
+
+    pub fn key_value_by_id(asset_id: <Asset as Identifiable>::Id, key: &str) -> QueryRequest {
+        FindAssetKeyValue::build_request(asset_id, key)
+    }
+
+    pub fn all_definitions(options: ListOptions) -> QueryRequest {
+        FindAllAssetDefinitions::build_request(options)
+    }
+
+    pub fn by_definition_id(
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+        options: ListOptions,
+    ) -> QueryRequest {
+        FindAssetsByDefinition::build_request(asset_definition_id, options)
+    }
+
+    pub fn supply_by_definition_id(
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+    ) -> QueryRequest {
+        GetAssetSupply::build_request(asset_definition_id)
+    }
+
+    pub fn holders_by_definition_id(
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+        limit: u32,
+    ) -> QueryRequest {
+        FindAssetHolders::build_request(asset_definition_id, limit)
+    }
+
+    pub fn holder_count_by_definition_id(
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+    ) -> QueryRequest {
+        GetAssetHolderCount::build_request(asset_definition_id)
+    }
+
+    pub fn balances_by_domain(
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+        limit: u32,
+    ) -> QueryRequest {
+        GetAssetBalancesByDomain::build_request(asset_definition_id, limit)
+    }
+
+    pub fn counts_by_account(limit: u32) -> QueryRequest {
+        GetAccountAssetCounts::build_request(limit)
+    }
+}
+
+pub mod peers {
+    use super::*;
+    use iroha::peer::query::{FindAllPeers, FindListeners};
+
+    pub fn all(options: ListOptions) -> QueryRequest {
+        FindAllPeers::build_request(options)
+    }
+
+    pub fn listeners(options: ListOptions) -> QueryRequest {
+        FindListeners::build_request(options)
+    }
+}
+
+#[cfg(feature = "bridge")]
+pub mod bridges {
+    use super::*;
+    use iroha::bridge::query::{FindAllBridges, FindBridgeByName};
+
+    pub fn all(options: ListOptions) -> QueryRequest {
+        FindAllBridges::build_request(options)
+    }
+
+    pub fn by_name(name: &str) -> QueryRequest {
+        FindBridgeByName::build_request(name)
+    }
+}
+
+pub mod blocks {
+    use super::*;
+    use iroha::block::query::{
+        FindBlockByHash, FindBlockByHeight, FindBlockHeaders, FindLatestBlock,
+    };
+
+    pub fn by_height(height: u64) -> QueryRequest {
+        FindBlockByHeight::build_request(height)
+    }
+
+    pub fn by_hash(hash: Hash) -> QueryRequest {
+        FindBlockByHash::build_request(hash)
+    }
+
+    pub fn headers(from_height: u64, to_height: u64, options: ListOptions) -> QueryRequest {
+        FindBlockHeaders::build_request(from_height, to_height, options)
+    }
+
+    pub fn latest() -> QueryRequest {
+        FindLatestBlock::build_request()
+    }
+}
+
+pub mod transactions {
+    use super::*;
+    use iroha::{
+        block::query::FindTransactionByHash,
+        tx::query::{GetAccountAssetTransactions, GetAccountTransactions, GetTransactionStatus},
+    };
+
+    pub fn status_by_hash(hash: Hash) -> QueryRequest {
+        GetTransactionStatus::build_request(hash)
+    }
+
+    pub fn by_hash(hash: Hash) -> QueryRequest {
+        FindTransactionByHash::build_request(hash)
+    }
+
+    pub fn by_account_id(
+        account_id: <Account as Identifiable>::Id,
+        cursor: Option<Hash>,
+        limit: u32,
+    ) -> QueryRequest {
+        GetAccountTransactions::build_request(account_id, cursor, limit)
+    }
 
-    pub fn by_account_id(account_id: <Account as Identifiable>::Id) -> QueryRequest {
-        GetAccountAssets::build_request(account_id)
+    pub fn by_asset_id(
+        asset_id: <Asset as Identifiable>::Id,
+        cursor: Option<Hash>,
+        limit: u32,
+    ) -> QueryRequest {
+        GetAccountAssetTransactions::build_request(asset_id, cursor, limit)
     }
 }
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use async_std::task;
-    use iroha::{isi, prelude::*};
+    use iroha::{isi, prelude::*, tx::TransactionStatus};
     use iroha_client::client::{self, Client};
     use std::{thread, time::Duration};
     use tempfile::TempDir;
@@ -12,7 +12,7 @@ mod tests {
     //TODO: use cucumber to write `gherkin` instead of code.
     async fn client_add_asset_quantity_to_existing_asset_should_increase_asset_amount() {
         // Given
-        thread::spawn(|| create_and_start_iroha());
+        thread::spawn(create_and_start_iroha);
         thread::sleep(std::time::Duration::from_millis(300));
         let configuration =
             Configuration::from_path(CONFIGURATION_PATH).expect("Failed to load configuration.");
@@ -25,14 +25,12 @@ mod tests {
             destination_id: domain_name.to_string(),
         };
         let mut iroha_client = Client::new(&configuration);
-        iroha_client
-            .submit(create_asset.into())
+        let timeout = Duration::from_millis(&configuration.block_build_step_ms * 20);
+        let status = iroha_client
+            .submit_blocking(create_asset.into(), timeout)
             .await
             .expect("Failed to prepare state.");
-        task::sleep(Duration::from_millis(
-            &configuration.block_build_step_ms * 20,
-        ))
-        .await;
+        assert!(matches!(status, TransactionStatus::Committed(_)));
         //When
         let quantity: u32 = 200;
         let mint_asset = isi::Mint {
@@ -42,21 +40,21 @@ mod tests {
                 account_id: account_id.clone(),
             },
         };
-        iroha_client
-            .submit(mint_asset.into())
+        let status = iroha_client
+            .submit_blocking(mint_asset.into(), timeout)
             .await
             .expect("Failed to create asset.");
-        task::sleep(Duration::from_millis(
-            &configuration.block_build_step_ms * 20,
-        ))
-        .await;
+        assert!(matches!(status, TransactionStatus::Committed(_)));
         //Then
-        let request = client::assets::by_account_id(account_id);
+        let request = client::assets::by_account_id(account_id, ListOptions::default());
         let query_result = iroha_client
             .request(&request)
             .await
             .expect("Failed to execute request.");
-        let QueryResult::GetAccountAssets(result) = query_result;
+        let result = match query_result {
+            QueryResult::GetAccountAssets(result) => result,
+            _ => panic!("Wrong Query Result Type."),
+        };
         assert!(!result.assets.is_empty());
         assert_eq!(
             quantity,
@@ -72,6 +70,8 @@ mod tests {
         let iroha = Iroha::new(configuration);
         task::block_on(iroha.start()).expect("Failed to start Iroha.");
         //Prevents temp_dir from clean up untill the end of the tests.
-        loop {}
+        loop {
+            std::thread::park();
+        }
     }
 }
@@ -26,7 +26,9 @@ mod tests {
         configuration.peer_id(peers.first().expect("Failed to get first peer.").clone());
         let account_name = "account";
         let account_id = AccountId::new(account_name, domain_name);
-        let (public_key, _) = configuration.key_pair();
+        let public_key = configuration
+            .client_public_key
+            .expect("Failed to get the client public key.");
         let create_account = isi::Register {
             object: Account::new(account_name, domain_name, public_key),
             destination_id: String::from(domain_name),
@@ -71,12 +73,15 @@ mod tests {
             Configuration::from_path(CONFIGURATION_PATH).expect("Failed to load configuration.");
         configuration.peer_id(peers.last().expect("Failed to get last peer.").clone());
         let mut iroha_client = Client::new(&configuration);
-        let request = client::assets::by_account_id(account_id);
+        let request = client::assets::by_account_id(account_id, ListOptions::default());
         let query_result = iroha_client
             .request(&request)
             .await
             .expect("Failed to execute request.");
-        let QueryResult::GetAccountAssets(result) = query_result;
+        let result = match query_result {
+            QueryResult::GetAccountAssets(result) => result,
+            _ => panic!("Wrong Query Result Type."),
+        };
         assert!(!result.assets.is_empty());
         assert_eq!(
             quantity,
@@ -106,13 +111,13 @@ mod tests {
                 configuration.kura_block_store_path(temp_dir.path());
                 configuration.peer_id(peer_id.clone());
                 configuration.public_key = public_key;
-                configuration.private_key = private_key;
+                configuration.private_key = Some(private_key);
                 configuration.trusted_peers(peer_ids.clone());
                 configuration.max_faulty_peers(MAX_FAULTS);
                 let iroha = Iroha::new(configuration);
                 iroha.start().await.expect("Failed to start Iroha.");
                 //Prevents temp_dir from clean up untill the end of the tests.
-                loop {}
+                futures::future::pending::<()>().await;
             });
             task::sleep(std::time::Duration::from_millis(100)).await;
         }
@@ -12,7 +12,7 @@ mod tests {
     //TODO: use cucumber to write `gherkin` instead of code.
     async fn client_can_transfer_asset_to_another_account_x100() {
         // Given
-        thread::spawn(|| create_and_start_iroha());
+        thread::spawn(create_and_start_iroha);
         thread::sleep(std::time::Duration::from_millis(200));
         let configuration =
             Configuration::from_path(CONFIGURATION_PATH).expect("Failed to load configuration.");
@@ -25,7 +25,9 @@ mod tests {
         let account2_name = "account2";
         let account1_id = AccountId::new(account1_name, domain_name);
         let account2_id = AccountId::new(account2_name, domain_name);
-        let (public_key, _) = configuration.key_pair();
+        let public_key = configuration
+            .client_public_key
+            .expect("Failed to get the client public key.");
         let create_account1 = isi::Register {
             object: Account::new(account1_name, domain_name, public_key),
             destination_id: String::from(domain_name),
@@ -63,7 +65,7 @@ mod tests {
         ));
         //When
         for _ in 0..100 {
-            let transfer_asset = isi::Transfer {
+            let transfer_asset = isi::Transfer::<Account, Asset, Account> {
                 source_id: account1_id.clone(),
                 destination_id: account2_id.clone(),
                 object: Asset::with_quantity(
@@ -72,8 +74,7 @@ mod tests {
                         account_id: account1_id.clone(),
                     },
                     quantity,
-                )
-                .into(),
+                ),
             };
             iroha_client
                 .submit(transfer_asset.into())
@@ -85,7 +86,7 @@ mod tests {
             &configuration.block_build_step_ms * 2,
         ));
         //Then
-        let request = client::assets::by_account_id(account2_id);
+        let request = client::assets::by_account_id(account2_id, ListOptions::default());
         iroha_client
             .request(&request)
             .await
@@ -100,6 +101,8 @@ mod tests {
         let iroha = Iroha::new(configuration);
         task::block_on(iroha.start()).expect("Failed to start Iroha.");
         //Prevents temp_dir from clean up untill the end of the tests.
-        loop {}
+        loop {
+            std::thread::park();
+        }
     }
 }
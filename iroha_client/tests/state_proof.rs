@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use async_std::task;
+    use iroha::{isi, prelude::*, tx::TransactionStatus};
+    use iroha_client::client::{self, Client};
+    use std::{thread, time::Duration};
+    use tempfile::TempDir;
+
+    const CONFIGURATION_PATH: &str = "tests/test_config.json";
+
+    #[async_std::test]
+    async fn client_account_proof_should_be_verified_against_the_latest_block_header() {
+        // Given
+        thread::spawn(create_and_start_iroha);
+        thread::sleep(std::time::Duration::from_millis(300));
+        let configuration =
+            Configuration::from_path(CONFIGURATION_PATH).expect("Failed to load configuration.");
+        let domain_name = "global";
+        let account_id = AccountId::new("root", domain_name);
+        let create_asset = isi::Register {
+            object: AssetDefinition::new(AssetDefinitionId::new("xor", domain_name)),
+            destination_id: domain_name.to_string(),
+        };
+        let mut iroha_client = Client::new(&configuration);
+        let timeout = Duration::from_millis(&configuration.block_build_step_ms * 20);
+        let status = iroha_client
+            .submit_blocking(create_asset.into(), timeout)
+            .await
+            .expect("Failed to prepare state.");
+        let height = match status {
+            TransactionStatus::Committed(height) => height,
+            _ => panic!("Transaction is not committed: {:?}", status),
+        };
+        //When
+        let query_result = iroha_client
+            .request(&client::accounts::by_id_with_proof(account_id.clone()))
+            .await
+            .expect("Failed to execute request.");
+        //Then
+        let result = match query_result {
+            QueryResult::FindAccountById(result) => result,
+            _ => panic!("Wrong Query Result Type."),
+        };
+        let state_header = result.state_header.expect("Failed to get state header.");
+        assert_eq!(state_header.header.height, height);
+        assert!(state_header.verify_signatures().is_ok());
+        let proof = result.proof.expect("Failed to get proof.");
+        assert!(proof.verify(&state_header.header.state_root_hash));
+    }
+
+    fn create_and_start_iroha() {
+        let temp_dir = TempDir::new().expect("Failed to create TempDir.");
+        let mut configuration =
+            Configuration::from_path(CONFIGURATION_PATH).expect("Failed to load configuration.");
+        configuration.kura_block_store_path(temp_dir.path());
+        let iroha = Iroha::new(configuration);
+        task::block_on(iroha.start()).expect("Failed to start Iroha.");
+        //Prevents temp_dir from clean up untill the end of the tests.
+        loop {
+            std::thread::park();
+        }
+    }
+}
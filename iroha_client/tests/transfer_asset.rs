@@ -1,9 +1,9 @@
 #[cfg(test)]
 mod tests {
     use async_std::task;
-    use iroha::{isi, prelude::*};
+    use iroha::{isi, prelude::*, tx::TransactionStatus};
     use iroha_client::client::{self, Client};
-    use std::thread;
+    use std::{thread, time::Duration};
     use tempfile::TempDir;
 
     const CONFIGURATION_PATH: &str = "tests/test_config.json";
@@ -26,7 +26,9 @@ mod tests {
         let account2_name = "account2";
         let account1_id = AccountId::new(account1_name, domain_name);
         let account2_id = AccountId::new(account2_name, domain_name);
-        let (public_key, _) = configuration.key_pair();
+        let public_key = configuration
+            .client_public_key
+            .expect("Failed to get the client public key.");
         let create_account1 = isi::Register {
             object: Account::new(account1_name, domain_name, public_key),
             destination_id: String::from(domain_name),
@@ -48,19 +50,21 @@ mod tests {
                 account_id: account1_id.clone(),
             },
         };
-        iroha_client
-            .submit_all(vec![
-                create_domain.into(),
-                create_account1.into(),
-                create_account2.into(),
-                create_asset.into(),
-                mint_asset.into(),
-            ])
+        let timeout = Duration::from_millis(&configuration.block_build_step_ms * 20);
+        let status = iroha_client
+            .submit_all_blocking(
+                vec![
+                    create_domain.into(),
+                    create_account1.into(),
+                    create_account2.into(),
+                    create_asset.into(),
+                    mint_asset.into(),
+                ],
+                timeout,
+            )
             .await
             .expect("Failed to prepare state.");
-        std::thread::sleep(std::time::Duration::from_millis(
-            &configuration.block_build_step_ms * 2,
-        ));
+        assert!(matches!(status, TransactionStatus::Committed(_)));
         //When
         let quantity = 20;
         let transfer_asset = isi::Transfer {
@@ -82,12 +86,15 @@ mod tests {
             &configuration.block_build_step_ms * 2,
         ));
         //Then
-        let request = client::assets::by_account_id(account2_id);
+        let request = client::assets::by_account_id(account2_id, ListOptions::default());
         let query_result = iroha_client
             .request(&request)
             .await
             .expect("Failed to execute request.");
-        let QueryResult::GetAccountAssets(result) = query_result;
+        let result = match query_result {
+            QueryResult::GetAccountAssets(result) => result,
+            _ => panic!("Wrong Query Result Type."),
+        };
         assert_eq!(
             quantity,
             result.assets.first().expect("Asset should exist.").quantity,
@@ -102,6 +109,8 @@ mod tests {
         let iroha = Iroha::new(configuration);
         task::block_on(iroha.start()).expect("Failed to start Iroha.");
         //Prevents temp_dir from clean up untill the end of the tests.
-        loop {}
+        loop {
+            std::thread::park();
+        }
     }
 }
@@ -32,13 +32,13 @@ fn main() {
     if let Some(configuration_path) = matches.value_of(CONFIG) {
         println!("Value for config: {}", configuration_path);
     }
-    if let Some(ref matches) = matches.subcommand_matches(DOMAIN) {
+    if let Some(matches) = matches.subcommand_matches(DOMAIN) {
         domain::process(matches);
     }
-    if let Some(ref matches) = matches.subcommand_matches(ACCOUNT) {
+    if let Some(matches) = matches.subcommand_matches(ACCOUNT) {
         account::process(matches);
     }
-    if let Some(ref matches) = matches.subcommand_matches(ASSET) {
+    if let Some(matches) = matches.subcommand_matches(ASSET) {
         asset::process(matches);
     }
 }
@@ -48,10 +48,12 @@ mod domain {
     use clap::ArgMatches;
     use futures::executor;
     use iroha::{isi, prelude::*};
-    use iroha_client::client::Client;
+    use iroha_client::client::{self, Client};
 
     const DOMAIN_NAME: &str = "name";
     const ADD: &str = "add";
+    const REMOVE: &str = "remove";
+    const GET: &str = "get";
 
     pub fn build_app<'a, 'b>() -> App<'a, 'b> {
         App::new(DOMAIN)
@@ -66,15 +68,51 @@ mod domain {
                         .required(true),
                 ),
             )
+            .subcommand(
+                App::new(REMOVE)
+                    .about("Use this command to remove Domain with all of its Accounts and Asset Definitions from Iroha Peer.")
+                    .arg(
+                        Arg::with_name(DOMAIN_NAME)
+                            .long(DOMAIN_NAME)
+                            .value_name(DOMAIN_NAME)
+                            .help("Domain's name as double-quoted string.")
+                            .takes_value(true)
+                            .required(true),
+                    ),
+            )
+            .subcommand(
+                App::new(GET)
+                    .about("Use this command to get Domain information from Iroha Peer.")
+                    .arg(
+                        Arg::with_name(DOMAIN_NAME)
+                            .long(DOMAIN_NAME)
+                            .value_name(DOMAIN_NAME)
+                            .help("Domain's name as double-quoted string.")
+                            .takes_value(true)
+                            .required(true),
+                    ),
+            )
     }
 
     pub fn process(matches: &ArgMatches<'_>) {
-        if let Some(ref matches) = matches.subcommand_matches(ADD) {
+        if let Some(matches) = matches.subcommand_matches(ADD) {
             if let Some(domain_name) = matches.value_of(DOMAIN_NAME) {
                 println!("Adding a new Domain with a name: {}", domain_name);
                 create_domain(domain_name);
             }
         }
+        if let Some(matches) = matches.subcommand_matches(REMOVE) {
+            if let Some(domain_name) = matches.value_of(DOMAIN_NAME) {
+                println!("Removing a Domain with a name: {}", domain_name);
+                remove_domain(domain_name);
+            }
+        }
+        if let Some(matches) = matches.subcommand_matches(GET) {
+            if let Some(domain_name) = matches.value_of(DOMAIN_NAME) {
+                println!("Getting a Domain with a name: {}", domain_name);
+                get_domain(domain_name);
+            }
+        }
     }
 
     fn create_domain(domain_name: &str) {
@@ -88,6 +126,36 @@ mod domain {
         executor::block_on(iroha_client.submit(create_domain.into()))
             .expect("Failed to create domain.");
     }
+
+    fn remove_domain(domain_name: &str) {
+        let configuration =
+            &Configuration::from_path("config.json").expect("Failed to load configuration.");
+        let mut iroha_client = Client::new(configuration);
+        let remove_domain = isi::Remove::<Peer, Domain>::new(
+            domain_name.to_string(),
+            configuration.peer_id.clone(),
+        );
+        executor::block_on(iroha_client.submit(remove_domain.into()))
+            .expect("Failed to remove domain.");
+    }
+
+    fn get_domain(domain_name: &str) {
+        let mut iroha_client = Client::new(
+            &Configuration::from_path("config.json").expect("Failed to load configuration."),
+        );
+        let query_result = executor::block_on(
+            iroha_client.request(&client::domains::by_name(domain_name.to_string())),
+        )
+        .expect("Failed to get domain.");
+        if let QueryResult::FindDomainByName(result) = query_result {
+            println!(
+                "Domain {}: {} accounts, {} asset definitions.",
+                result.domain.name,
+                result.domain.accounts.len(),
+                result.domain.asset_definitions.len()
+            );
+        }
+    }
 }
 
 mod account {
@@ -95,9 +163,11 @@ mod account {
     use clap::ArgMatches;
     use futures::executor;
     use iroha::{isi, prelude::*};
-    use iroha_client::client::Client;
+    use iroha_client::client::{self, Client};
 
     const REGISTER: &str = "register";
+    const UNREGISTER: &str = "unregister";
+    const GET: &str = "get";
     const ACCOUNT_NAME: &str = "name";
     const ACCOUNT_DOMAIN_NAME: &str = "domain";
     const ACCOUNT_KEY: &str = "key";
@@ -133,10 +203,50 @@ mod account {
                             .required(true),
                     ),
             )
+            .subcommand(
+                App::new(UNREGISTER)
+                    .about("Use this command to unregister Account with empty assets and no permissions from Iroha Domain.")
+                    .arg(
+                        Arg::with_name(ACCOUNT_NAME)
+                            .long(ACCOUNT_NAME)
+                            .value_name(ACCOUNT_NAME)
+                            .help("Account's name as double-quoted string.")
+                            .takes_value(true)
+                            .required(true),
+                    )
+                    .arg(
+                        Arg::with_name(ACCOUNT_DOMAIN_NAME)
+                            .long(ACCOUNT_DOMAIN_NAME)
+                            .value_name(ACCOUNT_DOMAIN_NAME)
+                            .help("Account's Domain's name as double-quoted string.")
+                            .takes_value(true)
+                            .required(true),
+                    ),
+            )
+            .subcommand(
+                App::new(GET)
+                    .about("Use this command to get Account information from Iroha Domain.")
+                    .arg(
+                        Arg::with_name(ACCOUNT_NAME)
+                            .long(ACCOUNT_NAME)
+                            .value_name(ACCOUNT_NAME)
+                            .help("Account's name as double-quoted string.")
+                            .takes_value(true)
+                            .required(true),
+                    )
+                    .arg(
+                        Arg::with_name(ACCOUNT_DOMAIN_NAME)
+                            .long(ACCOUNT_DOMAIN_NAME)
+                            .value_name(ACCOUNT_DOMAIN_NAME)
+                            .help("Account's Domain's name as double-quoted string.")
+                            .takes_value(true)
+                            .required(true),
+                    ),
+            )
     }
 
     pub fn process(matches: &ArgMatches<'_>) {
-        if let Some(ref matches) = matches.subcommand_matches(REGISTER) {
+        if let Some(matches) = matches.subcommand_matches(REGISTER) {
             if let Some(account_name) = matches.value_of(ACCOUNT_NAME) {
                 println!("Creating account with a name: {}", account_name);
                 if let Some(domain_name) = matches.value_of(ACCOUNT_DOMAIN_NAME) {
@@ -148,6 +258,27 @@ mod account {
                 }
             }
         }
+        if let Some(matches) = matches.subcommand_matches(UNREGISTER) {
+            if let Some(account_name) = matches.value_of(ACCOUNT_NAME) {
+                println!("Unregistering account with a name: {}", account_name);
+                if let Some(domain_name) = matches.value_of(ACCOUNT_DOMAIN_NAME) {
+                    println!(
+                        "Unregistering account with a domain's name: {}",
+                        domain_name
+                    );
+                    unregister_account(account_name, domain_name);
+                }
+            }
+        }
+        if let Some(matches) = matches.subcommand_matches(GET) {
+            if let Some(account_name) = matches.value_of(ACCOUNT_NAME) {
+                println!("Getting account with a name: {}", account_name);
+                if let Some(domain_name) = matches.value_of(ACCOUNT_DOMAIN_NAME) {
+                    println!("Getting account with a domain's name: {}", domain_name);
+                    get_account(account_name, domain_name);
+                }
+            }
+        }
     }
 
     fn create_account(account_name: &str, domain_name: &str, _public_key: &str) {
@@ -161,6 +292,35 @@ mod account {
         executor::block_on(iroha_client.submit(create_account.into()))
             .expect("Failed to create account.");
     }
+
+    fn unregister_account(account_name: &str, domain_name: &str) {
+        let unregister_account = isi::Unregister::<Domain, Account>::new(
+            AccountId::new(account_name, domain_name),
+            domain_name.to_string(),
+        );
+        let mut iroha_client = Client::new(
+            &Configuration::from_path("config.json").expect("Failed to load configuration."),
+        );
+        executor::block_on(iroha_client.submit(unregister_account.into()))
+            .expect("Failed to unregister account.");
+    }
+
+    fn get_account(account_name: &str, domain_name: &str) {
+        let mut iroha_client = Client::new(
+            &Configuration::from_path("config.json").expect("Failed to load configuration."),
+        );
+        let query_result = executor::block_on(iroha_client.request(&client::accounts::by_id(
+            AccountId::new(account_name, domain_name),
+        )))
+        .expect("Failed to get account.");
+        if let QueryResult::FindAccountById(result) = query_result {
+            println!(
+                "Account {:?}: {} assets.",
+                result.account.id,
+                result.account.assets.len()
+            );
+        }
+    }
 }
 
 mod asset {
@@ -171,6 +331,7 @@ mod asset {
     use iroha_client::client::{self, Client};
 
     const REGISTER: &str = "register";
+    const UNREGISTER: &str = "unregister";
     const MINT: &str = "mint";
     const GET: &str = "get";
     const ASSET_NAME: &str = "name";
@@ -178,6 +339,7 @@ mod asset {
     const ASSET_ACCOUNT_ID: &str = "account_id";
     const ASSET_ID: &str = "id";
     const QUANTITY: &str = "quantity";
+    const PRECISION: &str = "precision";
 
     pub fn build_app<'a, 'b>() -> App<'a, 'b> {
         App::new(ASSET)
@@ -201,6 +363,33 @@ mod asset {
                     .takes_value(true)
                     .required(true),
             )
+            .arg(
+                Arg::with_name(PRECISION)
+                    .long(PRECISION)
+                    .value_name(PRECISION)
+                    .help("Number of digits after the decimal point for fixed-point decimal assets.")
+                    .takes_value(true),
+            )
+            )
+            .subcommand(
+                App::new(UNREGISTER)
+                    .about("Use this command to unregister Asset Definition without circulating Assets from Iroha Domain.")
+                    .arg(
+                        Arg::with_name(ASSET_DOMAIN_NAME)
+                            .long(ASSET_DOMAIN_NAME)
+                            .value_name(ASSET_DOMAIN_NAME)
+                            .help("Asset's domain's name as double-quoted string.")
+                            .takes_value(true)
+                            .required(true),
+                    )
+                    .arg(
+                        Arg::with_name(ASSET_NAME)
+                            .long(ASSET_NAME)
+                            .value_name(ASSET_NAME)
+                            .help("Asset's name as double-quoted string.")
+                            .takes_value(true)
+                            .required(true),
+                    ),
             )
                .subcommand(
                     App::new(MINT)
@@ -208,6 +397,7 @@ mod asset {
                     .arg(Arg::with_name(ASSET_ACCOUNT_ID).long(ASSET_ACCOUNT_ID).value_name(ASSET_ACCOUNT_ID).help("Account's id as double-quoted string in the following format `account_name@domain_name`.").takes_value(true).required(true))
                     .arg(Arg::with_name(ASSET_ID).long(ASSET_ID).value_name(ASSET_ID).help("Asset's id as double-quoted string in the following format `asset_name#domain_name`.").takes_value(true).required(true))
                     .arg(Arg::with_name(QUANTITY).long(QUANTITY).value_name(QUANTITY).help("Asset's quantity as a number.").takes_value(true).required(true))
+                    .arg(Arg::with_name(PRECISION).long(PRECISION).value_name(PRECISION).help("Number of digits after the decimal point if the asset is a fixed-point decimal one.").takes_value(true))
                 )
 .subcommand(
 App::new(GET)
@@ -219,7 +409,7 @@ App::new(GET)
     }
 
     pub fn process(matches: &ArgMatches<'_>) {
-        if let Some(ref matches) = matches.subcommand_matches(REGISTER) {
+        if let Some(matches) = matches.subcommand_matches(REGISTER) {
             if let Some(asset_name) = matches.value_of(ASSET_NAME) {
                 println!("Registering asset defintion with a name: {}", asset_name);
                 if let Some(domain_name) = matches.value_of(ASSET_DOMAIN_NAME) {
@@ -227,11 +417,23 @@ App::new(GET)
                         "Registering asset definition with a domain's name: {}",
                         domain_name
                     );
-                    register_asset_definition(asset_name, domain_name);
+                    register_asset_definition(asset_name, domain_name, matches.value_of(PRECISION));
                 }
             }
         }
-        if let Some(ref matches) = matches.subcommand_matches(MINT) {
+        if let Some(matches) = matches.subcommand_matches(UNREGISTER) {
+            if let Some(asset_name) = matches.value_of(ASSET_NAME) {
+                println!("Unregistering asset definition with a name: {}", asset_name);
+                if let Some(domain_name) = matches.value_of(ASSET_DOMAIN_NAME) {
+                    println!(
+                        "Unregistering asset definition with a domain's name: {}",
+                        domain_name
+                    );
+                    unregister_asset_definition(asset_name, domain_name);
+                }
+            }
+        }
+        if let Some(matches) = matches.subcommand_matches(MINT) {
             if let Some(asset_id) = matches.value_of(ASSET_ID) {
                 println!("Minting asset with an identification: {}", asset_id);
                 if let Some(account_id) = matches.value_of(ASSET_ACCOUNT_ID) {
@@ -241,12 +443,12 @@ App::new(GET)
                     );
                     if let Some(amount) = matches.value_of(QUANTITY) {
                         println!("Minting asset's quantity: {}", amount);
-                        mint_asset(asset_id, account_id, amount);
+                        mint_asset(asset_id, account_id, amount, matches.value_of(PRECISION));
                     }
                 }
             }
         }
-        if let Some(ref matches) = matches.subcommand_matches(GET) {
+        if let Some(matches) = matches.subcommand_matches(GET) {
             if let Some(asset_id) = matches.value_of(ASSET_ID) {
                 println!("Getting asset with an identification: {}", asset_id);
                 if let Some(account_id) = matches.value_of(ASSET_ACCOUNT_ID) {
@@ -257,14 +459,23 @@ App::new(GET)
         }
     }
 
-    fn register_asset_definition(asset_name: &str, domain_name: &str) {
+    fn register_asset_definition(asset_name: &str, domain_name: &str, precision: Option<&str>) {
+        let id = AssetDefinitionId::new(asset_name, domain_name);
+        let asset_definition = match precision {
+            Some(precision) => AssetDefinition::with_value_type(
+                id,
+                AssetValueType::Fixed(precision.parse().expect("Failed to parse precision.")),
+                Mintable::Infinitely,
+            ),
+            None => AssetDefinition::new(id),
+        };
         let mut iroha_client = Client::new(
             &Configuration::from_path("config.json").expect("Failed to load configuration."),
         );
         executor::block_on(
             iroha_client.submit(
                 isi::Register {
-                    object: AssetDefinition::new(AssetDefinitionId::new(asset_name, domain_name)),
+                    object: asset_definition,
                     destination_id: domain_name.to_string(),
                 }
                 .into(),
@@ -273,31 +484,74 @@ App::new(GET)
         .expect("Failed to create account.");
     }
 
-    fn mint_asset(asset_definition_id: &str, account_id: &str, quantity: &str) {
-        let quantity: u32 = quantity.parse().expect("Failed to parse Asset quantity.");
-        let mint_asset = isi::Mint {
-            object: quantity,
-            destination_id: AssetId {
-                definition_id: AssetDefinitionId::from(asset_definition_id),
-                account_id: AccountId::from(account_id),
-            },
+    fn unregister_asset_definition(asset_name: &str, domain_name: &str) {
+        let mut iroha_client = Client::new(
+            &Configuration::from_path("config.json").expect("Failed to load configuration."),
+        );
+        executor::block_on(
+            iroha_client.submit(
+                isi::Unregister::<Domain, AssetDefinition>::new(
+                    AssetDefinitionId::new(asset_name, domain_name),
+                    domain_name.to_string(),
+                )
+                .into(),
+            ),
+        )
+        .expect("Failed to unregister asset definition.");
+    }
+
+    fn mint_asset(
+        asset_definition_id: &str,
+        account_id: &str,
+        quantity: &str,
+        precision: Option<&str>,
+    ) {
+        let asset_id = AssetId {
+            definition_id: AssetDefinitionId::from(asset_definition_id),
+            account_id: AccountId::from(account_id),
+        };
+        let mint_asset: Instruction = match precision {
+            Some(precision) => isi::Mint::<Asset, Fixed>::new(
+                Fixed::parse(
+                    quantity,
+                    precision.parse().expect("Failed to parse precision."),
+                )
+                .expect("Failed to parse Asset quantity."),
+                asset_id,
+            )
+            .into(),
+            None => isi::Mint::<Asset, u32>::new(
+                quantity.parse().expect("Failed to parse Asset quantity."),
+                asset_id,
+            )
+            .into(),
         };
         let mut iroha_client = Client::new(
             &Configuration::from_path("config.json").expect("Failed to load configuration."),
         );
-        executor::block_on(iroha_client.submit(mint_asset.into()))
-            .expect("Failed to create account.");
+        executor::block_on(iroha_client.submit(mint_asset)).expect("Failed to create account.");
     }
 
     fn get_asset(_asset_id: &str, account_id: &str) {
         let mut iroha_client = Client::new(
             &Configuration::from_path("config.json").expect("Failed to load configuration."),
         );
-        let query_result = executor::block_on(iroha_client.request(
-            &client::assets::by_account_id(<Account as Identifiable>::Id::from(account_id)),
-        ))
-        .expect("Failed to get asset.");
-        let QueryResult::GetAccountAssets(result) = query_result;
-        println!("Get Asset result: {:?}", result);
+        let query_result =
+            executor::block_on(iroha_client.request(&client::assets::by_account_id(
+                <Account as Identifiable>::Id::from(account_id),
+                ListOptions::default(),
+            )))
+            .expect("Failed to get asset.");
+        if let QueryResult::GetAccountAssets(result) = query_result {
+            for asset in result.assets {
+                println!(
+                    "Asset {}: quantity {}, big quantity {}, fixed quantity {}.",
+                    asset.id.definition_id,
+                    asset.quantity,
+                    asset.big_quantity,
+                    asset.fixed_quantity
+                );
+            }
+        }
     }
 }
@@ -15,7 +15,8 @@ use std::{
     sync::Arc,
 };
 
-const BUFFER_SIZE: usize = 2048;
+/// The maximum size in bytes of a single request or response.
+pub const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
 
 pub type State<T> = Arc<RwLock<T>>;
 pub trait AsyncStream: async_std::io::Read + async_std::io::Write + Send + Unpin {}
@@ -56,15 +57,8 @@ impl Network {
         let mut stream = TcpStream::connect(server_url)
             .await
             .map_err(|e| e.to_string())?;
-        let payload: Vec<u8> = request.into();
-        stream
-            .write_all(&payload)
-            .await
-            .map_err(|e| e.to_string())?;
-        stream.flush().await.map_err(|e| e.to_string())?;
-        let mut buffer = vec![0u8; BUFFER_SIZE];
-        let read_size = stream.read(&mut buffer).await.map_err(|e| e.to_string())?;
-        Ok(Response::try_from(buffer[..read_size].to_vec())?)
+        write_message(&mut stream, request.into()).await?;
+        Ok(Response::try_from(read_message(&mut stream).await?)?)
     }
 
     /// Listens on the specified `server_url`.
@@ -108,23 +102,56 @@ impl Network {
         H: FnMut(State<S>, Request) -> F,
         F: Future<Output = Result<Response, String>>,
     {
-        let mut buffer = [0u8; BUFFER_SIZE];
-        let read_size = stream
-            .read(&mut buffer)
-            .await
-            .expect("Request read failed.");
-        let bytes: Vec<u8> = buffer[..read_size].to_vec();
-        let request: Request = bytes
+        let request: Request = read_message(&mut stream)
+            .await?
             .try_into()
             .map_err(|e: Box<dyn Error>| e.to_string())?;
         let response: Vec<u8> = handler(state, request).await?.into();
-        stream
-            .write_all(&response)
-            .await
-            .map_err(|e| e.to_string())?;
-        stream.flush().await.map_err(|e| e.to_string())?;
-        Ok(())
+        write_message(&mut stream, response).await
+    }
+}
+
+/// Writes `bytes` to the `stream` prefixed with their length, so the other side knows how much
+/// to read regardless of how the message is split into packets.
+async fn write_message<T: async_std::io::Write + Unpin>(
+    stream: &mut T,
+    bytes: Vec<u8>,
+) -> Result<(), String> {
+    if bytes.len() > MAX_MESSAGE_SIZE {
+        return Err(format!(
+            "Message size {} bytes exceeds the limit of {} bytes.",
+            bytes.len(),
+            MAX_MESSAGE_SIZE
+        ));
+    }
+    stream
+        .write_all(&(bytes.len() as u32).to_le_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+    stream.write_all(&bytes).await.map_err(|e| e.to_string())?;
+    stream.flush().await.map_err(|e| e.to_string())
+}
+
+/// Reads a whole length prefixed message written by `write_message` from the `stream`.
+async fn read_message<T: async_std::io::Read + Unpin>(stream: &mut T) -> Result<Vec<u8>, String> {
+    let mut length = [0u8; 4];
+    stream
+        .read_exact(&mut length)
+        .await
+        .map_err(|e| e.to_string())?;
+    let length = u32::from_le_bytes(length) as usize;
+    if length > MAX_MESSAGE_SIZE {
+        return Err(format!(
+            "Message size {} bytes exceeds the limit of {} bytes.",
+            length, MAX_MESSAGE_SIZE
+        ));
     }
+    let mut bytes = vec![0u8; length];
+    stream
+        .read_exact(&mut bytes)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(bytes)
 }
 
 #[derive(Eq, PartialEq, Debug, Clone)]
@@ -139,7 +166,7 @@ impl Request {
     /// # Arguments
     ///
     /// * uri_path - corresponds to [URI syntax](https://en.wikipedia.org/wiki/Uniform_Resource_Identifier)
-    /// `path` part (e.g. "/commands")
+    ///   `path` part (e.g. "/commands")
     /// * payload - the message in bytes
     ///
     /// # Examples
@@ -193,6 +220,8 @@ impl TryFrom<Vec<u8>> for Request {
 #[derive(Debug, PartialEq, Io, Encode, Decode)]
 pub enum Response {
     Ok(Vec<u8>),
+    /// Request was processed, but rejected - payload contains an encoded reason of the rejection.
+    Rejected(Vec<u8>),
     InternalError,
 }
 
@@ -251,14 +280,14 @@ mod tests {
             _request: Request,
         ) -> Result<Response, String> {
             Ok(Response::Ok("pong".as_bytes().to_vec()))
-        };
+        }
 
         async fn handle_connection<S>(
             state: State<S>,
             stream: Box<dyn AsyncStream>,
         ) -> Result<(), String> {
             Network::handle_message_async(state, stream, handle_request).await
-        };
+        }
 
         task::spawn(async move {
             Network::listen(get_empty_state(), "127.0.0.1:7878", handle_connection).await
@@ -273,6 +302,36 @@ mod tests {
         }
     }
 
+    #[async_std::test]
+    async fn large_messages_should_be_transferred_whole() {
+        async fn handle_request<S>(_state: State<S>, request: Request) -> Result<Response, String> {
+            Ok(Response::Ok(request.payload))
+        }
+
+        async fn handle_connection<S>(
+            state: State<S>,
+            stream: Box<dyn AsyncStream>,
+        ) -> Result<(), String> {
+            Network::handle_message_async(state, stream, handle_request).await
+        }
+
+        task::spawn(async move {
+            Network::listen(get_empty_state(), "127.0.0.1:7879", handle_connection).await
+        });
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        let payload: Vec<u8> = (0..100_000).map(|i| (i % 256) as u8).collect();
+        match Network::send_request_to(
+            "127.0.0.1:7879",
+            Request::new("/echo".to_string(), payload.clone()),
+        )
+        .await
+        .expect("Failed to send request to.")
+        {
+            Response::Ok(response) => assert_eq!(response, payload),
+            _ => panic!("Response should be ok."),
+        }
+    }
+
     #[async_std::test]
     async fn single_threaded_async_stateful() {
         let counter: State<usize> = Arc::new(RwLock::new(0));
@@ -284,13 +343,13 @@ mod tests {
             let mut data = state.write().await;
             *data += 1;
             Ok(Response::Ok("pong".as_bytes().to_vec()))
-        };
+        }
         async fn handle_connection(
             state: State<usize>,
             stream: Box<dyn AsyncStream>,
         ) -> Result<(), String> {
             Network::handle_message_async(state, stream, handle_request).await
-        };
+        }
         let counter_move = counter.clone();
         task::spawn(async move {
             Network::listen(counter_move, "127.0.0.1:7870", handle_connection).await
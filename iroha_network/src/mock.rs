@@ -1,6 +1,6 @@
+use crate::{read_message, write_message};
 use async_std::{
     io::{Read, Write},
-    prelude::*,
     sync::{self, Arc, RwLock, Sender},
     task,
 };
@@ -14,7 +14,6 @@ use std::{
     task::{Context, Poll},
 };
 
-const BUFFER_SIZE: usize = 2048;
 static mut ENDPOINTS: Vec<(String, Sender<RequestStream>)> = Vec::new();
 
 fn find_sender(server_url: &str) -> Sender<RequestStream> {
@@ -32,8 +31,11 @@ pub type State<T> = Arc<RwLock<T>>;
 pub trait AsyncStream: Read + Write + Send + Unpin {}
 impl<T> AsyncStream for T where T: Read + Write + Send + Unpin {}
 
+/// In-memory connection - the server reads the `request` bytes and the `response` bytes it
+/// writes are sent back to the client on flush.
 struct RequestStream {
-    bytes: Vec<u8>,
+    request: Vec<u8>,
+    response: Vec<u8>,
     tx: Sender<Vec<u8>>,
 }
 
@@ -45,7 +47,7 @@ impl Read for RequestStream {
         _cx: &mut Context<'_>,
         buf: &mut [u8],
     ) -> Poll<async_std::io::Result<usize>> {
-        let bytes = &mut self.get_mut().bytes;
+        let bytes = &mut self.get_mut().request;
         let length = if buf.len() > bytes.len() {
             bytes.len()
         } else {
@@ -64,7 +66,7 @@ impl Write for RequestStream {
         _cx: &mut Context<'_>,
         buf: &[u8],
     ) -> Poll<async_std::io::Result<usize>> {
-        let bytes = &mut self.get_mut().bytes;
+        let bytes = &mut self.get_mut().response;
         for byte in buf.to_vec() {
             bytes.push(byte);
         }
@@ -72,7 +74,7 @@ impl Write for RequestStream {
     }
 
     fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<async_std::io::Result<()>> {
-        task::block_on(self.tx.send(self.bytes.clone()));
+        task::block_on(self.tx.send(self.response.clone()));
         Poll::Ready(Ok(()))
     }
 
@@ -100,18 +102,21 @@ impl Network {
     #[log]
     pub async fn send_request_to(server_url: &str, request: Request) -> Result<Response, String> {
         let (tx, rx) = sync::channel(100);
-        let mut stream = RequestStream {
-            bytes: Vec::new(),
+        let mut bytes = Vec::new();
+        write_message(&mut bytes, request.into()).await?;
+        let stream = RequestStream {
+            request: bytes,
+            response: Vec::new(),
             tx,
         };
-        let payload: Vec<u8> = request.into();
-        stream
-            .write_all(&payload)
-            .await
-            .map_err(|e| e.to_string())?;
         find_sender(server_url).send(stream).await;
-        //TODO: return actual response
-        Ok(Response::try_from(rx.recv().await.unwrap())?)
+        let response = rx
+            .recv()
+            .await
+            .ok_or("Connection is closed without a response.")?;
+        Ok(Response::try_from(
+            read_message(&mut response.as_slice()).await?,
+        )?)
     }
 
     /// Listens on the specified `server_url`.
@@ -152,22 +157,12 @@ impl Network {
         H: FnMut(State<S>, Request) -> F,
         F: Future<Output = Result<Response, String>>,
     {
-        let mut buffer = [0u8; BUFFER_SIZE];
-        let read_size = stream
-            .read(&mut buffer)
-            .await
-            .expect("Request read failed.");
-        let bytes: Vec<u8> = buffer[..read_size].to_vec();
-        let request: Request = bytes
+        let request: Request = read_message(&mut stream)
+            .await?
             .try_into()
             .map_err(|e: Box<dyn Error>| e.to_string())?;
         let response: Vec<u8> = handler(state, request).await?.into();
-        stream
-            .write_all(&response)
-            .await
-            .map_err(|e| e.to_string())?;
-        stream.flush().await.map_err(|e| e.to_string())?;
-        Ok(())
+        write_message(&mut stream, response).await
     }
 }
 
@@ -237,6 +232,8 @@ impl TryFrom<Vec<u8>> for Request {
 #[derive(Debug, PartialEq, Io, Encode, Decode)]
 pub enum Response {
     Ok(Vec<u8>),
+    /// Request was processed, but rejected - payload contains an encoded reason of the rejection.
+    Rejected(Vec<u8>),
     InternalError,
 }
 